@@ -1,3 +1,4 @@
+use consts::{constant_with_locals, Constant};
 use rustc::lint::*;
 use rustc::middle::const_val::ConstVal;
 use rustc::ty;
@@ -6,6 +7,7 @@ use rustc_const_math::{ConstUsize, ConstIsize, ConstInt};
 use rustc::hir;
 use syntax::ast::RangeLimits;
 use utils::{self, higher};
+use utils::interval::{interval, FullInt};
 
 /// **What it does:** Checks for out of bounds array indexing with a constant index.
 ///
@@ -62,11 +64,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ArrayIndexing {
             if let ty::TyArray(_, size) = ty.sty {
                 let size = ConstInt::Usize(ConstUsize::new(size as u64, cx.sess().target.uint_type)
                     .expect("array size is invalid"));
-                let constcx = ConstContext::with_tables(cx.tcx, cx.tables);
 
                 // Index is a constant uint
-                let const_index = constcx.eval(index);
-                if let Ok(ConstVal::Integral(const_index)) = const_index {
+                if let Some(const_index) = const_int(cx, index) {
                     if size <= const_index {
                         utils::span_lint(cx, OUT_OF_BOUNDS_INDEXING, e.span, "const index is out of bounds");
                     }
@@ -76,12 +76,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ArrayIndexing {
 
                 // Index is a constant range
                 if let Some(range) = higher::range(index) {
-                    let start = range.start
-                        .map(|start| constcx.eval(start))
-                        .map(|v| v.ok());
-                    let end = range.end
-                        .map(|end| constcx.eval(end))
-                        .map(|v| v.ok());
+                    let start = range.start.map(|start| const_int(cx, start));
+                    let end = range.end.map(|end| const_int(cx, end));
 
                     if let Some((start, end)) = to_const_range(&start, &end, range.limits, size) {
                         if start > size || end > size {
@@ -90,6 +86,17 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ArrayIndexing {
                         return;
                     }
                 }
+
+                // Index is known to be either always or never in bounds
+                if let Some(index_range) = interval(cx, index) {
+                    if index_range.min >= FullInt::from(size) {
+                        utils::span_lint(cx, OUT_OF_BOUNDS_INDEXING, e.span, "index is out of bounds");
+                        return;
+                    }
+                    if index_range.max < FullInt::from(size) {
+                        return;
+                    }
+                }
             }
 
             if let Some(range) = higher::range(index) {
@@ -107,21 +114,37 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ArrayIndexing {
     }
 }
 
+/// The value of a constant integer expression, as rustc or else Clippy folds it, which also knows
+/// about locals like `let i = 4;`.
+fn const_int(cx: &LateContext, e: &hir::Expr) -> Option<ConstInt> {
+    match ConstContext::with_tables(cx.tcx, cx.tables).eval(e) {
+        Ok(ConstVal::Integral(x)) => Some(x),
+        Ok(_) => None,
+        Err(_) => {
+            if let Some((Constant::Int(x), _)) = constant_with_locals(cx, e) {
+                Some(x)
+            } else {
+                None
+            }
+        },
+    }
+}
+
 /// Returns an option containing a tuple with the start and end (exclusive) of the range.
 fn to_const_range(
-    start: &Option<Option<ConstVal>>,
-    end: &Option<Option<ConstVal>>,
+    start: &Option<Option<ConstInt>>,
+    end: &Option<Option<ConstInt>>,
     limits: RangeLimits,
     array_size: ConstInt
 ) -> Option<(ConstInt, ConstInt)> {
     let start = match *start {
-        Some(Some(ConstVal::Integral(x))) => x,
-        Some(_) => return None,
+        Some(Some(x)) => x,
+        Some(None) => return None,
         None => ConstInt::U8(0),
     };
 
     let end = match *end {
-        Some(Some(ConstVal::Integral(x))) => {
+        Some(Some(x)) => {
             if limits == RangeLimits::Closed {
                 match x {
                         ConstInt::U8(_) => (x + ConstInt::U8(1)),
@@ -146,7 +169,7 @@ fn to_const_range(
                 x
             }
         },
-        Some(_) => return None,
+        Some(None) => return None,
         None => array_size,
     };
 
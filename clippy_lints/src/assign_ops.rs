@@ -1,8 +1,10 @@
 use rustc::hir;
 use rustc::lint::*;
 use syntax::ast;
-use utils::{span_lint_and_then, snippet_opt, SpanlessEq, get_trait_def_id, implements_trait};
+use utils::{span_lint_and_then, span_lint_and_sugg, snippet_with_applicability, SpanlessEq, get_trait_def_id,
+            implements_trait};
 use utils::{higher, sugg};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for compound assignment operations (`+=` and similar).
 ///
@@ -72,12 +74,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AssignOps {
         match expr.node {
             hir::ExprAssignOp(op, ref lhs, ref rhs) => {
                 span_lint_and_then(cx, ASSIGN_OPS, expr.span, "assign operation detected", |db| {
-                    let lhs = &sugg::Sugg::hir(cx, lhs, "..");
-                    let rhs = &sugg::Sugg::hir(cx, rhs, "..");
+                    let mut applicability = Applicability::MachineApplicable;
+                    let lhs = &sugg::Sugg::hir_with_applicability(cx, lhs, "..", &mut applicability);
+                    let rhs = &sugg::Sugg::hir_with_applicability(cx, rhs, "..", &mut applicability);
 
-                    db.span_suggestion(expr.span,
-                                       "replace it with",
-                                       format!("{} = {}", lhs, sugg::make_binop(higher::binop(op.node), lhs, rhs)));
+                    db.span_suggestion_with_applicability(expr.span,
+                                                          "replace it with",
+                                                          format!("{} = {}",
+                                                                  lhs,
+                                                                  sugg::make_binop(higher::binop(op.node), lhs, rhs)),
+                                                          applicability);
                 });
                 if let hir::ExprBinary(binop, ref l, ref r) = rhs.node {
                     if op.node == binop.node {
@@ -90,19 +96,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AssignOps {
                             if rty.walk_shallow().next().is_some() {
                                 return; // implements_trait does not work with generics
                             }
-                            span_lint_and_then(cx,
+                            let mut applicability = Applicability::MaybeIncorrect;
+                            let snip_a = snippet_with_applicability(cx, assignee.span, "..", &mut applicability);
+                            let snip_r = snippet_with_applicability(cx, rhs.span, "..", &mut applicability);
+                            span_lint_and_sugg(cx,
                                                MISREFACTORED_ASSIGN_OP,
                                                expr.span,
                                                "variable appears on both sides of an assignment operation",
-                                               |db| if let (Some(snip_a), Some(snip_r)) =
-                                                   (snippet_opt(cx, assignee.span), snippet_opt(cx, rhs.span)) {
-                                                   db.span_suggestion(expr.span,
-                                                                      "replace it with",
-                                                                      format!("{} {}= {}",
-                                                                              snip_a,
-                                                                              op.node.as_str(),
-                                                                              snip_r));
-                                               });
+                                               "replace it with",
+                                               format!("{} {}= {}", snip_a, op.node.as_str(), snip_r),
+                                               applicability);
                         };
                         // lhs op= l op r
                         if SpanlessEq::new(cx).ignore_fn().eq_expr(lhs, l) {
@@ -174,19 +177,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AssignOps {
                                 BitXor: BiBitXor,
                                 Shr: BiShr,
                                 Shl: BiShl) {
-                            span_lint_and_then(cx,
+                            let mut applicability = Applicability::MachineApplicable;
+                            let snip_a = snippet_with_applicability(cx, assignee.span, "..", &mut applicability);
+                            let snip_r = snippet_with_applicability(cx, rhs.span, "..", &mut applicability);
+                            span_lint_and_sugg(cx,
                                                ASSIGN_OP_PATTERN,
                                                expr.span,
                                                "manual implementation of an assign operation",
-                                               |db| if let (Some(snip_a), Some(snip_r)) =
-                                                   (snippet_opt(cx, assignee.span), snippet_opt(cx, rhs.span)) {
-                                                   db.span_suggestion(expr.span,
-                                                                      "replace it with",
-                                                                      format!("{} {}= {}",
-                                                                              snip_a,
-                                                                              op.node.as_str(),
-                                                                              snip_r));
-                                               });
+                                               "replace it with",
+                                               format!("{} {}= {}", snip_a, op.node.as_str(), snip_r),
+                                               applicability);
                         }
                     };
                     // a = a op b
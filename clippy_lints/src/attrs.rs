@@ -7,7 +7,9 @@ use rustc::ty;
 use semver::Version;
 use syntax::ast::{Attribute, Lit, LitKind, MetaItemKind, NestedMetaItem, NestedMetaItemKind};
 use syntax::codemap::Span;
-use utils::{in_macro, match_def_path, paths, span_lint, span_lint_and_then, snippet_opt};
+use utils::{catalogue, in_macro, match_def_path, paths, span_help_and_lint, span_lint, span_lint_and_then, snippet_opt};
+use utils::sugg::{Applicability, SuggestionExt};
+use utils::suppression::{self, Kind, Suppression};
 
 /// **What it does:** Checks for items annotated with `#[inline(always)]`,
 /// unless the annotated function is empty or simply panics.
@@ -56,6 +58,26 @@ declare_lint! {
     "use of lint attributes on `extern crate` items"
 }
 
+/// **What it does:** Checks for `#[allow(..)]` attributes of Clippy lints that do not say why the
+/// lint is allowed, with a `//` comment at the end of the attribute's line or on the lines just
+/// above it, or with a `reason = "..."` item.
+///
+/// **Why is this bad?** An unexplained `allow` cannot be audited, and the next reader does not
+/// know whether the lint is a false positive or a debt.
+///
+/// **Known problems:** Any comment is taken as a justification. rustc does not accept
+/// `reason = "..."` in lint attributes yet, so a comment is the only way for now.
+///
+/// **Example:**
+/// ```rust
+/// #[allow(needless_range_loop)]
+/// fn foo() { .. }
+/// ```
+declare_restriction_lint! {
+    pub ALLOW_ATTRIBUTES_WITHOUT_REASON,
+    "`#[allow(..)]` of Clippy lints without a justification"
+}
+
 /// **What it does:** Checks for `#[deprecated]` annotations with a `since`
 /// field that is not a valid semantic version.
 ///
@@ -80,13 +102,17 @@ pub struct AttrPass;
 
 impl LintPass for AttrPass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(INLINE_ALWAYS, DEPRECATED_SEMVER, USELESS_ATTRIBUTE)
+        lint_array!(INLINE_ALWAYS, DEPRECATED_SEMVER, USELESS_ATTRIBUTE, ALLOW_ATTRIBUTES_WITHOUT_REASON)
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AttrPass {
     fn check_attribute(&mut self, cx: &LateContext<'a, 'tcx>, attr: &'tcx Attribute) {
         if let Some(ref items) = attr.meta_item_list() {
+            if attr.name().map_or(false, |n| n == "allow") {
+                check_allow(cx, attr, items);
+                return;
+            }
             if items.is_empty() || attr.name().map_or(true, |n| n != "deprecated") {
                 return;
             }
@@ -130,7 +156,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AttrPass {
                                                                "useless lint attribute",
                                                                |db| {
                                                 sugg.insert(1, '!');
-                                                db.span_suggestion(attr.span, "if you just forgot a `!`, use", sugg);
+                                                db.span_suggestion_with_applicability(attr.span,
+                                                                                      "if you just forgot a `!`, use",
+                                                                                      sugg,
+                                                                                      Applicability::MaybeIncorrect);
                                             });
                                         }
                                     }
@@ -235,6 +264,96 @@ fn check_attrs(cx: &LateContext, span: Span, name: &Name, attrs: &[Attribute]) {
     }
 }
 
+/// Check that an `allow` attribute of Clippy lints is justified, and record it for the driver's
+/// report of the suppressions.
+fn check_allow(cx: &LateContext, attr: &Attribute, items: &[NestedMetaItem]) {
+    if in_macro(attr.span) {
+        return;
+    }
+    let lints: Vec<_> = items.iter()
+        .filter(|item| item.is_word())
+        .filter_map(NestedMetaItem::name)
+        .map(|name| name.as_str().to_string())
+        .filter(|name| catalogue::find(name).is_some() || catalogue::find_group(name).is_some())
+        .collect();
+    if lints.is_empty() {
+        return;
+    }
+
+    let reason = justification(cx, attr, items);
+    if reason.is_none() {
+        span_help_and_lint(cx,
+                           ALLOW_ATTRIBUTES_WITHOUT_REASON,
+                           attr.span,
+                           "this attribute allows Clippy lints without saying why",
+                           "add a comment saying why on the line above the attribute");
+    }
+    let loc = cx.sess().codemap().lookup_char_pos(attr.span.lo);
+    suppression::record(Suppression {
+        kind: Kind::Attribute,
+        lints: lints,
+        reason: reason,
+        span: attr.span,
+        target: None,
+        file_name: loc.file.name.clone(),
+        line: loc.line,
+        used: Vec::new(),
+    });
+}
+
+/// The justification of a lint attribute: its `reason = "..."` item, or else the `//` comment at
+/// the end of its last line, or else the `//` comments on the lines just above it, skipping other
+/// attributes.
+fn justification(cx: &LateContext, attr: &Attribute, items: &[NestedMetaItem]) -> Option<String> {
+    for item in items {
+        if_let_chain! {[
+            let NestedMetaItemKind::MetaItem(ref mi) = item.node,
+            mi.name() == "reason",
+            let Some(reason) = mi.value_str(),
+        ], {
+            return Some(reason.as_str().to_string());
+        }}
+    }
+
+    let codemap = cx.sess().codemap();
+    let lo = codemap.lookup_char_pos(attr.span.lo);
+    let hi = codemap.lookup_char_pos(attr.span.hi);
+    let lines: Vec<_> = if let Some(ref src) = lo.file.src {
+        src.lines().map(str::to_owned).collect()
+    } else {
+        return None;
+    };
+
+    // `line` starts at 1 and `col` counts characters
+    if let Some(line) = lines.get(hi.line - 1) {
+        let rest: String = line.chars().skip(hi.col.0).collect();
+        if let Some(start) = rest.find("//") {
+            let comment = rest[start + 2..].trim();
+            if !comment.is_empty() {
+                return Some(comment.to_owned());
+            }
+        }
+    }
+
+    let mut above = Vec::new();
+    for line in lines[..lo.line - 1].iter().rev().map(|line| line.trim()) {
+        if line.starts_with("///") || line.starts_with("//!") {
+            break;
+        } else if line.starts_with("//") {
+            above.push(line[2..].trim());
+        } else if !line.starts_with("#[") {
+            break;
+        }
+    }
+    above.reverse();
+    above.retain(|comment| !comment.is_empty());
+    if above.is_empty() {
+        None
+    } else {
+        Some(above.join(" "))
+    }
+}
+
 fn check_semver(cx: &LateContext, span: Span, lit: &Lit) {
     if let LitKind::Str(ref is, _) = lit.node {
         if Version::parse(&is.as_str()).is_ok() {
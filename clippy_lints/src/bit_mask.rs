@@ -1,10 +1,9 @@
+use consts::{constant, Constant};
 use rustc::hir::*;
-use rustc::hir::def::Def;
 use rustc::lint::*;
-use rustc_const_eval::lookup_const_by_id;
-use syntax::ast::LitKind;
 use syntax::codemap::Span;
 use utils::span_lint;
+use utils::interval::{interval, FullInt};
 
 /// **What it does:** Checks for incompatible bit masks in comparisons.
 ///
@@ -83,10 +82,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for BitMask {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
         if let ExprBinary(ref cmp, ref left, ref right) = e.node {
             if cmp.node.is_comparison() {
-                if let Some(cmp_opt) = fetch_int_literal(cx, right) {
-                    check_compare(cx, left, cmp.node, cmp_opt, &e.span)
-                } else if let Some(cmp_val) = fetch_int_literal(cx, left) {
-                    check_compare(cx, right, invert_cmp(cmp.node), cmp_val, &e.span)
+                match constant_operand(cx, left, right) {
+                    Some((cmp_val, bit_op, false)) => check_compare(cx, bit_op, cmp.node, cmp_val, &e.span),
+                    Some((cmp_val, bit_op, true)) => check_compare(cx, bit_op, invert_cmp(cmp.node), cmp_val, &e.span),
+                    None => (),
                 }
             }
         }
@@ -111,9 +110,8 @@ fn check_compare(cx: &LateContext, bit_op: &Expr, cmp_op: BinOp_, cmp_value: u12
         if op.node != BiBitAnd && op.node != BiBitOr {
             return;
         }
-        fetch_int_literal(cx, right)
-            .or_else(|| fetch_int_literal(cx, left))
-            .map_or((), |mask| check_bit_mask(cx, op.node, cmp_op, mask, cmp_value, span))
+        constant_operand(cx, left, right)
+            .map_or((), |(mask, _, _)| check_bit_mask(cx, op.node, cmp_op, mask, cmp_value, span))
     }
 }
 
@@ -236,22 +234,39 @@ fn check_ineffective_gt(cx: &LateContext, span: Span, m: u128, c: u128, op: &str
     }
 }
 
+/// The non-negative constant operand of `left op right`, the other operand, and whether the
+/// constant is `left`. Literals and constants are preferred to the values the interval analysis
+/// finds, like immutable locals, so that `0 & x` masks with zero even if `x` is known.
+fn constant_operand<'a>(cx: &LateContext, left: &'a Expr, right: &'a Expr) -> Option<(u128, &'a Expr, bool)> {
+    if let Some(value) = fetch_int_literal(cx, right) {
+        Some((value, left, false))
+    } else if let Some(value) = fetch_int_literal(cx, left) {
+        Some((value, right, true))
+    } else if let Some(value) = fetch_int_value(cx, right) {
+        Some((value, left, false))
+    } else if let Some(value) = fetch_int_value(cx, left) {
+        Some((value, right, true))
+    } else {
+        None
+    }
+}
+
 fn fetch_int_literal(cx: &LateContext, lit: &Expr) -> Option<u128> {
-    use rustc::ty::subst::Substs;
-    match lit.node {
-        ExprLit(ref lit_ptr) => {
-            if let LitKind::Int(value, _) = lit_ptr.node {
-                Some(value) // TODO: Handle sign
-            } else {
-                None
-            }
-        },
-        ExprPath(ref qpath) => {
-            let def = cx.tables.qpath_def(qpath, lit.id);
-            if let Def::Const(def_id) = def {
-                lookup_const_by_id(cx.tcx, def_id, Substs::empty()).and_then(|(l, _ty)| fetch_int_literal(cx, l))
-            } else {
-                None
+    match constant(cx, lit) {
+        Some((Constant::Int(value), _)) if !value.is_negative() => Some(value.to_u128_unchecked()),
+        _ => None,
+    }
+}
+
+/// The value of `e` if the interval analysis finds it is a non-negative constant.
+#[allow(cast_sign_loss)]
+fn fetch_int_value(cx: &LateContext, e: &Expr) -> Option<u128> {
+    match interval(cx, e) {
+        Some(range) if range.is_constant() => {
+            match range.min {
+                FullInt::U(value) => Some(value),
+                FullInt::S(value) if value >= 0 => Some(value as u128),
+                FullInt::S(_) => None,
             }
         },
         _ => None,
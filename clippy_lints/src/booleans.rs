@@ -5,6 +5,7 @@ use syntax::ast::{LitKind, DUMMY_NODE_ID, NodeId};
 use syntax::codemap::{DUMMY_SP, dummy_spanned, Span};
 use syntax::util::ThinVec;
 use utils::{span_lint_and_then, in_macro, snippet_opt, SpanlessEq};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for boolean expressions that can be written more
 /// concisely.
@@ -361,9 +362,11 @@ impl<'a, 'tcx> NonminimalBoolVisitor<'a, 'tcx> {
                             db.span_help(h2q.terminals[i].span,
                                          "this expression can be optimized out by applying boolean operations to the \
                                           outer expression");
-                            db.span_suggestion(e.span,
-                                               "it would look like the following",
-                                               suggest(self.cx, suggestion, &h2q.terminals));
+                            db.span_suggestion_with_applicability(e.span,
+                                                                  "it would look like the following",
+                                                                  suggest(self.cx, suggestion, &h2q.terminals),
+                                                                  // the expression does something else now
+                                                                  Applicability::MaybeIncorrect);
                         });
                         // don't also lint `NONMINIMAL_BOOL`
                         return;
@@ -385,7 +388,11 @@ impl<'a, 'tcx> NonminimalBoolVisitor<'a, 'tcx> {
                                    e.span,
                                    "this boolean expression can be simplified",
                                    |db| for suggestion in &improvements {
-                                       db.span_suggestion(e.span, "try", suggest(self.cx, suggestion, &h2q.terminals));
+                                       let sugg = suggest(self.cx, suggestion, &h2q.terminals);
+                                       db.span_suggestion_with_applicability(e.span,
+                                                                             "try",
+                                                                             sugg,
+                                                                             Applicability::MachineApplicable);
                                    });
             }
         }
@@ -15,8 +15,8 @@
 use rustc::lint::*;
 use syntax::ast;
 
-use utils::{in_macro, snippet_block, span_lint_and_then};
-use utils::sugg::Sugg;
+use utils::{in_macro, snippet_block_with_applicability, span_lint_and_then};
+use utils::sugg::{Applicability, Sugg, SuggestionExt};
 
 /// **What it does:** Checks for nested `if` statements which can be collapsed
 /// by `&&`-combining their conditions and for `else { if ... }` expressions that
@@ -112,7 +112,9 @@ fn check_collapsible_maybe_if_let(cx: &EarlyContext, else_: &ast::Expr) {
                                    COLLAPSIBLE_IF,
                                    block.span,
                                    "this `else { if .. }` block can be collapsed", |db| {
-                    db.span_suggestion(block.span, "try", snippet_block(cx, else_.span, "..").into_owned());
+                    let mut applicability = Applicability::MachineApplicable;
+                    let sugg = snippet_block_with_applicability(cx, else_.span, "..", &mut applicability);
+                    db.span_suggestion_with_applicability(block.span, "try", sugg.into_owned(), applicability);
                 });
             }
             _ => (),
@@ -129,13 +131,14 @@ fn check_collapsible_no_if_let(cx: &EarlyContext, expr: &ast::Expr, check: &ast:
             return;
         }
         span_lint_and_then(cx, COLLAPSIBLE_IF, expr.span, "this if statement can be collapsed", |db| {
-            let lhs = Sugg::ast(cx, check, "..");
-            let rhs = Sugg::ast(cx, check_inner, "..");
-            db.span_suggestion(expr.span,
-                               "try",
-                               format!("if {} {}",
-                                       lhs.and(rhs),
-                                       snippet_block(cx, content.span, "..")));
+            let mut applicability = Applicability::MachineApplicable;
+            let lhs = Sugg::ast_with_applicability(cx, check, "..", &mut applicability);
+            let rhs = Sugg::ast_with_applicability(cx, check_inner, "..", &mut applicability);
+            let content = snippet_block_with_applicability(cx, content.span, "..", &mut applicability);
+            db.span_suggestion_with_applicability(expr.span,
+                                                  "try",
+                                                  format!("if {} {}", lhs.and(rhs), content),
+                                                  applicability);
         });
     }}
 }
@@ -2,17 +2,22 @@
 
 use rustc::lint::LateContext;
 use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
 use rustc_const_eval::lookup_const_by_id;
 use rustc_const_math::ConstInt;
 use rustc::hir::*;
 use rustc::ty::{self, TyCtxt};
-use std::cmp::Ordering::{self, Equal};
+use std::cmp::Ordering::{self, Equal, Greater, Less};
 use std::cmp::PartialOrd;
+use std::fmt::Display;
 use std::hash::{Hash, Hasher};
 use std::mem;
+use std::ops::{Add, Div, Mul, Rem, Sub};
 use std::rc::Rc;
-use syntax::ast::{FloatTy, LitKind, StrStyle, NodeId};
+use std::str::FromStr;
+use syntax::ast::{FloatTy, LitKind, Name, StrStyle, NodeId, UintTy};
 use syntax::ptr::P;
+use utils::{is_immutable_binding, let_init, walk_ptrs_ty};
 
 #[derive(Debug, Copy, Clone)]
 pub enum FloatWidth {
@@ -220,11 +225,156 @@ fn neg_float_str(s: &str) -> String {
     }
 }
 
+/// parse the value of a float constant, which keeps the underscores of its literal
+fn parse_float<T: FromStr>(s: &str) -> Option<T> {
+    s.replace('_', "").parse().ok()
+}
+
+/// the value of a float constant of type `ty`, computed at the width of the type
+fn float_value(s: &str, ty: FloatTy) -> Option<f64> {
+    match ty {
+        FloatTy::F32 => parse_float::<f32>(s).map(f64::from),
+        FloatTy::F64 => parse_float::<f64>(s),
+    }
+}
+
+/// an integer of type `ty` from the two’s complement representation of `bits`, truncated like
+/// `as` does
+#[allow(cast_possible_wrap)]
+fn int_from_bits<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, bits: u128, ty: ty::Ty<'tcx>) -> Option<ConstInt> {
+    match ty.sty {
+        ty::TyInt(ity) => Some(ConstInt::new_signed_truncating(bits as i128, ity, tcx.sess.target.int_type)),
+        ty::TyUint(uty) => Some(ConstInt::new_unsigned_truncating(bits, uty, tcx.sess.target.uint_type)),
+        _ => None,
+    }
+}
+
+/// the integer `value` as a float of type `ty`, rounded like `as` does
+#[allow(cast_possible_wrap, cast_precision_loss)]
+fn int_to_float(value: ConstInt, ty: FloatTy) -> Constant {
+    let bits = value.to_u128_unchecked();
+    let s = match (ty, value.is_negative()) {
+        (FloatTy::F32, false) => (bits as f32).to_string(),
+        (FloatTy::F32, true) => (bits as i128 as f32).to_string(),
+        (FloatTy::F64, false) => (bits as f64).to_string(),
+        (FloatTy::F64, true) => (bits as i128 as f64).to_string(),
+    };
+    Constant::Float(s, ty.into())
+}
+
+/// the float `value` as an integer of type `ty`, rounded towards zero like `as` does, `None` if
+/// the value does not fit in the type
+#[allow(cast_precision_loss, cast_sign_loss)]
+fn float_to_int<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, value: f64, ty: ty::Ty<'tcx>) -> Option<ConstInt> {
+    let truncated = value.trunc();
+    let bits = if truncated >= 0.0 && truncated < u128::max_value() as f64 {
+        truncated as u128
+    } else if truncated < 0.0 && truncated >= i128::min_value() as f64 {
+        truncated as i128 as u128
+    } else {
+        // NaN or out of any range
+        return None;
+    };
+    int_from_bits(tcx, bits, ty).and_then(|int| if int.to_u128_unchecked() == bits &&
+                                                   int.is_negative() == (truncated < 0.0) {
+        Some(int)
+    } else {
+        None
+    })
+}
+
+/// the value of `cst as ty` where `cst` is of type `from`, `None` if this is not a cast between
+/// numbers, `bool`s and `char`s or if it would not be allowed in a constant
+fn constant_cast<'a, 'tcx>(
+    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+    cst: Constant,
+    from: ty::Ty<'tcx>,
+    ty: ty::Ty<'tcx>
+) -> Option<Constant> {
+    use self::Constant::*;
+    match (cst, &from.sty, &ty.sty) {
+        (Int(value), _, &ty::TyFloat(fty)) => Some(int_to_float(value, fty)),
+        (Int(ConstInt::U8(b)), _, &ty::TyChar) => Some(Char(b as char)),
+        (Int(value), _, _) => int_from_bits(tcx, value.to_u128_unchecked(), ty).map(Int),
+        (Bool(b), _, _) => int_from_bits(tcx, b as u128, ty).map(Int),
+        (Char(c), _, _) => int_from_bits(tcx, c as u128, ty).map(Int),
+        (Float(ref s, _), &ty::TyFloat(fty), &ty::TyFloat(FloatTy::F32)) => {
+            float_value(s, fty).map(|f| Float((f as f32).to_string(), FloatWidth::F32))
+        },
+        (Float(ref s, _), &ty::TyFloat(fty), &ty::TyFloat(FloatTy::F64)) => {
+            float_value(s, fty).map(|f| Float(f.to_string(), FloatWidth::F64))
+        },
+        (Float(ref s, _), &ty::TyFloat(fty), _) => {
+            float_value(s, fty).and_then(|f| float_to_int(tcx, f, ty)).map(Int)
+        },
+        _ => None,
+    }
+}
+
+/// compare two constants of a totally ordered type
+fn compare<T: Ord>(op: BinOp_, l: &T, r: &T) -> Option<Constant> {
+    let ordering = l.cmp(r);
+    let value = match op {
+        BiEq => ordering == Equal,
+        BiNe => ordering != Equal,
+        BiLt => ordering == Less,
+        BiLe => ordering != Greater,
+        BiGt => ordering == Greater,
+        BiGe => ordering != Less,
+        _ => return None,
+    };
+    Some(Constant::Bool(value))
+}
+
+/// fold an operation on two floats, computed with the type `T` they have
+fn float_binop<T>(op: BinOp_, l: &str, r: &str, width: FloatWidth) -> Option<Constant>
+    where T: Copy + Display + FromStr + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T> +
+             Div<Output = T> + Rem<Output = T>
+{
+    let (l, r) = if let (Some(l), Some(r)) = (parse_float::<T>(l), parse_float::<T>(r)) {
+        (l, r)
+    } else {
+        return None;
+    };
+    let value = match op {
+        BiAdd => l + r,
+        BiSub => l - r,
+        BiMul => l * r,
+        BiDiv => l / r,
+        BiRem => l % r,
+        // comparisons with NaN are all false but `!=`, so they can't use `partial_cmp`
+        BiEq => return Some(Constant::Bool(l == r)),
+        BiNe => return Some(Constant::Bool(l != r)),
+        BiLt => return Some(Constant::Bool(l < r)),
+        BiLe => return Some(Constant::Bool(l <= r)),
+        BiGt => return Some(Constant::Bool(l > r)),
+        BiGe => return Some(Constant::Bool(l >= r)),
+        _ => return None,
+    };
+    Some(Constant::Float(value.to_string(), width))
+}
+
 pub fn constant(lcx: &LateContext, e: &Expr) -> Option<(Constant, bool)> {
     let mut cx = ConstEvalLateContext {
         tcx: lcx.tcx,
         tables: lcx.tables,
         needed_resolution: false,
+        locals: false,
+    };
+    cx.expr(e).map(|cst| (cst, cx.needed_resolution))
+}
+
+/// like `constant`, but also folds the locals bound by an immutable `let` with a constant
+/// initializer, like `x` in `let x = 4;`, which count as a resolution
+///
+/// This is not what `SpanlessEq` wants: two locals that happen to have the same value are not the
+/// same expression.
+pub fn constant_with_locals(lcx: &LateContext, e: &Expr) -> Option<(Constant, bool)> {
+    let mut cx = ConstEvalLateContext {
+        tcx: lcx.tcx,
+        tables: lcx.tables,
+        needed_resolution: false,
+        locals: true,
     };
     cx.expr(e).map(|cst| (cst, cx.needed_resolution))
 }
@@ -237,6 +387,8 @@ struct ConstEvalLateContext<'a, 'tcx: 'a> {
     tcx: TyCtxt<'a, 'tcx, 'tcx>,
     tables: &'a ty::TypeckTables<'tcx>,
     needed_resolution: bool,
+    /// whether to fold immutable locals with a constant initializer
+    locals: bool,
 }
 
 impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
@@ -264,6 +416,18 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
                 })
             },
             ExprBinary(op, ref left, ref right) => self.binop(op, left, right),
+            ExprCast(ref value, _) => {
+                let (tcx, from, ty) = (self.tcx, self.tables.expr_ty(value), self.tables.expr_ty(e));
+                self.expr(value).and_then(|cst| constant_cast(tcx, cst, from, ty))
+            },
+            ExprMethodCall(ref method, _, ref args) => self.method_call(method.node, args),
+            ExprTupField(ref tup, ref index) => {
+                match self.expr(tup) {
+                    Some(Constant::Tuple(fields)) => fields.into_iter().nth(index.node),
+                    _ => None,
+                }
+            },
+            ExprIndex(ref array, ref index) => self.index(array, index),
             // TODO: add other expressions
             _ => None,
         }
@@ -291,6 +455,7 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
                         tcx: self.tcx,
                         tables: tables,
                         needed_resolution: false,
+                        locals: false,
                     };
                     let ret = cx.expr(const_expr);
                     if ret.is_some() {
@@ -299,11 +464,32 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
                     return ret;
                 }
             },
+            Def::Local(def_id) if self.locals => {
+                let ret = self.local(def_id);
+                if ret.is_some() {
+                    self.needed_resolution = true;
+                }
+                return ret;
+            },
             _ => {},
         }
         None
     }
 
+    /// the value of a local bound by an immutable `let` with a constant initializer, like
+    /// `let x = 42;`
+    fn local(&mut self, def_id: DefId) -> Option<Constant> {
+        let tcx = self.tcx;
+        if_let_chain! {[
+            let Some(id) = tcx.hir.as_local_node_id(def_id),
+            is_immutable_binding(&tcx.hir, id),
+            let Some(init) = let_init(&tcx.hir, id),
+        ], {
+            return self.expr(init);
+        }}
+        None
+    }
+
     /// A block can only yield a constant if it only has one constant expression
     fn block(&mut self, block: &Block) -> Option<Constant> {
         if block.stmts.is_empty() {
@@ -325,6 +511,42 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
         }
     }
 
+    /// the `len()` of constant strings, byte strings and arrays
+    fn method_call(&mut self, name: Name, args: &[Expr]) -> Option<Constant> {
+        if name != "len" || args.len() != 1 {
+            return None;
+        }
+        // only the inherent methods, not a `len` of some trait implemented for them
+        match walk_ptrs_ty(self.tables.expr_ty(&args[0])).sty {
+            ty::TyStr | ty::TyArray(..) | ty::TySlice(_) => (),
+            _ => return None,
+        }
+        let len = match self.expr(&args[0]) {
+            Some(Constant::Str(s, _)) => s.len(),
+            Some(Constant::Binary(b)) => b.len(),
+            Some(Constant::Vec(v)) => v.len(),
+            Some(Constant::Repeat(_, n)) => n,
+            _ => return None,
+        };
+        let uint_type = self.tcx.sess.target.uint_type;
+        Some(Constant::Int(ConstInt::new_unsigned_truncating(len as u128, UintTy::Us, uint_type)))
+    }
+
+    /// an element of a constant array or byte string, `None` if the index is out of bounds
+    fn index(&mut self, array: &Expr, index_expr: &Expr) -> Option<Constant> {
+        // indices are `usize`s
+        let index = match self.expr(index_expr) {
+            Some(Constant::Int(i)) if !i.is_negative() => i.to_u128_unchecked() as usize,
+            _ => return None,
+        };
+        match self.expr(array) {
+            Some(Constant::Vec(v)) => v.into_iter().nth(index),
+            Some(Constant::Repeat(value, n)) => if index < n { Some(*value) } else { None },
+            Some(Constant::Binary(bytes)) => bytes.get(index).map(|&byte| Constant::Int(ConstInt::U8(byte))),
+            _ => None,
+        }
+    }
+
     fn binop(&mut self, op: BinOp, left: &Expr, right: &Expr) -> Option<Constant> {
         let l = if let Some(l) = self.expr(left) {
             l
@@ -350,12 +572,17 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
             (BiBitOr, Constant::Int(l), Some(Constant::Int(r))) => (l | r).ok().map(Constant::Int),
             (BiShl, Constant::Int(l), Some(Constant::Int(r))) => (l << r).ok().map(Constant::Int),
             (BiShr, Constant::Int(l), Some(Constant::Int(r))) => (l >> r).ok().map(Constant::Int),
-            (BiEq, Constant::Int(l), Some(Constant::Int(r))) => Some(Constant::Bool(l == r)),
-            (BiNe, Constant::Int(l), Some(Constant::Int(r))) => Some(Constant::Bool(l != r)),
-            (BiLt, Constant::Int(l), Some(Constant::Int(r))) => Some(Constant::Bool(l < r)),
-            (BiLe, Constant::Int(l), Some(Constant::Int(r))) => Some(Constant::Bool(l <= r)),
-            (BiGe, Constant::Int(l), Some(Constant::Int(r))) => Some(Constant::Bool(l >= r)),
-            (BiGt, Constant::Int(l), Some(Constant::Int(r))) => Some(Constant::Bool(l > r)),
+            (kind, Constant::Int(l), Some(Constant::Int(r))) => compare(kind, &l, &r),
+            (kind, Constant::Bool(l), Some(Constant::Bool(r))) => compare(kind, &l, &r),
+            (kind, Constant::Char(l), Some(Constant::Char(r))) => compare(kind, &l, &r),
+            (kind, Constant::Str(ref l, _), Some(Constant::Str(ref r, _))) => compare(kind, l, r),
+            (kind, Constant::Float(ref l, _), Some(Constant::Float(ref r, _))) => {
+                match self.tables.expr_ty(left).sty {
+                    ty::TyFloat(FloatTy::F32) => float_binop::<f32>(kind, l, r, FloatWidth::F32),
+                    ty::TyFloat(FloatTy::F64) => float_binop::<f64>(kind, l, r, FloatWidth::F64),
+                    _ => None,
+                }
+            },
             _ => None,
         }
     }
@@ -1,5 +1,6 @@
 use syntax::ast::*;
-use rustc::lint::{EarlyContext, LintContext, LintArray, LintPass, EarlyLintPass};
+use rustc::lint::{EarlyContext, LintArray, LintPass, EarlyLintPass};
+use utils::span_lint;
 
 /// **What it does:** Checks for unnecessary double parentheses.
 ///
@@ -35,7 +36,7 @@ impl EarlyLintPass for DoubleParens {
                 match in_paren.node {
                     ExprKind::Paren(_) |
                     ExprKind::Tup(_) => {
-                        cx.span_lint(DOUBLE_PARENS, expr.span, "Consider removing unnecessary double parentheses");
+                        span_lint(cx, DOUBLE_PARENS, expr.span, "Consider removing unnecessary double parentheses");
                     },
                     _ => {},
                 }
@@ -44,7 +45,7 @@ impl EarlyLintPass for DoubleParens {
                 if params.len() == 1 {
                     let param = &params[0];
                     if let ExprKind::Paren(_) = param.node {
-                        cx.span_lint(DOUBLE_PARENS, param.span, "Consider removing unnecessary double parentheses");
+                        span_lint(cx, DOUBLE_PARENS, param.span, "Consider removing unnecessary double parentheses");
                     }
                 }
             },
@@ -52,7 +53,7 @@ impl EarlyLintPass for DoubleParens {
                 if params.len() == 2 {
                     let param = &params[1];
                     if let ExprKind::Paren(_) = param.node {
-                        cx.span_lint(DOUBLE_PARENS, param.span, "Consider removing unnecessary double parentheses");
+                        span_lint(cx, DOUBLE_PARENS, param.span, "Consider removing unnecessary double parentheses");
                     }
                 }
             },
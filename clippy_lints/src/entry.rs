@@ -3,7 +3,8 @@ use rustc::hir::intravisit::{Visitor, walk_expr, NestedVisitorMap};
 use rustc::lint::*;
 use syntax::codemap::Span;
 use utils::SpanlessEq;
-use utils::{get_item_name, match_type, paths, snippet, span_lint_and_then, walk_ptrs_ty};
+use utils::{get_item_name, match_type, paths, snippet_with_applicability, span_lint_and_then, walk_ptrs_ty};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for uses of `contains_key` + `insert` on `HashMap`
 /// or `BTreeMap`.
@@ -132,19 +133,22 @@ impl<'a, 'tcx, 'b> Visitor<'tcx> for InsertVisitor<'a, 'tcx, 'b> {
             span_lint_and_then(self.cx, MAP_ENTRY, self.span,
                                &format!("usage of `contains_key` followed by `insert` on a `{}`", self.ty), |db| {
                 if self.sole_expr {
+                    let mut app = Applicability::MachineApplicable;
                     let help = format!("{}.entry({}).or_insert({})",
-                                       snippet(self.cx, self.map.span, "map"),
-                                       snippet(self.cx, params[1].span, ".."),
-                                       snippet(self.cx, params[2].span, ".."));
+                                       snippet_with_applicability(self.cx, self.map.span, "map", &mut app),
+                                       snippet_with_applicability(self.cx, params[1].span, "..", &mut app),
+                                       snippet_with_applicability(self.cx, params[2].span, "..", &mut app));
 
-                    db.span_suggestion(self.span, "consider using", help);
+                    db.span_suggestion_with_applicability(self.span, "consider using", help, app);
                 }
                 else {
+                    // the rest of the block still has to be moved into the entry
+                    let mut app = Applicability::HasPlaceholders;
                     let help = format!("{}.entry({})",
-                                       snippet(self.cx, self.map.span, "map"),
-                                       snippet(self.cx, params[1].span, ".."));
+                                       snippet_with_applicability(self.cx, self.map.span, "map", &mut app),
+                                       snippet_with_applicability(self.cx, params[1].span, "..", &mut app));
 
-                    db.span_suggestion(self.span, "consider using", help);
+                    db.span_suggestion_with_applicability(self.span, "consider using", help, app);
                 }
             });
         }}
@@ -1,7 +1,7 @@
 use rustc::hir::*;
 use rustc::lint::*;
-use utils::{SpanlessEq, span_lint, span_lint_and_then, multispan_sugg, snippet};
-use utils::sugg::Sugg;
+use utils::{SpanlessEq, span_lint, span_lint_and_then, multispan_sugg, snippet_with_applicability};
+use utils::sugg::{Applicability, Sugg};
 
 /// **What it does:** Checks for equal operands to comparison, logical and
 /// bitwise, difference and division binary operators (`==`, `>`, etc., `&&`,
@@ -67,12 +67,14 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for EqOp {
                                 e.span,
                                 "taken reference of both operands, which is done automatically by the operator anyway",
                                 |db| {
-                                    let lsnip = snippet(cx, l.span, "...").to_string();
-                                    let rsnip = snippet(cx, r.span, "...").to_string();
+                                    let mut app = Applicability::MachineApplicable;
+                                    let lsnip = snippet_with_applicability(cx, l.span, "...", &mut app).to_string();
+                                    let rsnip = snippet_with_applicability(cx, r.span, "...", &mut app).to_string();
                                     multispan_sugg(db,
                                                 "use the values directly".to_string(),
                                                 vec![(left.span, lsnip),
-                                                     (right.span, rsnip)]);
+                                                     (right.span, rsnip)],
+                                                app);
                                 }
                             )
                         }
@@ -82,12 +84,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for EqOp {
                                 e.span,
                                 "taken reference of left operand",
                                 |db| {
-                                    let lsnip = snippet(cx, l.span, "...").to_string();
-                                    let rsnip = Sugg::hir(cx, right, "...").deref().to_string();
+                                    let mut app = Applicability::MachineApplicable;
+                                    let lsnip = snippet_with_applicability(cx, l.span, "...", &mut app).to_string();
+                                    let rsnip = Sugg::hir_with_applicability(cx, right, "...", &mut app)
+                                        .deref()
+                                        .to_string();
                                     multispan_sugg(db,
                                                 "dereference the right operand instead".to_string(),
                                                 vec![(left.span, lsnip),
-                                                     (right.span, rsnip)]);
+                                                     (right.span, rsnip)],
+                                                app);
                                 }
                             )
                         }
@@ -97,12 +103,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for EqOp {
                                 e.span,
                                 "taken reference of right operand",
                                 |db| {
-                                    let lsnip = Sugg::hir(cx, left, "...").deref().to_string();
-                                    let rsnip = snippet(cx, r.span, "...").to_string();
+                                    let mut app = Applicability::MachineApplicable;
+                                    let lsnip = Sugg::hir_with_applicability(cx, left, "...", &mut app)
+                                        .deref()
+                                        .to_string();
+                                    let rsnip = snippet_with_applicability(cx, r.span, "...", &mut app).to_string();
                                     multispan_sugg(db,
                                                 "dereference the left operand instead".to_string(),
                                                 vec![(left.span, lsnip),
-                                                     (right.span, rsnip)]);
+                                                     (right.span, rsnip)],
+                                                app);
                                 }
                             )
                         }
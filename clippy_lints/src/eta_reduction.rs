@@ -2,6 +2,7 @@ use rustc::lint::*;
 use rustc::ty;
 use rustc::hir::*;
 use utils::{snippet_opt, span_lint_and_then, is_adjusted, iter_input_pats};
+use utils::sugg::{Applicability, SuggestionExt};
 
 #[allow(missing_copy_implementations)]
 pub struct EtaPass;
@@ -97,7 +98,10 @@ fn check_closure(cx: &LateContext, expr: &Expr) {
                                expr.span,
                                "redundant closure found",
                                |db| if let Some(snippet) = snippet_opt(cx, caller.span) {
-                                   db.span_suggestion(expr.span, "remove closure as shown:", snippet);
+                                   db.span_suggestion_with_applicability(expr.span,
+                                                                         "remove closure as shown:",
+                                                                         snippet,
+                                                                         Applicability::MachineApplicable);
                                });
         }
     }
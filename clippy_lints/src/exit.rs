@@ -0,0 +1,41 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use utils::{match_def_path, paths, resolve_node, span_lint};
+
+/// **What it does:** Checks for calls to `std::process::exit`.
+///
+/// **Why is this bad?** `process::exit` ends the whole process without running
+/// the destructors, taking the decision away from the caller. A library should
+/// return an error instead.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// std::process::exit(1)
+/// ```
+declare_restriction_lint! {
+    pub EXIT,
+    "usage of `std::process::exit`"
+}
+
+#[allow(missing_copy_implementations)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXIT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_let_chain! {[
+            let ExprCall(ref fun, _) = expr.node,
+            let ExprPath(ref qpath) = fun.node,
+            match_def_path(cx.tcx, resolve_node(cx, qpath, fun.id).def_id(), &paths::EXIT)
+        ], {
+            span_lint(cx, EXIT, expr.span, "use of `process::exit`");
+        }}
+    }
+}
@@ -0,0 +1,84 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use utils::{higher, in_macro, is_expn_of, match_type, paths, span_lint};
+
+/// **What it does:** Checks for heap allocations: `box` expressions, `vec!`
+/// and calls returning a `Box`, `Rc`, `Arc`, `String` or a collection.
+///
+/// **Why is this bad?** Some targets have no heap, or only a small one that
+/// should not be allocated from after startup.
+///
+/// **Known problems:** Calls are linted by the type they return, so
+/// constructors like `Vec::new()` that do not allocate yet are linted as well,
+/// and allocations hidden in other types are not.
+///
+/// **Example:**
+/// ```rust
+/// let buffer = vec![0u8; 1024];
+/// ```
+declare_restriction_lint! {
+    pub HEAP_ALLOCATION,
+    "usage of `box`, `vec!` or calls returning a `Box`, `Rc`, `Arc`, `String` or collection"
+}
+
+/// The heap-allocated types besides `Box` whose construction is linted, with their name in the
+/// message.
+const HEAP_TYPES: [(&'static [&'static str], &'static str); 11] = [
+    (&paths::ARC, "Arc"),
+    (&paths::BINARY_HEAP, "BinaryHeap"),
+    (&paths::BTREEMAP, "BTreeMap"),
+    (&paths::BTREESET, "BTreeSet"),
+    (&paths::HASHMAP, "HashMap"),
+    (&paths::HASHSET, "HashSet"),
+    (&paths::LINKED_LIST, "LinkedList"),
+    (&paths::RC, "Rc"),
+    (&paths::STRING, "String"),
+    (&paths::VEC, "Vec"),
+    (&paths::VEC_DEQUE, "VecDeque"),
+];
+
+#[allow(missing_copy_implementations)]
+pub struct HeapAllocation;
+
+impl LintPass for HeapAllocation {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(HEAP_ALLOCATION)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for HeapAllocation {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if higher::vec_macro(cx, expr).is_some() {
+            if let Some(span) = is_expn_of(expr.span, "vec") {
+                if !in_macro(span) {
+                    span_lint(cx, HEAP_ALLOCATION, span, "`vec!` allocates on the heap");
+                }
+            }
+            return;
+        }
+        if in_macro(expr.span) {
+            return;
+        }
+        match expr.node {
+            ExprBox(_) => span_lint(cx, HEAP_ALLOCATION, expr.span, "`box` allocates on the heap"),
+            ExprCall(..) | ExprMethodCall(..) => {
+                if let Some(name) = heap_type(cx, cx.tables.expr_ty(expr)) {
+                    span_lint(cx,
+                              HEAP_ALLOCATION,
+                              expr.span,
+                              &format!("this call returns a `{}`, which allocates on the heap", name));
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Return the name of `ty` if it is a `Box` or one of the `HEAP_TYPES`.
+fn heap_type(cx: &LateContext, ty: ty::Ty) -> Option<&'static str> {
+    if ty.is_box() {
+        return Some("Box");
+    }
+    HEAP_TYPES.iter().find(|&&(path, _)| match_type(cx, ty, path)).map(|&(_, name)| name)
+}
@@ -1,7 +1,8 @@
 use rustc::lint::*;
 use rustc::hir::*;
 use syntax::codemap::Span;
-use utils::{paths, span_lint_and_then, match_path, snippet};
+use utils::{paths, span_lint_and_then, match_path, snippet_with_applicability};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:*** Lint for redundant pattern matching over `Result` or `Option`
 ///
@@ -77,7 +78,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                         hi: op.span.hi,
                         ctxt: expr.span.ctxt,
                     };
-                    db.span_suggestion(span, "try this", format!("if {}.{}", snippet(cx, op.span, "_"), good_method));
+                    let mut app = Applicability::MachineApplicable;
+                    let sugg = format!("if {}.{}", snippet_with_applicability(cx, op.span, "_", &mut app), good_method);
+                    db.span_suggestion_with_applicability(span, "try this", sugg, app);
                 });
             }
 
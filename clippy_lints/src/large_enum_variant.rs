@@ -3,6 +3,7 @@
 use rustc::lint::*;
 use rustc::hir::*;
 use utils::{span_lint_and_then, snippet_opt, type_size};
+use utils::sugg::{Applicability, SuggestionExt};
 use rustc::ty::TypeFoldable;
 
 /// **What it does:** Checks for large size differences between variants on `enum`s.
@@ -89,10 +90,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
                                 VariantData::Unit(_) => unreachable!(),
                             };
                             if let Some(snip) = snippet_opt(cx, span) {
-                                db.span_suggestion(span,
-                                                   "consider boxing the large fields to reduce the total size of the \
-                                                    enum",
-                                                   format!("Box<{}>", snip));
+                                // the uses of the variant have to box the field too
+                                db.span_suggestion_with_applicability(span,
+                                                                      "consider boxing the large fields to reduce the \
+                                                                       total size of the enum",
+                                                                      format!("Box<{}>", snip),
+                                                                      Applicability::MaybeIncorrect);
                                 return;
                             }
                         }
@@ -4,7 +4,8 @@ use rustc::ty;
 use rustc::hir::*;
 use syntax::ast::{Lit, LitKind, Name};
 use syntax::codemap::{Span, Spanned};
-use utils::{get_item_name, in_macro, snippet, span_lint, span_lint_and_then, walk_ptrs_ty};
+use utils::{get_item_name, in_macro, snippet_with_applicability, span_lint, span_lint_and_then, walk_ptrs_ty};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for getting the length of something via `.len()`
 /// just to compare to zero, and suggests using `.is_empty()` where applicable.
@@ -172,9 +173,12 @@ fn check_len_zero(cx: &LateContext, span: Span, name: Name, args: &[Expr], lit:
     if let Spanned { node: LitKind::Int(0, _), .. } = *lit {
         if name == "len" && args.len() == 1 && has_is_empty(cx, &args[0]) {
             span_lint_and_then(cx, LEN_ZERO, span, "length comparison to zero", |db| {
-                db.span_suggestion(span,
-                                   "consider using `is_empty`",
-                                   format!("{}{}.is_empty()", op, snippet(cx, args[0].span, "_")));
+                let mut app = Applicability::MachineApplicable;
+                let recv = snippet_with_applicability(cx, args[0].span, "_", &mut app);
+                db.span_suggestion_with_applicability(span,
+                                                      "consider using `is_empty`",
+                                                      format!("{}{}.is_empty()", op, recv),
+                                                      app);
             });
         }
     }
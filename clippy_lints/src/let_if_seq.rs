@@ -2,6 +2,7 @@ use rustc::lint::*;
 use rustc::hir;
 use syntax_pos::{Span, NO_EXPANSION};
 use utils::{snippet, span_lint_and_then};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for variable declarations immediately followed by a
 /// conditional affectation.
@@ -116,9 +117,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LetIfSeq {
                                    span,
                                    "`if _ { .. } else { .. }` is an expression",
                                    |db| {
-                                       db.span_suggestion(span,
-                                                          "it is more idiomatic to write",
-                                                          sug);
+                                       db.span_suggestion_with_applicability(span,
+                                                                             "it is more idiomatic to write",
+                                                                             sug,
+                                                                             Applicability::HasPlaceholders);
                                        if !mutability.is_empty() {
                                            db.note("you might not need `mut` at all");
                                        }
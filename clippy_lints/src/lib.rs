@@ -81,9 +81,11 @@ pub mod eq_op;
 pub mod escape;
 pub mod eta_reduction;
 pub mod eval_order_dependence;
+pub mod exit;
 pub mod format;
 pub mod formatting;
 pub mod functions;
+pub mod heap_allocation;
 pub mod identity_op;
 pub mod if_let_redundant_pattern_matching;
 pub mod if_not_else;
@@ -128,6 +130,7 @@ pub mod serde;
 pub mod shadow;
 pub mod should_assert_eq;
 pub mod strings;
+pub mod suppression;
 pub mod swap;
 pub mod temporary_assignment;
 pub mod transmute;
@@ -140,13 +143,15 @@ pub mod vec;
 pub mod zero_div_zero;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
+use rustc::lint::{EarlyLintPass, LateLintPass, LintSource};
+
 mod reexport {
     pub use syntax::ast::{Name, NodeId};
 }
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
-pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
-    let conf = match utils::conf::file_from_args(reg.args()) {
+/// Read Clippy's configuration file, reporting the errors on the session of `reg`.
+pub fn read_conf(reg: &rustc_plugin::Registry) -> utils::conf::Conf {
+    match utils::conf::file_from_args(reg.args()) {
         Ok(file_name) => {
             // if the user specified a file, it must exist, otherwise default to `clippy.toml` but
             // do not require the file to exist
@@ -162,11 +167,32 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
                 }
             };
 
-            let (conf, errors) = utils::conf::read(file_name.as_ref().map(|p| p.as_ref()));
+            // the configuration file overrides the `[package.metadata.clippy]` table of the manifest
+            let manifest = match utils::conf::lookup_manifest() {
+                Ok(manifest) => manifest,
+                Err(error) => {
+                    reg.sess.struct_err(&format!("error reading Clippy's configuration from `Cargo.toml`: {}", error))
+                        .emit();
+                    None
+                }
+            };
+
+            let (conf, errors) = utils::conf::read(manifest.as_ref().map(|p| p.as_ref()),
+                                                   file_name.as_ref().map(|p| p.as_ref()));
 
             // all conf errors are non-fatal, we just use the default conf in case of error
             for error in errors {
-                reg.sess.struct_err(&format!("error reading Clippy's configuration file: {}", error)).emit();
+                let msg = format!("error reading Clippy's configuration file: {}", error.error);
+                let mut db = reg.sess.struct_err(&msg);
+                db.note(&format!("in {}", error.location()));
+                if let utils::conf::Error::UnknownKey(_) = error.error {
+                    if let Some(suggestion) = error.suggestion() {
+                        db.help(&format!("did you mean `{}`?", suggestion));
+                    }
+                    let keys: Vec<_> = utils::conf::KEYS.iter().map(|key| format!("`{}`", key)).collect();
+                    db.note(&format!("valid keys are {}", keys.join(", ")));
+                }
+                db.emit();
             }
 
             conf
@@ -177,8 +203,90 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
                     .emit();
             utils::conf::Conf::default()
         }
-    };
+    }
+}
+
+/// Report the names of the `[lints]` table of the configuration that are neither a Clippy lint
+/// nor a Clippy lint group. This must be called once all the lints are registered in `reg`.
+fn check_lint_levels(reg: &rustc_plugin::Registry, conf: &utils::conf::Conf) {
+    let mut names: Vec<String> = reg.lint_groups.keys().map(|&group| group.to_owned()).collect();
+    names.extend(reg.early_lint_passes
+                     .iter()
+                     .flat_map(|pass| pass.get_lints())
+                     .chain(reg.late_lint_passes.iter().flat_map(|pass| pass.get_lints()))
+                     .map(|lint| lint.name_lower()));
+
+    for &(ref name, _) in &conf.lints {
+        if !names.contains(name) {
+            let error = utils::conf::unknown_lint(&conf.files, name);
+            let mut err = reg.sess.struct_err(&format!("error reading Clippy's configuration file: {}", error.error));
+            err.note(&format!("in {}", error.location()));
+            if let Some(similar) = utils::conf::similar_name(name, names.iter().map(AsRef::as_ref)) {
+                err.help(&format!("did you mean `{}`?", similar));
+            }
+            err.emit();
+        }
+    }
+}
+
+/// Move the lint passes and groups of `reg` to the lint store of the session, then apply the
+/// levels of the `[lints]` table of the configuration, those of lint groups first so that the level
+/// of a lint overrides the level of its group.
+///
+/// rustc resets the levels of the lints of a pass when registering it, so the passes are registered
+/// here rather than by rustc once the plugin registrar returns. Command line flags and attributes
+/// are applied later and still take precedence. Unknown names are ignored, `check_lint_levels`
+/// reports them.
+fn set_lint_levels(reg: &mut rustc_plugin::Registry, conf: &utils::conf::Conf) {
+    let sess = reg.sess;
+    let mut store = sess.lint_store.borrow_mut();
+    for pass in reg.early_lint_passes.drain(..) {
+        store.register_early_pass(Some(sess), true, pass);
+    }
+    for pass in reg.late_lint_passes.drain(..) {
+        store.register_late_pass(Some(sess), true, pass);
+    }
+    for (name, to) in reg.lint_groups.drain() {
+        store.register_group(Some(sess), true, name, to);
+    }
 
+    let groups: Vec<_> = store.get_lint_groups().into_iter().map(|(name, _, _)| name).collect();
+    let (group_levels, lint_levels): (Vec<_>, Vec<_>) = conf.lints
+        .iter()
+        .partition(|&&(ref name, _)| groups.contains(&name.as_str()));
+    for &(ref name, level) in group_levels.into_iter().chain(lint_levels) {
+        if let Ok(ids) = store.find_lints(name) {
+            for id in ids {
+                store.set_level(id, (level, LintSource::Default));
+            }
+        }
+    }
+}
+
+/// Register an early lint pass, timed as `name`, like `methods::Pass`, if lint passes are profiled.
+fn register_early_lint_pass<P>(reg: &mut rustc_plugin::Registry, name: &'static str, pass: Box<P>)
+    where P: EarlyLintPass + 'static
+{
+    if utils::profile::is_enabled() {
+        reg.register_early_lint_pass(box utils::profile::Timed::new(name, pass));
+    } else {
+        reg.register_early_lint_pass(pass);
+    }
+}
+
+/// Register a late lint pass, timed as `name`, like `methods::Pass`, if lint passes are profiled.
+fn register_late_lint_pass<P>(reg: &mut rustc_plugin::Registry, name: &'static str, pass: Box<P>)
+    where P: for<'a, 'tcx> LateLintPass<'a, 'tcx> + 'static
+{
+    if utils::profile::is_enabled() {
+        reg.register_late_lint_pass(box utils::profile::Timed::new(name, pass));
+    } else {
+        reg.register_late_lint_pass(pass);
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+pub fn register_plugins(reg: &mut rustc_plugin::Registry, conf: &utils::conf::Conf) {
     let mut store = reg.sess.lint_store.borrow_mut();
     store.register_removed(
         "extend_from_slice",
@@ -201,111 +309,188 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         "using `string::to_string` is common even today and specialization will likely happen soon",
     );
     // end deprecated lints, do not remove this comment, it’s used in `update_lints`
+    // `set_lint_levels` borrows the store again
+    drop(store);
 
-    reg.register_late_lint_pass(box serde::Serde);
-    reg.register_early_lint_pass(box utils::internal_lints::Clippy);
-    reg.register_late_lint_pass(box utils::internal_lints::LintWithoutLintPass::default());
-    reg.register_late_lint_pass(box utils::inspector::Pass);
-    reg.register_late_lint_pass(box types::TypePass);
-    reg.register_late_lint_pass(box booleans::NonminimalBool);
-    reg.register_late_lint_pass(box eq_op::EqOp);
-    reg.register_early_lint_pass(box enum_variants::EnumVariantNames::new(conf.enum_variant_name_threshold));
-    reg.register_late_lint_pass(box enum_glob_use::EnumGlobUse);
-    reg.register_late_lint_pass(box enum_clike::UnportableVariant);
-    reg.register_late_lint_pass(box bit_mask::BitMask);
-    reg.register_late_lint_pass(box ptr::PointerPass);
-    reg.register_late_lint_pass(box needless_bool::NeedlessBool);
-    reg.register_late_lint_pass(box needless_bool::BoolComparison);
-    reg.register_late_lint_pass(box approx_const::Pass);
-    reg.register_late_lint_pass(box misc::Pass);
-    reg.register_early_lint_pass(box precedence::Precedence);
-    reg.register_late_lint_pass(box eta_reduction::EtaPass);
-    reg.register_late_lint_pass(box identity_op::IdentityOp);
-    reg.register_early_lint_pass(box items_after_statements::ItemsAfterStatements);
-    reg.register_late_lint_pass(box mut_mut::MutMut);
-    reg.register_late_lint_pass(box mut_reference::UnnecessaryMutPassed);
-    reg.register_late_lint_pass(box len_zero::LenZero);
-    reg.register_late_lint_pass(box attrs::AttrPass);
-    reg.register_early_lint_pass(box collapsible_if::CollapsibleIf);
-    reg.register_late_lint_pass(box block_in_if_condition::BlockInIfCondition);
-    reg.register_late_lint_pass(box unicode::Unicode);
-    reg.register_late_lint_pass(box strings::StringAdd);
-    reg.register_early_lint_pass(box returns::ReturnPass);
-    reg.register_late_lint_pass(box methods::Pass);
-    reg.register_late_lint_pass(box shadow::Pass);
-    reg.register_late_lint_pass(box types::LetPass);
-    reg.register_late_lint_pass(box types::UnitCmp);
-    reg.register_late_lint_pass(box loops::Pass);
-    reg.register_late_lint_pass(box lifetimes::LifetimePass);
-    reg.register_late_lint_pass(box entry::HashMapLint);
-    reg.register_late_lint_pass(box ranges::StepByZero);
-    reg.register_late_lint_pass(box types::CastPass);
-    reg.register_late_lint_pass(box types::TypeComplexityPass::new(conf.type_complexity_threshold));
-    reg.register_late_lint_pass(box matches::MatchPass);
-    reg.register_late_lint_pass(box minmax::MinMaxPass);
-    reg.register_late_lint_pass(box open_options::NonSensical);
-    reg.register_late_lint_pass(box zero_div_zero::Pass);
-    reg.register_late_lint_pass(box mutex_atomic::MutexAtomic);
-    reg.register_late_lint_pass(box needless_update::Pass);
-    reg.register_late_lint_pass(box needless_borrow::NeedlessBorrow);
-    reg.register_late_lint_pass(box no_effect::Pass);
-    reg.register_late_lint_pass(box map_clone::Pass);
-    reg.register_late_lint_pass(box temporary_assignment::Pass);
-    reg.register_late_lint_pass(box transmute::Transmute);
-    reg.register_late_lint_pass(
+    // the suppression comments must be known before any lint is emitted
+    register_early_lint_pass(reg, "suppression::Suppressions", box suppression::Suppressions);
+    register_late_lint_pass(reg, "serde::Serde", box serde::Serde);
+    register_early_lint_pass(reg, "utils::internal_lints::Clippy", box utils::internal_lints::Clippy);
+    register_late_lint_pass(reg, "utils::internal_lints::LintWithoutLintPass",
+        box utils::internal_lints::LintWithoutLintPass::default()
+    );
+    register_late_lint_pass(reg, "utils::inspector::Pass", box utils::inspector::Pass);
+    register_late_lint_pass(reg, "types::TypePass", box types::TypePass);
+    register_late_lint_pass(reg, "booleans::NonminimalBool", box booleans::NonminimalBool);
+    register_late_lint_pass(reg, "eq_op::EqOp", box eq_op::EqOp);
+    register_early_lint_pass(reg, "enum_variants::EnumVariantNames",
+        box enum_variants::EnumVariantNames::new(conf.enum_variant_name_threshold)
+    );
+    register_late_lint_pass(reg, "enum_glob_use::EnumGlobUse", box enum_glob_use::EnumGlobUse);
+    register_late_lint_pass(reg, "enum_clike::UnportableVariant", box enum_clike::UnportableVariant);
+    register_late_lint_pass(reg, "bit_mask::BitMask", box bit_mask::BitMask);
+    register_late_lint_pass(reg, "ptr::PointerPass", box ptr::PointerPass);
+    register_late_lint_pass(reg, "needless_bool::NeedlessBool", box needless_bool::NeedlessBool);
+    register_late_lint_pass(reg, "needless_bool::BoolComparison", box needless_bool::BoolComparison);
+    register_late_lint_pass(reg, "approx_const::Pass", box approx_const::Pass);
+    register_late_lint_pass(reg, "misc::Pass", box misc::Pass);
+    register_early_lint_pass(reg, "precedence::Precedence", box precedence::Precedence);
+    register_late_lint_pass(reg, "eta_reduction::EtaPass", box eta_reduction::EtaPass);
+    register_late_lint_pass(reg, "identity_op::IdentityOp", box identity_op::IdentityOp);
+    register_early_lint_pass(reg, "items_after_statements::ItemsAfterStatements",
+        box items_after_statements::ItemsAfterStatements
+    );
+    register_late_lint_pass(reg, "mut_mut::MutMut", box mut_mut::MutMut);
+    register_late_lint_pass(reg, "mut_reference::UnnecessaryMutPassed", box mut_reference::UnnecessaryMutPassed);
+    register_late_lint_pass(reg, "len_zero::LenZero", box len_zero::LenZero);
+    register_late_lint_pass(reg, "attrs::AttrPass", box attrs::AttrPass);
+    register_early_lint_pass(reg, "collapsible_if::CollapsibleIf", box collapsible_if::CollapsibleIf);
+    register_late_lint_pass(reg, "block_in_if_condition::BlockInIfCondition",
+        box block_in_if_condition::BlockInIfCondition
+    );
+    register_late_lint_pass(reg, "unicode::Unicode", box unicode::Unicode);
+    register_late_lint_pass(reg, "strings::StringAdd", box strings::StringAdd);
+    register_early_lint_pass(reg, "returns::ReturnPass", box returns::ReturnPass);
+    register_late_lint_pass(reg, "methods::Pass", box methods::Pass);
+    register_late_lint_pass(reg, "shadow::Pass", box shadow::Pass);
+    register_late_lint_pass(reg, "types::LetPass", box types::LetPass);
+    register_late_lint_pass(reg, "types::UnitCmp", box types::UnitCmp);
+    register_late_lint_pass(reg, "loops::Pass", box loops::Pass);
+    register_late_lint_pass(reg, "lifetimes::LifetimePass", box lifetimes::LifetimePass);
+    register_late_lint_pass(reg, "entry::HashMapLint", box entry::HashMapLint);
+    register_late_lint_pass(reg, "ranges::StepByZero", box ranges::StepByZero);
+    register_late_lint_pass(reg, "types::CastPass", box types::CastPass);
+    register_late_lint_pass(reg, "types::TypeComplexityPass",
+        box types::TypeComplexityPass::new(conf.type_complexity_threshold)
+    );
+    register_late_lint_pass(reg, "matches::MatchPass", box matches::MatchPass);
+    register_late_lint_pass(reg, "minmax::MinMaxPass", box minmax::MinMaxPass);
+    register_late_lint_pass(reg, "open_options::NonSensical", box open_options::NonSensical);
+    register_late_lint_pass(reg, "zero_div_zero::Pass", box zero_div_zero::Pass);
+    register_late_lint_pass(reg, "mutex_atomic::MutexAtomic", box mutex_atomic::MutexAtomic);
+    register_late_lint_pass(reg, "needless_update::Pass", box needless_update::Pass);
+    register_late_lint_pass(reg, "needless_borrow::NeedlessBorrow", box needless_borrow::NeedlessBorrow);
+    register_late_lint_pass(reg, "no_effect::Pass", box no_effect::Pass);
+    register_late_lint_pass(reg, "map_clone::Pass", box map_clone::Pass);
+    register_late_lint_pass(reg, "temporary_assignment::Pass", box temporary_assignment::Pass);
+    register_late_lint_pass(reg, "transmute::Transmute", box transmute::Transmute);
+    register_late_lint_pass(reg, "cyclomatic_complexity::CyclomaticComplexity",
         box cyclomatic_complexity::CyclomaticComplexity::new(conf.cyclomatic_complexity_threshold)
     );
-    reg.register_late_lint_pass(box escape::Pass{too_large_for_stack: conf.too_large_for_stack});
-    reg.register_early_lint_pass(box misc_early::MiscEarly);
-    reg.register_late_lint_pass(box array_indexing::ArrayIndexing);
-    reg.register_late_lint_pass(box panic::Pass);
-    reg.register_late_lint_pass(box strings::StringLitAsBytes);
-    reg.register_late_lint_pass(box derive::Derive);
-    reg.register_late_lint_pass(box types::CharLitAsU8);
-    reg.register_late_lint_pass(box print::Pass);
-    reg.register_late_lint_pass(box vec::Pass);
-    reg.register_early_lint_pass(box non_expressive_names::NonExpressiveNames {
-        max_single_char_names: conf.max_single_char_names,
-    });
-    reg.register_late_lint_pass(box drop_forget_ref::Pass);
-    reg.register_late_lint_pass(box empty_enum::EmptyEnum);
-    reg.register_late_lint_pass(box types::AbsurdExtremeComparisons);
-    reg.register_late_lint_pass(box types::InvalidUpcastComparisons);
-    reg.register_late_lint_pass(box regex::Pass::default());
-    reg.register_late_lint_pass(box copies::CopyAndPaste);
-    reg.register_late_lint_pass(box format::Pass);
-    reg.register_early_lint_pass(box formatting::Formatting);
-    reg.register_late_lint_pass(box swap::Swap);
-    reg.register_early_lint_pass(box if_not_else::IfNotElse);
-    reg.register_late_lint_pass(box overflow_check_conditional::OverflowCheckConditional);
-    reg.register_late_lint_pass(box unused_label::UnusedLabel);
-    reg.register_late_lint_pass(box new_without_default::NewWithoutDefault);
-    reg.register_late_lint_pass(box blacklisted_name::BlackListedName::new(conf.blacklisted_names));
-    reg.register_late_lint_pass(box functions::Functions::new(conf.too_many_arguments_threshold));
-    reg.register_early_lint_pass(box doc::Doc::new(conf.doc_valid_idents));
-    reg.register_late_lint_pass(box neg_multiply::NegMultiply);
-    reg.register_early_lint_pass(box unsafe_removed_from_name::UnsafeNameRemoval);
-    reg.register_late_lint_pass(box mem_forget::MemForget);
-    reg.register_late_lint_pass(box arithmetic::Arithmetic::default());
-    reg.register_late_lint_pass(box assign_ops::AssignOps);
-    reg.register_late_lint_pass(box let_if_seq::LetIfSeq);
-    reg.register_late_lint_pass(box eval_order_dependence::EvalOrderDependence);
-    reg.register_late_lint_pass(box missing_doc::MissingDoc::new());
-    reg.register_late_lint_pass(box ok_if_let::Pass);
-    reg.register_late_lint_pass(box if_let_redundant_pattern_matching::Pass);
-    reg.register_late_lint_pass(box partialeq_ne_impl::Pass);
-    reg.register_early_lint_pass(box reference::Pass);
-    reg.register_early_lint_pass(box double_parens::DoubleParens);
-    reg.register_late_lint_pass(box unused_io_amount::UnusedIoAmount);
-    reg.register_late_lint_pass(box large_enum_variant::LargeEnumVariant::new(conf.enum_variant_size_threshold));
-    reg.register_late_lint_pass(box should_assert_eq::ShouldAssertEq);
-    reg.register_late_lint_pass(box needless_pass_by_value::NeedlessPassByValue);
+    register_late_lint_pass(reg, "escape::Pass", box escape::Pass{too_large_for_stack: conf.too_large_for_stack});
+    register_early_lint_pass(reg, "misc_early::MiscEarly", box misc_early::MiscEarly);
+    register_late_lint_pass(reg, "array_indexing::ArrayIndexing", box array_indexing::ArrayIndexing);
+    register_late_lint_pass(reg, "panic::Pass", box panic::Pass);
+    register_late_lint_pass(reg, "strings::StringLitAsBytes", box strings::StringLitAsBytes);
+    register_late_lint_pass(reg, "derive::Derive", box derive::Derive);
+    register_late_lint_pass(reg, "types::CharLitAsU8", box types::CharLitAsU8);
+    register_late_lint_pass(reg, "print::Pass", box print::Pass);
+    register_late_lint_pass(reg, "vec::Pass", box vec::Pass);
+    register_early_lint_pass(reg, "non_expressive_names::NonExpressiveNames",
+        box non_expressive_names::NonExpressiveNames {
+            max_single_char_names: conf.max_single_char_names,
+        }
+    );
+    register_late_lint_pass(reg, "drop_forget_ref::Pass", box drop_forget_ref::Pass);
+    register_late_lint_pass(reg, "empty_enum::EmptyEnum", box empty_enum::EmptyEnum);
+    register_late_lint_pass(reg, "types::AbsurdExtremeComparisons", box types::AbsurdExtremeComparisons);
+    register_late_lint_pass(reg, "types::InvalidUpcastComparisons", box types::InvalidUpcastComparisons);
+    register_late_lint_pass(reg, "regex::Pass", box regex::Pass::default());
+    register_late_lint_pass(reg, "copies::CopyAndPaste", box copies::CopyAndPaste);
+    register_late_lint_pass(reg, "format::Pass", box format::Pass);
+    register_early_lint_pass(reg, "formatting::Formatting", box formatting::Formatting);
+    register_late_lint_pass(reg, "swap::Swap", box swap::Swap);
+    register_early_lint_pass(reg, "if_not_else::IfNotElse", box if_not_else::IfNotElse);
+    register_late_lint_pass(reg, "overflow_check_conditional::OverflowCheckConditional",
+        box overflow_check_conditional::OverflowCheckConditional
+    );
+    register_late_lint_pass(reg, "unused_label::UnusedLabel", box unused_label::UnusedLabel);
+    register_late_lint_pass(reg, "new_without_default::NewWithoutDefault", box new_without_default::NewWithoutDefault);
+    register_late_lint_pass(reg, "blacklisted_name::BlackListedName",
+        box blacklisted_name::BlackListedName::new(conf.blacklisted_names.clone())
+    );
+    register_late_lint_pass(reg, "functions::Functions",
+        box functions::Functions::new(conf.too_many_arguments_threshold)
+    );
+    register_early_lint_pass(reg, "doc::Doc", box doc::Doc::new(conf.doc_valid_idents.clone()));
+    register_late_lint_pass(reg, "neg_multiply::NegMultiply", box neg_multiply::NegMultiply);
+    register_early_lint_pass(reg, "unsafe_removed_from_name::UnsafeNameRemoval",
+        box unsafe_removed_from_name::UnsafeNameRemoval
+    );
+    register_late_lint_pass(reg, "mem_forget::MemForget", box mem_forget::MemForget);
+    register_late_lint_pass(reg, "arithmetic::Arithmetic", box arithmetic::Arithmetic::default());
+    register_late_lint_pass(reg, "assign_ops::AssignOps", box assign_ops::AssignOps);
+    register_late_lint_pass(reg, "let_if_seq::LetIfSeq", box let_if_seq::LetIfSeq);
+    register_late_lint_pass(reg, "eval_order_dependence::EvalOrderDependence",
+        box eval_order_dependence::EvalOrderDependence
+    );
+    register_late_lint_pass(reg, "missing_doc::MissingDoc", box missing_doc::MissingDoc::new());
+    register_late_lint_pass(reg, "ok_if_let::Pass", box ok_if_let::Pass);
+    register_late_lint_pass(reg, "if_let_redundant_pattern_matching::Pass",
+        box if_let_redundant_pattern_matching::Pass
+    );
+    register_late_lint_pass(reg, "partialeq_ne_impl::Pass", box partialeq_ne_impl::Pass);
+    register_early_lint_pass(reg, "reference::Pass", box reference::Pass);
+    register_early_lint_pass(reg, "double_parens::DoubleParens", box double_parens::DoubleParens);
+    register_late_lint_pass(reg, "unused_io_amount::UnusedIoAmount", box unused_io_amount::UnusedIoAmount);
+    register_late_lint_pass(reg, "large_enum_variant::LargeEnumVariant",
+        box large_enum_variant::LargeEnumVariant::new(conf.enum_variant_size_threshold)
+    );
+    register_late_lint_pass(reg, "should_assert_eq::ShouldAssertEq", box should_assert_eq::ShouldAssertEq);
+    register_late_lint_pass(reg, "needless_pass_by_value::NeedlessPassByValue",
+        box needless_pass_by_value::NeedlessPassByValue
+    );
+    register_late_lint_pass(reg, "heap_allocation::HeapAllocation", box heap_allocation::HeapAllocation);
+    register_late_lint_pass(reg, "exit::Pass", box exit::Pass);
+    // must run after every other lint, to know which suppression comments were used
+    register_late_lint_pass(reg, "suppression::UnusedSuppressions", box suppression::UnusedSuppressions);
 
     reg.register_lint_group("clippy_restrictions", vec![
         arithmetic::FLOAT_ARITHMETIC,
         arithmetic::INTEGER_ARITHMETIC,
         array_indexing::INDEXING_SLICING,
         assign_ops::ASSIGN_OPS,
+        attrs::ALLOW_ATTRIBUTES_WITHOUT_REASON,
+        exit::EXIT,
+        heap_allocation::HEAP_ALLOCATION,
+        methods::EXPECT_USED,
+        panic::PANIC,
+        panic::UNREACHABLE,
+    ]);
+
+    // The presets below are curated groups of lints for a domain, mostly from `clippy_pedantic`
+    // and `clippy_restrictions`. They are written by hand, and the comment above each one is its
+    // description in `cargo clippy --list-groups`, see the README for their documentation.
+
+    // code that may panic at runtime, for code that must report all its errors
+    reg.register_lint_group("clippy_no_panic", vec![
+        array_indexing::INDEXING_SLICING,
+        array_indexing::OUT_OF_BOUNDS_INDEXING,
+        methods::EXPECT_USED,
+        methods::OPTION_UNWRAP_USED,
+        methods::RESULT_UNWRAP_USED,
+        panic::PANIC,
+        panic::UNREACHABLE,
+    ]);
+
+    // features that are slow or unavailable on small targets without an FPU, a heap or standard output
+    reg.register_lint_group("clippy_embedded", vec![
+        arithmetic::FLOAT_ARITHMETIC,
+        heap_allocation::HEAP_ALLOCATION,
+        print::PRINT_STDOUT,
+        print::USE_DEBUG,
+    ]);
+
+    // Printing, exiting and missing docs, which a library should leave to its users or avoid, and
+    // public API naming: repetitive variant and item names, `self` conventions, `Debug` output
+    reg.register_lint_group("clippy_library", vec![
+        enum_variants::PUB_ENUM_VARIANT_NAMES,
+        enum_variants::STUTTER,
+        exit::EXIT,
+        methods::WRONG_PUB_SELF_CONVENTION,
+        missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
+        print::PRINT_STDOUT,
+        print::USE_DEBUG,
     ]);
 
     reg.register_lint_group("clippy_pedantic", vec![
@@ -489,6 +674,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         serde::SERDE_API_MISUSE,
         should_assert_eq::SHOULD_ASSERT_EQ,
         strings::STRING_LIT_AS_BYTES,
+        suppression::UNUSED_SUPPRESSION,
         swap::ALMOST_SWAPPED,
         swap::MANUAL_SWAP,
         temporary_assignment::TEMPORARY_ASSIGNMENT,
@@ -511,6 +697,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         vec::USELESS_VEC,
         zero_div_zero::ZERO_DIVIDED_BY_ZERO,
     ]);
+
+    check_lint_levels(reg, conf);
+    set_lint_levels(reg, conf);
 }
 
 // only exists to let the dogfood integration test works.
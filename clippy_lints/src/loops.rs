@@ -2,8 +2,8 @@ use reexport::*;
 use rustc::hir::*;
 use rustc::hir::def::Def;
 use rustc::hir::def_id::DefId;
-use rustc::hir::intravisit::{Visitor, walk_expr, walk_block, walk_decl, NestedVisitorMap};
-use rustc::hir::map::Node::NodeBlock;
+use rustc::hir::intravisit::{Visitor, walk_expr, NestedVisitorMap};
+use rustc::hir::map::Node::NodeExpr;
 use rustc::lint::*;
 use rustc::middle::const_val::ConstVal;
 use rustc::middle::region::CodeExtent;
@@ -11,11 +11,13 @@ use rustc::ty;
 use rustc_const_eval::ConstContext;
 use std::collections::HashMap;
 use syntax::ast;
+use utils::dataflow::Flow;
 use utils::sugg;
+use utils::sugg::{Applicability, SuggestionExt};
 
 use utils::{snippet, span_lint, get_parent_expr, match_trait_method, match_type, multispan_sugg, in_external_macro,
-            is_refutable, span_help_and_lint, is_integer_literal, get_enclosing_block, span_lint_and_then, higher,
-            last_path_segment, span_lint_and_sugg};
+            is_refutable, span_help_and_lint, is_integer_literal, let_init, span_lint_and_then, higher,
+            last_path_segment, span_lint_and_sugg, snippet_with_applicability};
 use utils::paths;
 
 /// **What it does:** Checks for looping over the range of `0..len` of some
@@ -374,7 +376,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                                     let sug = format!("while let {} = {} {{ .. }}",
                                                       snippet(cx, arms[0].pats[0].span, ".."),
                                                       snippet(cx, matchexpr.span, ".."));
-                                    db.span_suggestion(expr.span, "try", sug);
+                                    db.span_suggestion_with_applicability(expr.span,
+                                                                          "try",
+                                                                          sug,
+                                                                          Applicability::HasPlaceholders);
                                 });
                             }
                         },
@@ -399,7 +404,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                                        expr.span,
                                        "this loop could be written as a `for` loop",
                                        |db| {
-                        db.span_suggestion(expr.span, "try", format!("for {} in {} {{ .. }}", loop_var, iterator));
+                        db.span_suggestion_with_applicability(expr.span,
+                                                              "try",
+                                                              format!("for {} in {} {{ .. }}", loop_var, iterator),
+                                                              Applicability::HasPlaceholders);
                     });
                 }
             }
@@ -549,7 +557,8 @@ fn check_for_loop_range<'a, 'tcx>(
                         multispan_sugg(db,
                                        "consider using an iterator".to_string(),
                                        vec![(pat.span, format!("({}, <item>)", ident.node)),
-                                            (arg.span, format!("{}.iter().enumerate(){}{}", indexed, take, skip))]);
+                                            (arg.span, format!("{}.iter().enumerate(){}{}", indexed, take, skip))],
+                                       Applicability::HasPlaceholders);
                     });
                 } else {
                     let repl = if starts_at_zero && take.is_empty() {
@@ -567,7 +576,8 @@ fn check_for_loop_range<'a, 'tcx>(
                                        |db| {
                         multispan_sugg(db,
                                        "consider using an iterator".to_string(),
-                                       vec![(pat.span, "<item>".to_string()), (arg.span, repl)]);
+                                       vec![(pat.span, "<item>".to_string()), (arg.span, repl)],
+                                       Applicability::HasPlaceholders);
                     });
                 }
             }
@@ -622,13 +632,15 @@ fn check_for_loop_reverse_range(cx: &LateContext, arg: &Expr, expr: &Expr) {
                                        expr.span,
                                        "this range is empty so this for loop will never run",
                                        |db| {
-                        db.span_suggestion(arg.span,
-                                           "consider using the following if you are attempting to iterate over this \
-                                            range in reverse",
-                                           format!("({end}{dots}{start}).rev()",
-                                                   end = end_snippet,
-                                                   dots = dots,
-                                                   start = start_snippet));
+                        // this changes what the loop does
+                        db.span_suggestion_with_applicability(arg.span,
+                                                              "consider using the following if you are attempting to \
+                                                               iterate over this range in reverse",
+                                                              format!("({end}{dots}{start}).rev()",
+                                                                      end = end_snippet,
+                                                                      dots = dots,
+                                                                      start = start_snippet),
+                                                              Applicability::MaybeIncorrect);
                     });
                 } else if eq && limits != ast::RangeLimits::Closed {
                     // if they are equal, it's also problematic - this loop
@@ -644,7 +656,8 @@ fn check_for_loop_reverse_range(cx: &LateContext, arg: &Expr, expr: &Expr) {
 }
 
 fn lint_iter_method(cx: &LateContext, args: &[Expr], arg: &Expr, method_name: &str) {
-    let object = snippet(cx, args[0].span, "_");
+    let mut applicability = Applicability::MachineApplicable;
+    let object = snippet_with_applicability(cx, args[0].span, "_", &mut applicability);
     let muta = if method_name == "iter_mut" {
         "mut "
     } else {
@@ -656,7 +669,8 @@ fn lint_iter_method(cx: &LateContext, args: &[Expr], arg: &Expr, method_name: &s
                        "it is more idiomatic to loop over references to containers instead of using explicit \
                         iteration methods",
                        "to write this more concisely, try",
-                       format!("&{}{}", muta, object))
+                       format!("&{}{}", muta, object),
+                       applicability)
 }
 
 fn check_for_loop_arg(cx: &LateContext, pat: &Pat, arg: &Expr, expr: &Expr) {
@@ -682,14 +696,16 @@ fn check_for_loop_arg(cx: &LateContext, pat: &Pat, arg: &Expr, expr: &Expr) {
                 if fn_arg_tys.skip_binder()[0].is_region_ptr() {
                     lint_iter_method(cx, args, arg, &method_name);
                 } else {
-                    let object = snippet(cx, args[0].span, "_");
+                    let mut applicability = Applicability::MachineApplicable;
+                    let object = snippet_with_applicability(cx, args[0].span, "_", &mut applicability);
                     span_lint_and_sugg(cx,
                                        EXPLICIT_INTO_ITER_LOOP,
                                        arg.span,
                                        "it is more idiomatic to loop over containers instead of using explicit \
                                         iteration methods`",
                                        "to write this more concisely, try",
-                                       object.to_string());
+                                       object.to_string(),
+                                       applicability);
                 }
             } else if method_name == "next" && match_trait_method(cx, arg, &paths::ITERATOR) {
                 span_lint(cx,
@@ -747,38 +763,50 @@ fn check_for_loop_explicit_counter<'a, 'tcx>(
     };
     walk_expr(&mut visitor, body);
 
-    // For each candidate, check the parent block to see if
-    // it's initialized to zero at the start of the loop.
+    let candidates: Vec<_> = visitor.states
+        .iter()
+        .filter(|&(_, v)| *v == VarState::IncrOnce)
+        .map(|(&id, _)| id)
+        .collect();
+    if candidates.is_empty() {
+        return;
+    }
+
+    // For each candidate, check that it is zero when the loop starts, and that its value is not
+    // needed once the loop is over. The analyses are solved once for all the candidates.
+    let flow = if let Some(flow) = Flow::enclosing(cx, expr.id) {
+        flow
+    } else {
+        return;
+    };
+    for id in candidates {
+        if is_zero_before_loop(cx, &flow, id, arg) && !flow.is_live_after(id, expr.id) {
+            span_lint(cx,
+                      EXPLICIT_COUNTER_LOOP,
+                      expr.span,
+                      &format!("the variable `{0}` is used as a loop counter. Consider using `for ({0}, item) in \
+                                {1}.enumerate()` or similar iterators",
+                               cx.tcx.hir.name(id),
+                               snippet(cx, arg.span, "_")));
+        }
+    }
+}
+
+/// Whether the local `id` is zero once `arg`, the iterator of a `for` loop, is evaluated: the only
+/// definition of the local reaching it must be a `let` or an assignment of zero.
+fn is_zero_before_loop(cx: &LateContext, flow: &Flow, id: NodeId, arg: &Expr) -> bool {
     let map = &cx.tcx.hir;
-    let parent_scope = map.get_enclosing_scope(expr.id).and_then(|id| map.get_enclosing_scope(id));
-    if let Some(parent_id) = parent_scope {
-        if let NodeBlock(block) = map.get(parent_id) {
-            for (id, _) in visitor.states.iter().filter(|&(_, v)| *v == VarState::IncrOnce) {
-                let mut visitor2 = InitializeVisitor {
-                    cx: cx,
-                    end_expr: expr,
-                    var_id: *id,
-                    state: VarState::IncrOnce,
-                    name: None,
-                    depth: 0,
-                    past_loop: false,
-                };
-                walk_block(&mut visitor2, block);
-
-                if visitor2.state == VarState::Warn {
-                    if let Some(name) = visitor2.name {
-                        span_lint(cx,
-                                  EXPLICIT_COUNTER_LOOP,
-                                  expr.span,
-                                  &format!("the variable `{0}` is used as a loop counter. Consider using `for ({0}, \
-                                            item) in {1}.enumerate()` or similar iterators",
-                                           name,
-                                           snippet(cx, arg.span, "_")));
-                    }
-                }
+    if let Some(definitions) = flow.definitions_reaching(id, arg.id) {
+        if let [definition] = *definitions.as_slice() {
+            if let Some(init) = let_init(map, definition) {
+                return is_integer_literal(init, 0);
+            }
+            if let Some(NodeExpr(&Expr { node: ExprAssign(_, ref value), .. })) = map.find(definition) {
+                return is_integer_literal(value, 0);
             }
         }
     }
+    false
 }
 
 /// Check for the `FOR_KV_MAP` lint.
@@ -819,11 +847,14 @@ fn check_for_loop_over_map_kv<'a, 'tcx>(
                                    expr.span,
                                    &format!("you seem to want to iterate on a map's {}s", kind),
                                    |db| {
-                    let map = sugg::Sugg::hir(cx, arg, "map");
+                    let mut applicability = Applicability::MachineApplicable;
+                    let map = sugg::Sugg::hir_with_applicability(cx, arg, "map", &mut applicability);
+                    let new_pat = snippet_with_applicability(cx, new_pat_span, kind, &mut applicability).into_owned();
                     multispan_sugg(db,
                                    "use the corresponding method".into(),
-                                   vec![(pat_span, snippet(cx, new_pat_span, kind).into_owned()),
-                                        (arg_span, format!("{}.{}s{}()", map.maybe_par(), kind, mutbl))]);
+                                   vec![(pat_span, new_pat),
+                                        (arg_span, format!("{}.{}s{}()", map.maybe_par(), kind, mutbl))],
+                                   applicability);
                 });
             }
         }
@@ -920,49 +951,16 @@ impl<'a, 'tcx> Visitor<'tcx> for VarVisitor<'a, 'tcx> {
     }
 }
 
+/// Whether the iterator of a `while let` loop is used by the loop body or after the loop, where
+/// it would not be available if the loop was a `for` loop.
 fn is_iterator_used_after_while_let<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, iter_expr: &'tcx Expr) -> bool {
     let def_id = match var_def_id(cx, iter_expr) {
         Some(id) => id,
         None => return false,
     };
-    let mut visitor = VarUsedAfterLoopVisitor {
-        cx: cx,
-        def_id: def_id,
-        iter_expr_id: iter_expr.id,
-        past_while_let: false,
-        var_used_after_while_let: false,
-    };
-    if let Some(enclosing_block) = get_enclosing_block(cx, def_id) {
-        walk_block(&mut visitor, enclosing_block);
-    }
-    visitor.var_used_after_while_let
+    Flow::enclosing(cx, iter_expr.id).map_or(true, |flow| flow.is_used_after(def_id, iter_expr.id))
 }
 
-struct VarUsedAfterLoopVisitor<'a, 'tcx: 'a> {
-    cx: &'a LateContext<'a, 'tcx>,
-    def_id: NodeId,
-    iter_expr_id: NodeId,
-    past_while_let: bool,
-    var_used_after_while_let: bool,
-}
-
-impl<'a, 'tcx> Visitor<'tcx> for VarUsedAfterLoopVisitor<'a, 'tcx> {
-    fn visit_expr(&mut self, expr: &'tcx Expr) {
-        if self.past_while_let {
-            if Some(self.def_id) == var_def_id(self.cx, expr) {
-                self.var_used_after_while_let = true;
-            }
-        } else if self.iter_expr_id == expr.id {
-            self.past_while_let = true;
-        }
-        walk_expr(self, expr);
-    }
-    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
-        NestedVisitorMap::All(&self.cx.tcx.hir)
-    }
-}
-
-
 /// Return true if the type of expr is one that provides `IntoIterator` impls
 /// for `&T` and `&mut T`, such as `Vec`.
 #[cfg_attr(rustfmt, rustfmt_skip)]
@@ -1045,8 +1043,6 @@ fn is_break_expr(expr: &Expr) -> bool {
 enum VarState {
     Initial, // Not examined yet
     IncrOnce, // Incremented exactly once, may be a loop counter
-    Declared, // Declared but not (yet) initialized to zero
-    Warn,
     DontWarn,
 }
 
@@ -1105,93 +1101,6 @@ impl<'a, 'tcx> Visitor<'tcx> for IncrementVisitor<'a, 'tcx> {
     }
 }
 
-/// Check whether a variable is initialized to zero at the start of a loop.
-struct InitializeVisitor<'a, 'tcx: 'a> {
-    cx: &'a LateContext<'a, 'tcx>, // context reference
-    end_expr: &'tcx Expr, // the for loop. Stop scanning here.
-    var_id: NodeId,
-    state: VarState,
-    name: Option<Name>,
-    depth: u32, // depth of conditional expressions
-    past_loop: bool,
-}
-
-impl<'a, 'tcx> Visitor<'tcx> for InitializeVisitor<'a, 'tcx> {
-    fn visit_decl(&mut self, decl: &'tcx Decl) {
-        // Look for declarations of the variable
-        if let DeclLocal(ref local) = decl.node {
-            if local.pat.id == self.var_id {
-                if let PatKind::Binding(_, _, ref ident, _) = local.pat.node {
-                    self.name = Some(ident.node);
-
-                    self.state = if let Some(ref init) = local.init {
-                        if is_integer_literal(init, 0) {
-                            VarState::Warn
-                        } else {
-                            VarState::Declared
-                        }
-                    } else {
-                        VarState::Declared
-                    }
-                }
-            }
-        }
-        walk_decl(self, decl);
-    }
-
-    fn visit_expr(&mut self, expr: &'tcx Expr) {
-        if self.state == VarState::DontWarn {
-            return;
-        }
-        if expr == self.end_expr {
-            self.past_loop = true;
-            return;
-        }
-        // No need to visit expressions before the variable is
-        // declared
-        if self.state == VarState::IncrOnce {
-            return;
-        }
-
-        // If node is the desired variable, see how it's used
-        if var_def_id(self.cx, expr) == Some(self.var_id) {
-            if let Some(parent) = get_parent_expr(self.cx, expr) {
-                match parent.node {
-                    ExprAssignOp(_, ref lhs, _) if lhs.id == expr.id => {
-                        self.state = VarState::DontWarn;
-                    },
-                    ExprAssign(ref lhs, ref rhs) if lhs.id == expr.id => {
-                        self.state = if is_integer_literal(rhs, 0) && self.depth == 0 {
-                            VarState::Warn
-                        } else {
-                            VarState::DontWarn
-                        }
-                    },
-                    ExprAddrOf(mutability, _) if mutability == MutMutable => self.state = VarState::DontWarn,
-                    _ => (),
-                }
-            }
-
-            if self.past_loop {
-                self.state = VarState::DontWarn;
-                return;
-            }
-        } else if !self.past_loop && is_loop(expr) {
-            self.state = VarState::DontWarn;
-            return;
-        } else if is_conditional(expr) {
-            self.depth += 1;
-            walk_expr(self, expr);
-            self.depth -= 1;
-            return;
-        }
-        walk_expr(self, expr);
-    }
-    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
-        NestedVisitorMap::All(&self.cx.tcx.hir)
-    }
-}
-
 fn var_def_id(cx: &LateContext, expr: &Expr) -> Option<NodeId> {
     if let ExprPath(ref qpath) = expr.node {
         let path_res = cx.tables.qpath_def(qpath, expr.id);
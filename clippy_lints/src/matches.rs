@@ -9,9 +9,9 @@ use std::collections::Bound;
 use syntax::ast::LitKind;
 use syntax::codemap::Span;
 use utils::paths;
-use utils::{match_type, snippet, span_note_and_lint, span_lint_and_then, in_external_macro, expr_block, walk_ptrs_ty,
-            is_expn_of, remove_blocks};
-use utils::sugg::Sugg;
+use utils::{match_type, snippet_with_applicability, span_note_and_lint, span_lint_and_then, span_lint_and_sugg,
+            in_external_macro, expr_block_with_applicability, walk_ptrs_ty, is_expn_of, remove_blocks};
+use utils::sugg::{Applicability, Sugg, SuggestionExt};
 
 /// **What it does:** Checks for matches with a single arm where an `if let`
 /// will usually suffice.
@@ -209,21 +209,23 @@ fn report_single_match_single_pattern(cx: &LateContext, ex: &Expr, arms: &[Arm],
     } else {
         SINGLE_MATCH
     };
-    let els_str = els.map_or(String::new(), |els| format!(" else {}", expr_block(cx, els, None, "..")));
-    span_lint_and_then(cx,
+    let mut applicability = Applicability::MachineApplicable;
+    let els_str = els.map_or(String::new(), |els| {
+        format!(" else {}", expr_block_with_applicability(cx, els, None, "..", &mut applicability))
+    });
+    let sugg = format!("if let {} = {} {}{}",
+                       snippet_with_applicability(cx, arms[0].pats[0].span, "..", &mut applicability),
+                       snippet_with_applicability(cx, ex.span, "..", &mut applicability),
+                       expr_block_with_applicability(cx, &arms[0].body, None, "..", &mut applicability),
+                       els_str);
+    span_lint_and_sugg(cx,
                        lint,
                        expr.span,
                        "you seem to be trying to use match for destructuring a single pattern. Consider using `if \
                         let`",
-                       |db| {
-        db.span_suggestion(expr.span,
-                           "try this",
-                           format!("if let {} = {} {}{}",
-                                   snippet(cx, arms[0].pats[0].span, ".."),
-                                   snippet(cx, ex.span, ".."),
-                                   expr_block(cx, &arms[0].body, None, ".."),
-                                   els_str));
-    });
+                       "try this",
+                       sugg,
+                       applicability);
 }
 
 fn check_single_match_opt_like(
@@ -288,25 +290,33 @@ fn check_match_bool(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
                 };
 
                 if let Some((true_expr, false_expr)) = exprs {
+                    let mut applicability = Applicability::MachineApplicable;
                     let sugg = match (is_unit_expr(true_expr), is_unit_expr(false_expr)) {
                         (false, false) => {
                             Some(format!("if {} {} else {}",
-                                         snippet(cx, ex.span, "b"),
-                                         expr_block(cx, true_expr, None, ".."),
-                                         expr_block(cx, false_expr, None, "..")))
+                                         snippet_with_applicability(cx, ex.span, "b", &mut applicability),
+                                         expr_block_with_applicability(cx, true_expr, None, "..", &mut applicability),
+                                         expr_block_with_applicability(cx, false_expr, None, "..", &mut applicability)))
                         },
                         (false, true) => {
-                            Some(format!("if {} {}", snippet(cx, ex.span, "b"), expr_block(cx, true_expr, None, "..")))
+                            Some(format!("if {} {}",
+                                         snippet_with_applicability(cx, ex.span, "b", &mut applicability),
+                                         expr_block_with_applicability(cx, true_expr, None, "..", &mut applicability)))
                         },
                         (true, false) => {
-                            let test = Sugg::hir(cx, ex, "..");
-                            Some(format!("if {} {}", !test, expr_block(cx, false_expr, None, "..")))
+                            let test = Sugg::hir_with_applicability(cx, ex, "..", &mut applicability);
+                            Some(format!("if {} {}",
+                                         !test,
+                                         expr_block_with_applicability(cx, false_expr, None, "..", &mut applicability)))
                         },
                         (true, true) => None,
                     };
 
                     if let Some(sugg) = sugg {
-                        db.span_suggestion(expr.span, "consider using an if/else expression", sugg);
+                        db.span_suggestion_with_applicability(expr.span,
+                                                              "consider using an if/else expression",
+                                                              sugg,
+                                                              applicability);
                     }
                 }
             }
@@ -381,7 +391,7 @@ fn check_match_ref_pats(cx: &LateContext, ex: &Expr, arms: &[Arm], source: Match
                                |db| {
                 let inner = Sugg::hir(cx, inner, "..");
                 let template = match_template(expr.span, source, &inner);
-                db.span_suggestion(expr.span, "try", template);
+                db.span_suggestion_with_applicability(expr.span, "try", template, Applicability::HasPlaceholders);
             });
         } else {
             span_lint_and_then(cx,
@@ -391,9 +401,11 @@ fn check_match_ref_pats(cx: &LateContext, ex: &Expr, arms: &[Arm], source: Match
                                |db| {
                 let ex = Sugg::hir(cx, ex, "..");
                 let template = match_template(expr.span, source, &ex.deref());
-                db.span_suggestion(expr.span,
-                                   "instead of prefixing all patterns with `&`, you can dereference the expression",
-                                   template);
+                db.span_suggestion_with_applicability(expr.span,
+                                                      "instead of prefixing all patterns with `&`, you can \
+                                                       dereference the expression",
+                                                      template,
+                                                      Applicability::HasPlaceholders);
             });
         }
     }
@@ -10,9 +10,10 @@ use syntax::codemap::Span;
 use utils::{get_trait_def_id, implements_trait, in_external_macro, in_macro, is_copy, match_path, match_trait_method,
             match_type, method_chain_args, return_ty, same_tys, snippet, span_lint, span_lint_and_then,
             span_note_and_lint, walk_ptrs_ty, walk_ptrs_ty_depth, last_path_segment, single_segment_path,
-            match_def_path, is_self, is_self_ty, iter_input_pats};
+            match_def_path, is_self, is_self_ty, iter_input_pats, snippet_with_applicability};
 use utils::paths;
 use utils::sugg;
+use utils::sugg::{Applicability, SuggestionExt};
 
 #[derive(Clone)]
 pub struct Pass;
@@ -58,6 +59,23 @@ declare_lint! {
     "using `Result.unwrap()`, which might be better handled"
 }
 
+/// **What it does:** Checks for `.expect()` calls on `Option`s and `Result`s.
+///
+/// **Why is this bad?** Like `unwrap`, `expect` will let the thread panic on
+/// `None` or `Err` values. Code that must report all its errors should
+/// propagate them instead.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// x.expect("more helpful message")
+/// ```
+declare_restriction_lint! {
+    pub EXPECT_USED,
+    "using `Option.expect()` or `Result.expect()`, which will panic on `None` or `Err`"
+}
+
 /// **What it does:** Checks for methods that should live in a trait
 /// implementation of a `std` trait (see [llogiq's blog
 /// post](http://llogiq.github.io/2015/07/30/traits.html) for further
@@ -525,6 +543,7 @@ impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(OPTION_UNWRAP_USED,
                     RESULT_UNWRAP_USED,
+                    EXPECT_USED,
                     SHOULD_IMPLEMENT_TRAIT,
                     WRONG_SELF_CONVENTION,
                     WRONG_PUB_SELF_CONVENTION,
@@ -610,6 +629,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 if args.len() == 1 && name.node == "clone" {
                     lint_clone_on_copy(cx, expr, &args[0], self_ty);
                 }
+                if args.len() == 2 && name.node == "expect" {
+                    lint_expect(cx, expr, args);
+                }
 
                 match self_ty.sty {
                     ty::TyRef(_, ty) if ty.ty.sty == ty::TyStr => {
@@ -729,9 +751,12 @@ fn lint_or_fun_call(cx: &LateContext, expr: &hir::Expr, name: &str, args: &[hir:
                                            span,
                                            &format!("use of `{}` followed by a call to `{}`", name, path),
                                            |db| {
-                            db.span_suggestion(span,
-                                               "try this",
-                                               format!("{}.unwrap_or_default()", snippet(cx, self_expr.span, "_")));
+                            let mut app = Applicability::MachineApplicable;
+                            let recv = snippet_with_applicability(cx, self_expr.span, "_", &mut app);
+                            db.span_suggestion_with_applicability(span,
+                                                                  "try this",
+                                                                  format!("{}.unwrap_or_default()", recv),
+                                                                  app);
                         });
                         return true;
                     }
@@ -784,20 +809,23 @@ fn lint_or_fun_call(cx: &LateContext, expr: &hir::Expr, name: &str, args: &[hir:
             return;
         }
 
+        let mut app = Applicability::MachineApplicable;
         let sugg: Cow<_> = match (fn_has_arguments, !or_has_args) {
-            (true, _) => format!("|_| {}", snippet(cx, arg.span, "..")).into(),
-            (false, false) => format!("|| {}", snippet(cx, arg.span, "..")).into(),
-            (false, true) => snippet(cx, fun_span, ".."),
+            (true, _) => format!("|_| {}", snippet_with_applicability(cx, arg.span, "..", &mut app)).into(),
+            (false, false) => format!("|| {}", snippet_with_applicability(cx, arg.span, "..", &mut app)).into(),
+            (false, true) => snippet_with_applicability(cx, fun_span, "..", &mut app),
         };
+        let recv = snippet_with_applicability(cx, self_expr.span, "_", &mut app);
 
         span_lint_and_then(cx,
                            OR_FUN_CALL,
                            span,
                            &format!("use of `{}` followed by a function call", name),
                            |db| {
-            db.span_suggestion(span,
-                               "try this",
-                               format!("{}.{}_{}({})", snippet(cx, self_expr.span, "_"), name, suffix, sugg));
+            db.span_suggestion_with_applicability(span,
+                                                  "try this",
+                                                  format!("{}.{}_{}({})", recv, name, suffix, sugg),
+                                                  app);
         });
     }
 
@@ -830,9 +858,11 @@ fn lint_clone_on_copy(cx: &LateContext, expr: &hir::Expr, arg: &hir::Expr, arg_t
                                "using `clone` on a double-reference; \
                                 this will copy the reference instead of cloning the inner type",
                                |db| if let Some(snip) = sugg::Sugg::hir_opt(cx, arg) {
-                                   db.span_suggestion(expr.span,
-                                                      "try dereferencing it",
-                                                      format!("({}).clone()", snip.deref()));
+                                   // this clones something else than before
+                                   db.span_suggestion_with_applicability(expr.span,
+                                                                         "try dereferencing it",
+                                                                         format!("({}).clone()", snip.deref()),
+                                                                         Applicability::MaybeIncorrect);
                                });
             return; // don't report clone_on_copy
         }
@@ -845,9 +875,15 @@ fn lint_clone_on_copy(cx: &LateContext, expr: &hir::Expr, arg: &hir::Expr, arg_t
                            "using `clone` on a `Copy` type",
                            |db| if let Some(snip) = sugg::Sugg::hir_opt(cx, arg) {
                                if let ty::TyRef(..) = cx.tables.expr_ty(arg).sty {
-                                   db.span_suggestion(expr.span, "try dereferencing it", format!("{}", snip.deref()));
+                                   db.span_suggestion_with_applicability(expr.span,
+                                                                         "try dereferencing it",
+                                                                         format!("{}", snip.deref()),
+                                                                         Applicability::MachineApplicable);
                                } else {
-                                   db.span_suggestion(expr.span, "try removing the `clone` call", format!("{}", snip));
+                                   db.span_suggestion_with_applicability(expr.span,
+                                                                         "try removing the `clone` call",
+                                                                         format!("{}", snip),
+                                                                         Applicability::MachineApplicable);
                                }
                            });
     }
@@ -867,12 +903,14 @@ fn lint_string_extend(cx: &LateContext, expr: &hir::Expr, args: &[hir::Expr]) {
         };
 
         span_lint_and_then(cx, STRING_EXTEND_CHARS, expr.span, "calling `.extend(_.chars())`", |db| {
-            db.span_suggestion(expr.span,
-                               "try this",
-                               format!("{}.push_str({}{})",
-                                       snippet(cx, args[0].span, "_"),
-                                       ref_str,
-                                       snippet(cx, target.span, "_")));
+            let mut app = Applicability::MachineApplicable;
+            db.span_suggestion_with_applicability(expr.span,
+                                                  "try this",
+                                                  format!("{}.push_str({}{})",
+                                                          snippet_with_applicability(cx, args[0].span, "_", &mut app),
+                                                          ref_str,
+                                                          snippet_with_applicability(cx, target.span, "_", &mut app)),
+                                                  app);
         });
     }
 }
@@ -959,12 +997,14 @@ fn lint_get_unwrap(cx: &LateContext, expr: &hir::Expr, get_args: &[hir::Expr], i
                                 mut_str,
                                 caller_type),
                        |db| {
-        db.span_suggestion(expr.span,
-                           "try this",
-                           format!("{}{}[{}]",
-                                   borrow_str,
-                                   snippet(cx, get_args[0].span, "_"),
-                                   snippet(cx, get_args[1].span, "_")));
+        let mut app = Applicability::MachineApplicable;
+        db.span_suggestion_with_applicability(expr.span,
+                                              "try this",
+                                              format!("{}{}[{}]",
+                                                      borrow_str,
+                                                      snippet_with_applicability(cx, get_args[0].span, "_", &mut app),
+                                                      snippet_with_applicability(cx, get_args[1].span, "_", &mut app)),
+                                              app);
     });
 }
 
@@ -1036,6 +1076,29 @@ fn lint_unwrap(cx: &LateContext, expr: &hir::Expr, unwrap_args: &[hir::Expr]) {
     }
 }
 
+/// lint use of `expect()` for `Option`s and `Result`s
+fn lint_expect(cx: &LateContext, expr: &hir::Expr, expect_args: &[hir::Expr]) {
+    let (obj_ty, _) = walk_ptrs_ty_depth(cx.tables.expr_ty(&expect_args[0]));
+
+    let mess = if match_type(cx, obj_ty, &paths::OPTION) {
+        Some(("an Option", "None"))
+    } else if match_type(cx, obj_ty, &paths::RESULT) {
+        Some(("a Result", "Err"))
+    } else {
+        None
+    };
+
+    if let Some((kind, none_value)) = mess {
+        span_lint(cx,
+                  EXPECT_USED,
+                  expr.span,
+                  &format!("used expect() on {} value. This will panic on {}, consider handling it or \
+                            propagating it",
+                           kind,
+                           none_value));
+    }
+}
+
 /// lint use of `ok().expect()` for `Result`s
 fn lint_ok_expect(cx: &LateContext, expr: &hir::Expr, ok_args: &[hir::Expr]) {
     // lint if the caller of `ok()` is a `Result`
@@ -1222,13 +1285,14 @@ fn lint_chars_next(cx: &LateContext, expr: &hir::Expr, chain: &hir::Expr, other:
                            expr.span,
                            "you should use the `starts_with` method",
                            |db| {
+                               let mut app = Applicability::MachineApplicable;
                                let sugg = format!("{}{}.starts_with({})",
                                                   if eq { "" } else { "!" },
-                                                  snippet(cx, args[0][0].span, "_"),
-                                                  snippet(cx, arg_char[0].span, "_")
+                                                  snippet_with_applicability(cx, args[0][0].span, "_", &mut app),
+                                                  snippet_with_applicability(cx, arg_char[0].span, "_", &mut app)
                                                   );
 
-                               db.span_suggestion(expr.span, "like this", sugg);
+                               db.span_suggestion_with_applicability(expr.span, "like this", sugg, app);
                            });
 
         return true;
@@ -1246,7 +1310,13 @@ fn lint_single_char_pattern(cx: &LateContext, expr: &hir::Expr, arg: &hir::Expr)
                                SINGLE_CHAR_PATTERN,
                                arg.span,
                                "single-character string constant used as pattern",
-                               |db| { db.span_suggestion(expr.span, "try using a char instead:", hint); });
+                               |db| {
+                // the same string could appear elsewhere in the expression
+                db.span_suggestion_with_applicability(expr.span,
+                                                      "try using a char instead:",
+                                                      hint,
+                                                      Applicability::MaybeIncorrect);
+            });
         }
     }
 }
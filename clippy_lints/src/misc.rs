@@ -8,8 +8,9 @@ use rustc_const_eval::ConstContext;
 use rustc_const_math::ConstFloat;
 use syntax::codemap::{Span, Spanned, ExpnFormat};
 use utils::{get_item_name, get_parent_expr, implements_trait, in_macro, is_integer_literal, match_path, snippet,
-            span_lint, span_lint_and_then, walk_ptrs_ty, last_path_segment, iter_input_pats, in_constant};
-use utils::sugg::Sugg;
+            span_lint, span_lint_and_then, walk_ptrs_ty, last_path_segment, iter_input_pats, in_constant,
+            snippet_with_applicability};
+use utils::sugg::{Applicability, Sugg, SuggestionExt};
 use syntax::ast::LitKind;
 
 /// **What it does:** Checks for function arguments and let bindings denoted as `ref`.
@@ -239,14 +240,15 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             let PatKind::Binding(BindByRef(mt), _, i, None) = l.pat.node,
             let Some(ref init) = l.init
         ], {
-            let init = Sugg::hir(cx, init, "..");
+            let mut app = Applicability::MachineApplicable;
+            let init = Sugg::hir_with_applicability(cx, init, "..", &mut app);
             let (mutopt,initref) = if mt == Mutability::MutMutable {
                 ("mut ", init.mut_addr())
             } else {
                 ("", init.addr())
             };
             let tyopt = if let Some(ref ty) = l.ty {
-                format!(": &{mutopt}{ty}", mutopt=mutopt, ty=snippet(cx, ty.span, "_"))
+                format!(": &{mutopt}{ty}", mutopt=mutopt, ty=snippet_with_applicability(cx, ty.span, "_", &mut app))
             } else {
                 "".to_owned()
             };
@@ -255,12 +257,14 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 l.pat.span,
                 "`ref` on an entire `let` pattern is discouraged, take a reference with `&` instead",
                 |db| {
-                    db.span_suggestion(s.span,
-                                       "try",
-                                       format!("let {name}{tyopt} = {initref};",
-                                               name=snippet(cx, i.span, "_"),
-                                               tyopt=tyopt,
-                                               initref=initref));
+                    let name = snippet_with_applicability(cx, i.span, "_", &mut app);
+                    db.span_suggestion_with_applicability(s.span,
+                                                          "try",
+                                                          format!("let {name}{tyopt} = {initref};",
+                                                                  name=name,
+                                                                  tyopt=tyopt,
+                                                                  initref=initref),
+                                                          app);
                 }
             );
         }};
@@ -275,9 +279,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 s.span,
                 "boolean short circuit operator in statement may be clearer using an explicit test",
                 |db| {
+                    let mut app = Applicability::MachineApplicable;
                     let sugg = if binop.node == BiOr { !sugg } else { sugg };
-                    db.span_suggestion(s.span, "replace it with",
-                                       format!("if {} {{ {}; }}", sugg, &snippet(cx, b.span, "..")));
+                    let rhs = snippet_with_applicability(cx, b.span, "..", &mut app);
+                    db.span_suggestion_with_applicability(s.span,
+                                                          "replace it with",
+                                                          format!("if {} {{ {}; }}", sugg, rhs),
+                                                          app);
                 });
         }};
     }
@@ -315,9 +323,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                         let lhs = Sugg::hir(cx, left, "..");
                         let rhs = Sugg::hir(cx, right, "..");
 
-                        db.span_suggestion(expr.span,
-                                           "consider comparing them within some error",
-                                           format!("({}).abs() < error", lhs - rhs));
+                        db.span_suggestion_with_applicability(expr.span,
+                                                              "consider comparing them within some error",
+                                                              format!("({}).abs() < error", lhs - rhs),
+                                                              Applicability::HasPlaceholders);
                         db.span_note(expr.span, "std::f32::EPSILON and std::f64::EPSILON are available.");
                     });
                 } else if op == BiRem && is_integer_literal(right, 1) {
@@ -4,7 +4,8 @@ use std::char;
 use syntax::ast::*;
 use syntax::codemap::Span;
 use syntax::visit::FnKind;
-use utils::{constants, span_lint, span_help_and_lint, snippet, snippet_opt, span_lint_and_then};
+use utils::{constants, span_lint, span_help_and_lint, snippet_opt, snippet_with_applicability, span_lint_and_then};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for structure field patterns bound to wildcards.
 ///
@@ -276,8 +277,13 @@ impl EarlyLintPass for MiscEarly {
                                            expr.span,
                                            "Try not to call a closure in the expression where it is declared.",
                                            |db| if decl.inputs.is_empty() {
-                                               let hint = snippet(cx, block.span, "..").into_owned();
-                                               db.span_suggestion(expr.span, "Try doing something like: ", hint);
+                                               let mut app = Applicability::MachineApplicable;
+                                               let hint = snippet_with_applicability(cx, block.span, "..", &mut app)
+                                                   .into_owned();
+                                               db.span_suggestion_with_applicability(expr.span,
+                                                                                     "Try doing something like: ",
+                                                                                     hint,
+                                                                                     app);
                                            });
                     }
                 }
@@ -330,15 +336,18 @@ impl EarlyLintPass for MiscEarly {
                                            lit.span,
                                            "this is a decimal constant",
                                            |db| {
-                            db.span_suggestion(
+                            // only the author knows which one was meant
+                            db.span_suggestion_with_applicability(
                                 lit.span,
                                 "if you mean to use a decimal constant, remove the `0` to remove confusion:",
                                 src[1..].to_string(),
+                                Applicability::MaybeIncorrect,
                             );
-                            db.span_suggestion(
+                            db.span_suggestion_with_applicability(
                                 lit.span,
                                 "if you mean to use an octal constant, use `0o`:",
                                 format!("0o{}", &src[1..]),
+                                Applicability::MaybeIncorrect,
                             );
                         });
                     }
@@ -26,7 +26,7 @@ use rustc::ty;
 use syntax::ast;
 use syntax::attr;
 use syntax::codemap::Span;
-use utils::in_macro;
+use utils::{in_macro, span_lint};
 
 /// **What it does:** Warns if there is missing doc for any documentable item (public or private).
 ///
@@ -79,9 +79,10 @@ impl MissingDoc {
 
         let has_doc = attrs.iter().any(|a| a.is_value_str() && a.name().map_or(false, |n| n == "doc"));
         if !has_doc {
-            cx.span_lint(MISSING_DOCS_IN_PRIVATE_ITEMS,
-                         sp,
-                         &format!("missing documentation for {}", desc));
+            span_lint(cx,
+                      MISSING_DOCS_IN_PRIVATE_ITEMS,
+                      sp,
+                      &format!("missing documentation for {}", desc));
         }
     }
 }
@@ -6,8 +6,8 @@ use rustc::lint::*;
 use rustc::hir::*;
 use syntax::ast::LitKind;
 use syntax::codemap::Spanned;
-use utils::{span_lint, span_lint_and_then, snippet};
-use utils::sugg::Sugg;
+use utils::{span_lint, span_lint_and_then, snippet_with_applicability};
+use utils::sugg::{Applicability, Sugg, SuggestionExt};
 
 /// **What it does:** Checks for expressions of the form `if c { true } else { false }`
 /// (or vice versa) and suggest using the condition directly.
@@ -61,7 +61,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessBool {
         use self::Expression::*;
         if let ExprIf(ref pred, ref then_block, Some(ref else_expr)) = e.node {
             let reduce = |ret, not| {
-                let snip = Sugg::hir(cx, pred, "<predicate>");
+                let mut app = Applicability::MachineApplicable;
+                let snip = Sugg::hir_with_applicability(cx, pred, "<predicate>", &mut app);
                 let snip = if not { !snip } else { snip };
 
                 let hint = if ret {
@@ -74,7 +75,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessBool {
                                    NEEDLESS_BOOL,
                                    e.span,
                                    "this if-then-else expression returns a bool literal",
-                                   |db| { db.span_suggestion(e.span, "you can reduce it to", hint); });
+                                   |db| {
+                    db.span_suggestion_with_applicability(e.span, "you can reduce it to", hint, app);
+                });
             };
             if let ExprBlock(ref then_block) = then_block.node {
             match (fetch_bool_block(then_block), fetch_bool_expr(else_expr)) {
@@ -120,39 +123,53 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for BoolComparison {
         if let ExprBinary(Spanned { node: BiEq, .. }, ref left_side, ref right_side) = e.node {
             match (fetch_bool_expr(left_side), fetch_bool_expr(right_side)) {
                 (Bool(true), Other) => {
-                    let hint = snippet(cx, right_side.span, "..").into_owned();
+                    let mut app = Applicability::MachineApplicable;
+                    let hint = snippet_with_applicability(cx, right_side.span, "..", &mut app).into_owned();
                     span_lint_and_then(cx,
                                        BOOL_COMPARISON,
                                        e.span,
                                        "equality checks against true are unnecessary",
-                                       |db| { db.span_suggestion(e.span, "try simplifying it as shown:", hint); });
+                                       |db| {
+                        db.span_suggestion_with_applicability(e.span, "try simplifying it as shown:", hint, app);
+                    });
                 },
                 (Other, Bool(true)) => {
-                    let hint = snippet(cx, left_side.span, "..").into_owned();
+                    let mut app = Applicability::MachineApplicable;
+                    let hint = snippet_with_applicability(cx, left_side.span, "..", &mut app).into_owned();
                     span_lint_and_then(cx,
                                        BOOL_COMPARISON,
                                        e.span,
                                        "equality checks against true are unnecessary",
-                                       |db| { db.span_suggestion(e.span, "try simplifying it as shown:", hint); });
+                                       |db| {
+                        db.span_suggestion_with_applicability(e.span, "try simplifying it as shown:", hint, app);
+                    });
                 },
                 (Bool(false), Other) => {
-                    let hint = Sugg::hir(cx, right_side, "..");
+                    let mut app = Applicability::MachineApplicable;
+                    let hint = Sugg::hir_with_applicability(cx, right_side, "..", &mut app);
                     span_lint_and_then(cx,
                                        BOOL_COMPARISON,
                                        e.span,
                                        "equality checks against false can be replaced by a negation",
                                        |db| {
-                        db.span_suggestion(e.span, "try simplifying it as shown:", (!hint).to_string());
+                        db.span_suggestion_with_applicability(e.span,
+                                                              "try simplifying it as shown:",
+                                                              (!hint).to_string(),
+                                                              app);
                     });
                 },
                 (Other, Bool(false)) => {
-                    let hint = Sugg::hir(cx, left_side, "..");
+                    let mut app = Applicability::MachineApplicable;
+                    let hint = Sugg::hir_with_applicability(cx, left_side, "..", &mut app);
                     span_lint_and_then(cx,
                                        BOOL_COMPARISON,
                                        e.span,
                                        "equality checks against false can be replaced by a negation",
                                        |db| {
-                        db.span_suggestion(e.span, "try simplifying it as shown:", (!hint).to_string());
+                        db.span_suggestion_with_applicability(e.span,
+                                                              "try simplifying it as shown:",
+                                                              (!hint).to_string(),
+                                                              app);
                     });
                 },
                 _ => (),
@@ -11,6 +11,7 @@ use syntax_pos::Span;
 use syntax::errors::DiagnosticBuilder;
 use utils::{in_macro, is_self, is_copy, implements_trait, get_trait_def_id, match_type, snippet, span_lint_and_then,
             multispan_sugg, paths};
+use utils::sugg::{Applicability, SuggestionExt};
 use std::collections::{HashSet, HashMap};
 
 /// **What it does:** Checks for functions taking arguments by value, but not consuming them in its
@@ -145,17 +146,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessPassByValue {
                             .map(|ps| ps.parameters.types()[0]),
                     ], {
                         let slice_ty = format!("&[{}]", snippet(cx, elem_ty.span, "_"));
-                        db.span_suggestion(input.span,
-                                        &format!("consider changing the type to `{}`", slice_ty),
-                                        slice_ty);
+                        db.span_suggestion_with_applicability(input.span,
+                                                              &format!("consider changing the type to `{}`", slice_ty),
+                                                              slice_ty,
+                                                              Applicability::MaybeIncorrect);
                         assert!(deref_span.is_none());
                         return; // `Vec` and `String` cannot be destructured - no need for `*` suggestion
                     }}
 
                     if match_type(cx, ty, &paths::STRING) {
-                        db.span_suggestion(input.span,
-                                           "consider changing the type to `&str`",
-                                           "&str".to_string());
+                        db.span_suggestion_with_applicability(input.span,
+                                                              "consider changing the type to `&str`",
+                                                              "&str".to_string(),
+                                                              Applicability::MaybeIncorrect);
                         assert!(deref_span.is_none());
                         return;
                     }
@@ -168,7 +171,11 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessPassByValue {
                                      .map(|span| (span, format!("*{}", snippet(cx, span, "<expr>")))));
                         spans.sort_by_key(|&(span, _)| span);
                     }
-                    multispan_sugg(db, "consider taking a reference instead".to_string(), spans);
+                    // the callers of the function have to be changed too
+                    multispan_sugg(db,
+                                   "consider taking a reference instead".to_string(),
+                                   spans,
+                                   Applicability::MaybeIncorrect);
                 };
 
                 span_lint_and_then(cx,
@@ -7,7 +7,7 @@ use syntax::ast;
 use syntax::codemap::Span;
 use utils::paths;
 use utils::{get_trait_def_id, implements_trait, in_external_macro, return_ty, same_tys, span_lint_and_then};
-use utils::sugg::DiagnosticBuilderExt;
+use utils::sugg::{Applicability, DiagnosticBuilderExt};
 
 /// **What it does:** Checks for types with a `fn new() -> Self` method and no
 /// implementation of
@@ -124,7 +124,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NewWithoutDefault {
                                                      `Default` implementation for `{}`",
                                                     self_ty),
                                            |db| {
-                            db.suggest_item_with_attr(cx, sp, "try this", "#[derive(Default)]");
+                            // the type could already have a `derive` attribute
+                            db.suggest_item_with_attr(cx,
+                                                      sp,
+                                                      "try this",
+                                                      "#[derive(Default)]",
+                                                      Applicability::MaybeIncorrect);
                         });
                     } else {
                         span_lint_and_then(cx,
@@ -142,7 +147,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NewWithoutDefault {
         Self::new()
     }}
 }}",
-                                                           self_ty));
+                                                           self_ty),
+                                                  Applicability::MachineApplicable);
                         });
                     }
                 }}
@@ -2,6 +2,7 @@ use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::hir::def::Def;
 use rustc::hir::{Expr, Expr_, Stmt, StmtSemi, BlockCheckMode, UnsafeSource, BiAnd, BiOr};
 use utils::{in_macro, span_lint, snippet_opt, span_lint_and_then};
+use utils::sugg::{Applicability, SuggestionExt};
 use std::ops::Deref;
 
 /// **What it does:** Checks for statements which have no effect.
@@ -124,7 +125,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                                    UNNECESSARY_OPERATION,
                                    stmt.span,
                                    "statement can be reduced",
-                                   |db| { db.span_suggestion(stmt.span, "replace it with", snippet); });
+                                   |db| {
+                    db.span_suggestion_with_applicability(stmt.span,
+                                                          "replace it with",
+                                                          snippet,
+                                                          Applicability::MachineApplicable);
+                });
             }
         }
     }
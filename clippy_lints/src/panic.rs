@@ -1,7 +1,7 @@
 use rustc::hir::*;
 use rustc::lint::*;
 use syntax::ast::LitKind;
-use utils::{is_direct_expn_of, match_def_path, resolve_node, paths, span_lint};
+use utils::{in_macro, is_direct_expn_of, is_expn_of, match_def_path, resolve_node, paths, span_lint};
 
 /// **What it does:** Checks for missing parameters in `panic!`.
 ///
@@ -23,17 +23,59 @@ declare_lint! {
     "missing parameters in `panic!` calls"
 }
 
+/// **What it does:** Checks for usage of `panic!`.
+///
+/// **Why is this bad?** Code that must report all its errors to its caller
+/// should not panic.
+///
+/// **Known problems:** Panics from macros like `assert!` are not linted.
+///
+/// **Example:**
+/// ```rust
+/// panic!("even with a good reason");
+/// ```
+declare_restriction_lint! {
+    pub PANIC,
+    "usage of the `panic!` macro"
+}
+
+/// **What it does:** Checks for usage of `unreachable!`.
+///
+/// **Why is this bad?** Like `panic!`, it panics at runtime when the
+/// supposedly unreachable code is reached.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// unreachable!();
+/// ```
+declare_restriction_lint! {
+    pub UNREACHABLE,
+    "usage of the `unreachable!` macro"
+}
+
 #[allow(missing_copy_implementations)]
 pub struct Pass;
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(PANIC_PARAMS)
+        lint_array!(PANIC_PARAMS, PANIC, UNREACHABLE)
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_let_chain! {[
+            let ExprCall(ref fun, _) = expr.node,
+            let ExprPath(ref qpath) = fun.node
+        ], {
+            let def_id = resolve_node(cx, qpath, fun.id).def_id();
+            if match_def_path(cx.tcx, def_id, &paths::BEGIN_PANIC) ||
+               match_def_path(cx.tcx, def_id, &paths::BEGIN_PANIC_FMT) {
+                lint_panic_call(cx, expr);
+            }
+        }}
         if_let_chain! {[
             let ExprBlock(ref block) = expr.node,
             let Some(ref ex) = block.expr,
@@ -52,3 +94,21 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         }}
     }
 }
+
+/// Lint the `panic!` or `unreachable!` call site `expr` was expanded from, unless the macro itself
+/// comes from another macro, like `assert!`.
+fn lint_panic_call(cx: &LateContext, expr: &Expr) {
+    if let Some(span) = is_expn_of(expr.span, "unreachable") {
+        if !in_macro(span) {
+            span_lint(cx, UNREACHABLE, span, "use of `unreachable!`");
+        }
+    } else if let Some(mut span) = is_expn_of(expr.span, "panic") {
+        // `panic!()` expands to another `panic!` with a default message
+        while let Some(outer) = is_direct_expn_of(span, "panic") {
+            span = outer;
+        }
+        if !in_macro(span) {
+            span_lint(cx, PANIC, span, "use of `panic!`");
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use rustc::lint::*;
 use syntax::ast::*;
 use syntax::codemap::Spanned;
-use utils::{span_lint_and_then, snippet};
+use utils::{span_lint_and_then, snippet_with_applicability};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for operations where precedence may be unclear
 /// and suggests to add parentheses. Currently it catches the following:
@@ -37,9 +38,12 @@ impl EarlyLintPass for Precedence {
     fn check_expr(&mut self, cx: &EarlyContext, expr: &Expr) {
         if let ExprKind::Binary(Spanned { node: op, .. }, ref left, ref right) = expr.node {
             let span_sugg =
-                |expr: &Expr, sugg| {
+                |expr: &Expr, sugg, applicability| {
                     span_lint_and_then(cx, PRECEDENCE, expr.span, "operator precedence can trip the unwary", |db| {
-                        db.span_suggestion(expr.span, "consider parenthesizing your expression", sugg);
+                        db.span_suggestion_with_applicability(expr.span,
+                                                              "consider parenthesizing your expression",
+                                                              sugg,
+                                                              applicability);
                     });
                 };
 
@@ -48,25 +52,28 @@ impl EarlyLintPass for Precedence {
             }
             match (is_arith_expr(left), is_arith_expr(right)) {
                 (true, true) => {
+                    let mut app = Applicability::MachineApplicable;
                     let sugg = format!("({}) {} ({})",
-                                       snippet(cx, left.span, ".."),
+                                       snippet_with_applicability(cx, left.span, "..", &mut app),
                                        op.to_string(),
-                                       snippet(cx, right.span, ".."));
-                    span_sugg(expr, sugg);
+                                       snippet_with_applicability(cx, right.span, "..", &mut app));
+                    span_sugg(expr, sugg, app);
                 },
                 (true, false) => {
+                    let mut app = Applicability::MachineApplicable;
                     let sugg = format!("({}) {} {}",
-                                       snippet(cx, left.span, ".."),
+                                       snippet_with_applicability(cx, left.span, "..", &mut app),
                                        op.to_string(),
-                                       snippet(cx, right.span, ".."));
-                    span_sugg(expr, sugg);
+                                       snippet_with_applicability(cx, right.span, "..", &mut app));
+                    span_sugg(expr, sugg, app);
                 },
                 (false, true) => {
+                    let mut app = Applicability::MachineApplicable;
                     let sugg = format!("{} {} ({})",
-                                       snippet(cx, left.span, ".."),
+                                       snippet_with_applicability(cx, left.span, "..", &mut app),
                                        op.to_string(),
-                                       snippet(cx, right.span, ".."));
-                    span_sugg(expr, sugg);
+                                       snippet_with_applicability(cx, right.span, "..", &mut app));
+                    span_sugg(expr, sugg, app);
                 },
                 (false, false) => (),
             }
@@ -85,9 +92,13 @@ impl EarlyLintPass for Precedence {
                                                    expr.span,
                                                    "unary minus has lower precedence than method call",
                                                    |db| {
-                                    db.span_suggestion(expr.span,
-                                                       "consider adding parentheses to clarify your intent",
-                                                       format!("-({})", snippet(cx, rhs.span, "..")));
+                                    let mut app = Applicability::MachineApplicable;
+                                    let rhs = snippet_with_applicability(cx, rhs.span, "..", &mut app);
+                                    db.span_suggestion_with_applicability(expr.span,
+                                                                          "consider adding parentheses to clarify \
+                                                                           your intent",
+                                                                          format!("-({})", rhs),
+                                                                          app);
                                 });
                             },
                             _ => (),
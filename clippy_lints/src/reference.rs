@@ -1,6 +1,7 @@
 use syntax::ast::{Expr, ExprKind, UnOp};
 use rustc::lint::*;
-use utils::{span_lint_and_then, snippet};
+use utils::{span_lint_and_then, snippet_with_applicability};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for usage of `*&` and `*&mut` in expressions.
 ///
@@ -41,7 +42,9 @@ impl EarlyLintPass for Pass {
         if let ExprKind::Unary(UnOp::Deref, ref deref_target) = e.node {
             if let ExprKind::AddrOf(_, ref addrof_target) = without_parens(deref_target).node {
                 span_lint_and_then(cx, DEREF_ADDROF, e.span, "immediately dereferencing a reference", |db| {
-                    db.span_suggestion(e.span, "try this", format!("{}", snippet(cx, addrof_target.span, "_")));
+                    let mut app = Applicability::MachineApplicable;
+                    let sugg = snippet_with_applicability(cx, addrof_target.span, "_", &mut app).into_owned();
+                    db.span_suggestion_with_applicability(e.span, "try this", sugg, app);
                 });
             }
         }
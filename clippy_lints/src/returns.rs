@@ -4,6 +4,7 @@ use syntax::codemap::{Span, Spanned};
 use syntax::visit::FnKind;
 
 use utils::{span_note_and_lint, span_lint_and_then, snippet_opt, match_path_ast, in_external_macro};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for return statements at the end of a block.
 ///
@@ -97,7 +98,10 @@ impl ReturnPass {
                            ret_span,
                            "unneeded return statement",
                            |db| if let Some(snippet) = snippet_opt(cx, inner_span) {
-                               db.span_suggestion(ret_span, "remove `return` as shown:", snippet);
+                               db.span_suggestion_with_applicability(ret_span,
+                                                                     "remove `return` as shown:",
+                                                                     snippet,
+                                                                     Applicability::MachineApplicable);
                            });
     }
 
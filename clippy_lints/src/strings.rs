@@ -140,7 +140,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StringLitAsBytes {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
         use std::ascii::AsciiExt;
         use syntax::ast::LitKind;
-        use utils::{snippet, in_macro};
+        use utils::{snippet_with_applicability, in_macro};
+        use utils::sugg::{Applicability, SuggestionExt};
 
         if let ExprMethodCall(ref name, _, ref args) = e.node {
             if name.node == "as_bytes" {
@@ -152,8 +153,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StringLitAsBytes {
                                                e.span,
                                                "calling `as_bytes()` on a string literal",
                                                |db| {
-                                let sugg = format!("b{}", snippet(cx, args[0].span, r#""foo""#));
-                                db.span_suggestion(e.span, "consider using a byte string literal instead", sugg);
+                                let mut app = Applicability::MachineApplicable;
+                                let sugg = format!("b{}",
+                                                   snippet_with_applicability(cx, args[0].span, r#""foo""#, &mut app));
+                                db.span_suggestion_with_applicability(e.span,
+                                                                      "consider using a byte string literal instead",
+                                                                      sugg,
+                                                                      app);
                             });
 
                         }
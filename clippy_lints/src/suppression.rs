@@ -0,0 +1,209 @@
+//! Suppression comments, `// clippy: allow(lint_name) reason="..."`, see `utils::suppression`.
+
+use rustc::hir;
+use rustc::lint::*;
+use syntax::ast::*;
+use syntax::codemap::{BytePos, Span, NO_EXPANSION};
+use syntax::visit::{self, Visitor};
+use utils::{catalogue, in_macro, span_lint, span_lint_and_then};
+use utils::conf::similar_name;
+use utils::suppression::{self, parse, Kind, Suppression};
+
+/// **What it does:** Checks for `// clippy: allow(lint_name)` comments that do not suppress
+/// anything, because the lint is not emitted on the statement or item that follows the comment,
+/// because nothing follows it, or because the comment is malformed.
+///
+/// A suppression comment allows Clippy lints on the next statement or item, where attributes
+/// cannot always be used. It may give a justification for audits, which is part of the output of
+/// `cargo clippy --message-format=json`:
+///
+/// ```rust
+/// // clippy: allow(needless_range_loop) reason="`i` is also used to index `other`"
+/// for i in 0..vec.len() { .. }
+/// ```
+///
+/// **Why is this bad?** An unused suppression is noise, and it would hide the lint if the code it
+/// is about came back.
+///
+/// **Known problems:** Only Clippy's lints can be suppressed, not rustc's. A lint that is allowed
+/// where the comment is is never emitted, so suppressing it is reported as unused.
+///
+/// **Example:**
+/// ```rust
+/// // clippy: allow(needless_range_loop)
+/// let x = 42;
+/// ```
+declare_lint! {
+    pub UNUSED_SUPPRESSION,
+    Warn,
+    "a `// clippy: allow(..)` comment that does not suppress any lint"
+}
+
+/// Collect the suppression comments of the crate before any lint runs.
+#[derive(Copy, Clone)]
+pub struct Suppressions;
+
+impl LintPass for Suppressions {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNUSED_SUPPRESSION)
+    }
+}
+
+impl EarlyLintPass for Suppressions {
+    fn check_crate(&mut self, cx: &EarlyContext, krate: &Crate) {
+        let mut targets = Targets { spans: Vec::new() };
+        visit::walk_crate(&mut targets, krate);
+        targets.spans.sort_by(|a, b| a.lo.cmp(&b.lo).then(b.hi.cmp(&a.hi)));
+
+        let codemap = cx.sess().codemap();
+        let mut suppressions = Vec::new();
+        let mut malformed = Vec::new();
+        let mut rest = &targets.spans[..];
+        while let Some(first) = rest.first() {
+            let fm = codemap.lookup_byte_offset(first.lo).fm;
+            let in_file = 1 + rest[1..].iter().take_while(|span| span.lo < fm.end_pos).count();
+            if let Some(ref src) = fm.src {
+                let start = fm.start_pos;
+                let mut offset = 0;
+                for (i, line) in src.split('\n').enumerate() {
+                    let comment = line.trim_left();
+                    if comment.starts_with("//") && !comment.starts_with("///") && !comment.starts_with("//!") {
+                        if let Some(parsed) = parse(&comment[2..]) {
+                            let span = Span {
+                                lo: start + BytePos((offset + line.len() - comment.len()) as u32),
+                                hi: start + BytePos((offset + line.trim_right().len()) as u32),
+                                ctxt: NO_EXPANSION,
+                            };
+                            if let Ok((lints, reason)) = parsed {
+                                suppressions.push(Suppression {
+                                    kind: Kind::Comment,
+                                    used: vec![false; lints.len()],
+                                    lints: lints,
+                                    reason: reason,
+                                    span: span,
+                                    target: target(src, start, span, &rest[..in_file]),
+                                    file_name: fm.name.clone(),
+                                    line: i + 1,
+                                });
+                            } else {
+                                malformed.push(span);
+                            }
+                        }
+                    }
+                    offset += line.len() + 1;
+                }
+            }
+            rest = &rest[in_file..];
+        }
+
+        suppression::install(suppressions);
+        for span in malformed {
+            span_lint(cx,
+                      UNUSED_SUPPRESSION,
+                      span,
+                      "malformed suppression comment, expected `// clippy: allow(lint_name) reason=\"...\"`");
+        }
+    }
+}
+
+/// The statement or item a suppression comment at `comment` applies to: the first of `targets`, in
+/// the file starting at `start` made of `src`, to come after the comment with only whitespace,
+/// comments and attributes in between.
+fn target(src: &str, start: BytePos, comment: Span, targets: &[Span]) -> Option<Span> {
+    if let Some(next) = targets.iter().find(|span| span.lo >= comment.hi) {
+        let gap = &src[(comment.hi - start).0 as usize..(next.lo - start).0 as usize];
+        if gap.lines().map(str::trim).all(|line| line.is_empty() || line.starts_with("//") || line.starts_with('#')) {
+            return Some(*next);
+        }
+    }
+    None
+}
+
+/// Collect the spans of the statements and items a suppression comment can apply to.
+struct Targets {
+    spans: Vec<Span>,
+}
+
+impl Targets {
+    fn push(&mut self, span: Span) {
+        if !in_macro(span) {
+            self.spans.push(span);
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for Targets {
+    fn visit_item(&mut self, item: &'a Item) {
+        self.push(item.span);
+        visit::walk_item(self, item);
+    }
+
+    fn visit_trait_item(&mut self, item: &'a TraitItem) {
+        self.push(item.span);
+        visit::walk_trait_item(self, item);
+    }
+
+    fn visit_impl_item(&mut self, item: &'a ImplItem) {
+        self.push(item.span);
+        visit::walk_impl_item(self, item);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        self.push(stmt.span);
+        visit::walk_stmt(self, stmt);
+    }
+
+    fn visit_mac(&mut self, _: &'a Mac) {
+        // macros are expanded by now
+    }
+}
+
+/// Report the suppression comments that did not suppress anything, once all the lints ran.
+#[derive(Copy, Clone)]
+pub struct UnusedSuppressions;
+
+impl LintPass for UnusedSuppressions {
+    fn get_lints(&self) -> LintArray {
+        // `UNUSED_SUPPRESSION` is registered by `Suppressions`, a lint cannot be registered twice
+        lint_array!()
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedSuppressions {
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx hir::Crate) {
+        // linting marks the suppressions as used, so they cannot be borrowed meanwhile
+        for comment in suppression::with(|suppressions| suppressions.to_vec()) {
+            if comment.kind != Kind::Comment {
+                continue;
+            }
+            if comment.target.is_none() {
+                span_lint(cx,
+                          UNUSED_SUPPRESSION,
+                          comment.span,
+                          "this suppression comment is not followed by a statement or an item");
+                continue;
+            }
+            for (name, _) in comment.lints.iter().zip(&comment.used).filter(|&(_, &used)| !used) {
+                if catalogue::find(name).is_some() || catalogue::find_group(name).is_some() {
+                    span_lint(cx,
+                              UNUSED_SUPPRESSION,
+                              comment.span,
+                              &format!("`{}` is not emitted on the next statement or item, this suppression is unused",
+                                       name));
+                    continue;
+                }
+                let candidates = catalogue::LINTS
+                    .iter()
+                    .map(|lint| lint.name)
+                    .chain(catalogue::GROUPS.iter().map(|group| group.name));
+                span_lint_and_then(cx,
+                                   UNUSED_SUPPRESSION,
+                                   comment.span,
+                                   &format!("`{}` is not a Clippy lint or lint group", name),
+                                   |db| if let Some(similar) = similar_name(name, candidates) {
+                                       db.help(&format!("did you mean `{}`?", similar));
+                                   });
+            }
+        }
+    }
+}
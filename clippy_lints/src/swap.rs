@@ -1,8 +1,9 @@
 use rustc::hir::*;
 use rustc::lint::*;
 use rustc::ty;
-use utils::{differing_macro_contexts, match_type, paths, snippet, span_lint_and_then, walk_ptrs_ty, SpanlessEq};
-use utils::sugg::Sugg;
+use utils::{differing_macro_contexts, match_type, paths, snippet_with_applicability, span_lint_and_then, walk_ptrs_ty,
+            SpanlessEq};
+use utils::sugg::{Applicability, Sugg, SuggestionExt};
 use syntax_pos::{Span, NO_EXPANSION};
 
 /// **What it does:** Checks for manual swapping.
@@ -104,14 +105,15 @@ fn check_manual_swap(cx: &LateContext, block: &Block) {
                 None
             }
 
+            let mut applicability = Applicability::MachineApplicable;
             let (replace, what, sugg) = if let Some((slice, idx1, idx2)) = check_for_slice(cx, lhs1, lhs2) {
                 if let Some(slice) = Sugg::hir_opt(cx, slice) {
                     (false,
                      format!(" elements of `{}`", slice),
                      format!("{}.swap({}, {})",
                              slice.maybe_par(),
-                             snippet(cx, idx1.span, ".."),
-                             snippet(cx, idx2.span, "..")))
+                             snippet_with_applicability(cx, idx1.span, "..", &mut applicability),
+                             snippet_with_applicability(cx, idx2.span, "..", &mut applicability)))
                 } else {
                     (false, "".to_owned(), "".to_owned())
                 }
@@ -130,7 +132,7 @@ fn check_manual_swap(cx: &LateContext, block: &Block) {
                                &format!("this looks like you are swapping{} manually", what),
                                |db| {
                                    if !sugg.is_empty() {
-                                       db.span_suggestion(span, "try", sugg);
+                                       db.span_suggestion_with_applicability(span, "try", sugg, applicability);
 
                                        if replace {
                                            db.note("or maybe you should use `std::mem::replace`?");
@@ -169,8 +171,12 @@ fn check_suspicious_swap(cx: &LateContext, block: &Block) {
                                &format!("this looks like you are trying to swap{}", what),
                                |db| {
                                    if !what.is_empty() {
-                                       db.span_suggestion(span, "try",
-                                                          format!("std::mem::swap({}, {})", lhs, rhs));
+                                       // this might not be a swap at all
+                                       let sugg = format!("std::mem::swap({}, {})", lhs, rhs);
+                                       db.span_suggestion_with_applicability(span,
+                                                                             "try",
+                                                                             sugg,
+                                                                             Applicability::MaybeIncorrect);
                                        db.note("or maybe you should use `std::mem::replace`?");
                                    }
                                });
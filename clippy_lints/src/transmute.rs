@@ -2,8 +2,9 @@ use rustc::lint::*;
 use rustc::ty::TypeVariants::{TyRawPtr, TyRef};
 use rustc::ty;
 use rustc::hir::*;
-use utils::{match_def_path, paths, span_lint, span_lint_and_then, snippet, last_path_segment};
+use utils::{match_def_path, paths, span_lint, span_lint_and_sugg, span_lint_and_then, snippet, last_path_segment};
 use utils::sugg;
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for transmutes that can't ever be correct on any
 /// architecture.
@@ -102,31 +103,32 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                       &format!("transmute from a type (`{}`) to itself", from_ty))
                         },
                         (&TyRef(_, rty), &TyRawPtr(ptr_ty)) => {
-                            span_lint_and_then(cx,
+                            let mut applicability = Applicability::MachineApplicable;
+                            let arg = sugg::Sugg::hir_with_applicability(cx, &args[0], "..", &mut applicability);
+                            let sugg = if ptr_ty == rty {
+                                arg.as_ty(to_ty)
+                            } else {
+                                arg.as_ty(cx.tcx.mk_ptr(rty)).as_ty(to_ty)
+                            };
+                            span_lint_and_sugg(cx,
                                                USELESS_TRANSMUTE,
                                                e.span,
                                                "transmute from a reference to a pointer",
-                                               |db| if let Some(arg) = sugg::Sugg::hir_opt(cx, &args[0]) {
-                                                   let sugg = if ptr_ty == rty {
-                                                       arg.as_ty(to_ty)
-                                                   } else {
-                                                       arg.as_ty(cx.tcx.mk_ptr(rty)).as_ty(to_ty)
-                                                   };
-
-                                                   db.span_suggestion(e.span, "try", sugg.to_string());
-                                               })
+                                               "try",
+                                               sugg.to_string(),
+                                               applicability);
                         },
                         (&ty::TyInt(_), &TyRawPtr(_)) |
                         (&ty::TyUint(_), &TyRawPtr(_)) => {
-                            span_lint_and_then(cx,
+                            let mut applicability = Applicability::MachineApplicable;
+                            let arg = sugg::Sugg::hir_with_applicability(cx, &args[0], "..", &mut applicability);
+                            span_lint_and_sugg(cx,
                                                USELESS_TRANSMUTE,
                                                e.span,
                                                "transmute from an integer to a pointer",
-                                               |db| if let Some(arg) = sugg::Sugg::hir_opt(cx, &args[0]) {
-                                                   db.span_suggestion(e.span,
-                                                                      "try",
-                                                                      arg.as_ty(&to_ty.to_string()).to_string());
-                                               })
+                                               "try",
+                                               arg.as_ty(&to_ty.to_string()).to_string(),
+                                               applicability);
                         },
                         (&ty::TyFloat(_), &TyRef(..)) |
                         (&ty::TyFloat(_), &TyRawPtr(_)) |
@@ -162,7 +164,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                                         from_ty,
                                                         to_ty),
                                                |db| {
-                                let arg = sugg::Sugg::hir(cx, &args[0], "..");
+                                let mut app = Applicability::MachineApplicable;
+                                let arg = sugg::Sugg::hir_with_applicability(cx, &args[0], "..", &mut app);
                                 let (deref, cast) = if to_rty.mutbl == Mutability::MutMutable {
                                     ("&mut *", "*mut")
                                 } else {
@@ -175,7 +178,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                     arg.as_ty(&format!("{} {}", cast, get_type_snippet(cx, qpath, to_rty.ty)))
                                 };
 
-                                db.span_suggestion(e.span, "try", sugg::make_unop(deref, arg).to_string());
+                                db.span_suggestion_with_applicability(e.span,
+                                                                      "try",
+                                                                      sugg::make_unop(deref, arg).to_string(),
+                                                                      app);
                             })
                         },
                         _ => return,
@@ -1,15 +1,15 @@
+use consts::{constant, Constant};
 use reexport::*;
 use rustc::hir::*;
 use rustc::hir::intravisit::{FnKind, Visitor, walk_ty, NestedVisitorMap};
 use rustc::lint::*;
 use rustc::ty;
-use std::cmp::Ordering;
 use syntax::ast::{IntTy, UintTy, FloatTy};
-use syntax::attr::IntType;
 use syntax::codemap::Span;
 use utils::{comparisons, higher, in_external_macro, in_macro, match_def_path, snippet, span_help_and_lint, span_lint,
             opt_def_id, last_path_segment, type_size};
 use utils::paths;
+use utils::interval::{interval, FullInt, Interval};
 
 /// Handles all the linting of funky types
 #[allow(missing_copy_implementations)]
@@ -547,6 +547,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                 }
             }
             if cast_from.is_numeric() && cast_to.is_numeric() && !in_external_macro(cx, expr.span) {
+                // the values the operand can have, unless it is a constant, which can be written
+                // with the right type in the first place
+                let range = interval(cx, ex).and_then(|range| if range.is_constant() { None } else { Some(range) });
                 match (cast_from.is_integral(), cast_to.is_integral()) {
                     (true, false) => {
                         let from_nbits = int_ty_to_nbits(cast_from);
@@ -555,7 +558,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                         } else {
                             64
                         };
-                        if is_isize_or_usize(cast_from) || from_nbits >= to_nbits {
+                        // floats represent all the integers up to 2 to the power of their mantissa
+                        // width plus one
+                        let mantissa_nbits = if to_nbits == 64 { 52 } else { 23 };
+                        let exact_bound = 1i128 << (mantissa_nbits + 1);
+                        let exact = range.and_then(|range| range.bounds())
+                            .map_or(false, |(min, max)| -exact_bound <= min && max <= exact_bound);
+                        if (is_isize_or_usize(cast_from) || from_nbits >= to_nbits) && !exact {
                             span_precision_loss_lint(cx, expr, cast_from, to_nbits == 64);
                         }
                     },
@@ -572,13 +581,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                         }
                     },
                     (true, true) => {
-                        if cast_from.is_signed() && !cast_to.is_signed() {
+                        if cast_from.is_signed() && !cast_to.is_signed() &&
+                           range.map_or(true, |range| range.min < FullInt::S(0)) {
                             span_lint(cx,
                                       CAST_SIGN_LOSS,
                                       expr.span,
                                       &format!("casting {} to {} may lose the sign of the value", cast_from, cast_to));
                         }
-                        check_truncation_and_wrapping(cx, expr, cast_from, cast_to);
+                        // `isize` and `usize` have the fewest values on 32-bit targets
+                        let fits = Interval::of_type(cast_to, 32)
+                            .map_or(false, |to_range| range.map_or(false, |range| to_range.contains(&range)));
+                        if !fits {
+                            check_truncation_and_wrapping(cx, expr, cast_from, cast_to);
+                        }
                     },
                     (false, false) => {
                         if let (&ty::TyFloat(FloatTy::F64), &ty::TyFloat(FloatTy::F32)) =
@@ -811,8 +826,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CharLitAsU8 {
 }
 
 /// **What it does:** Checks for comparisons where one side of the relation is
-/// either the minimum or maximum value for its type and warns if it involves a
-/// case that is always true or always false. Only integer and boolean types are
+/// either the minimum or maximum value for its type, or of the values the other
+/// side can have, like `255` for `x & 0xff`, and warns if it involves a case
+/// that is always true or always false. Only integer and boolean types are
 /// checked.
 ///
 /// **Why is this bad?** An expression like `min <= x` may misleadingly imply
@@ -848,6 +864,9 @@ enum ExtremeType {
 struct ExtremeExpr<'a> {
     which: ExtremeType,
     expr: &'a Expr,
+    /// The other operand if `expr` is an extreme of the values it can have, `None` if `expr` is an
+    /// extreme of their type.
+    of: Option<&'a Expr>,
 }
 
 enum AbsurdComparisonResult {
@@ -881,8 +900,8 @@ fn detect_absurd_comparison<'a>(
         return None;
     };
 
-    let lx = detect_extreme_expr(cx, normalized_lhs);
-    let rx = detect_extreme_expr(cx, normalized_rhs);
+    let lx = detect_extreme_expr(cx, normalized_lhs, normalized_rhs);
+    let rx = detect_extreme_expr(cx, normalized_rhs, normalized_lhs);
 
     Some(match rel {
         Rel::Lt => {
@@ -905,62 +924,52 @@ fn detect_absurd_comparison<'a>(
     })
 }
 
-fn detect_extreme_expr<'a>(cx: &LateContext, expr: &'a Expr) -> Option<ExtremeExpr<'a>> {
+/// Whether `expr` is the minimum or maximum of its type, or of the values `other` can have.
+fn detect_extreme_expr<'a>(cx: &LateContext, expr: &'a Expr, other: &'a Expr) -> Option<ExtremeExpr<'a>> {
     use rustc::middle::const_val::ConstVal::*;
-    use rustc_const_math::*;
     use rustc_const_eval::*;
     use types::ExtremeType::*;
 
-    let ty = &cx.tables.expr_ty(expr).sty;
-
-    match *ty {
+    match cx.tables.expr_ty(expr).sty {
         ty::TyBool | ty::TyInt(_) | ty::TyUint(_) => (),
         _ => return None,
     };
 
     let cv = match ConstContext::with_tables(cx.tcx, cx.tables).eval(expr) {
         Ok(val) => val,
-        Err(_) => return None,
+        // rustc does not fold method calls like `[0; 8].len()`, but Clippy does
+        Err(_) => {
+            match constant(cx, expr) {
+                Some((Constant::Int(i), _)) => Integral(i),
+                Some((Constant::Bool(b), _)) => Bool(b),
+                _ => return None,
+            }
+        },
     };
 
-    let which = match (ty, cv) {
-        (&ty::TyBool, Bool(false)) |
-        (&ty::TyInt(IntTy::Is), Integral(Isize(Is32(::std::i32::MIN)))) |
-        (&ty::TyInt(IntTy::Is), Integral(Isize(Is64(::std::i64::MIN)))) |
-        (&ty::TyInt(IntTy::I8), Integral(I8(::std::i8::MIN))) |
-        (&ty::TyInt(IntTy::I16), Integral(I16(::std::i16::MIN))) |
-        (&ty::TyInt(IntTy::I32), Integral(I32(::std::i32::MIN))) |
-        (&ty::TyInt(IntTy::I64), Integral(I64(::std::i64::MIN))) |
-        (&ty::TyInt(IntTy::I128), Integral(I128(::std::i128::MIN))) |
-        (&ty::TyUint(UintTy::Us), Integral(Usize(Us32(::std::u32::MIN)))) |
-        (&ty::TyUint(UintTy::Us), Integral(Usize(Us64(::std::u64::MIN)))) |
-        (&ty::TyUint(UintTy::U8), Integral(U8(::std::u8::MIN))) |
-        (&ty::TyUint(UintTy::U16), Integral(U16(::std::u16::MIN))) |
-        (&ty::TyUint(UintTy::U32), Integral(U32(::std::u32::MIN))) |
-        (&ty::TyUint(UintTy::U64), Integral(U64(::std::u64::MIN))) |
-        (&ty::TyUint(UintTy::U128), Integral(U128(::std::u128::MIN))) => Minimum,
-
-        (&ty::TyBool, Bool(true)) |
-        (&ty::TyInt(IntTy::Is), Integral(Isize(Is32(::std::i32::MAX)))) |
-        (&ty::TyInt(IntTy::Is), Integral(Isize(Is64(::std::i64::MAX)))) |
-        (&ty::TyInt(IntTy::I8), Integral(I8(::std::i8::MAX))) |
-        (&ty::TyInt(IntTy::I16), Integral(I16(::std::i16::MAX))) |
-        (&ty::TyInt(IntTy::I32), Integral(I32(::std::i32::MAX))) |
-        (&ty::TyInt(IntTy::I64), Integral(I64(::std::i64::MAX))) |
-        (&ty::TyInt(IntTy::I128), Integral(I128(::std::i128::MAX))) |
-        (&ty::TyUint(UintTy::Us), Integral(Usize(Us32(::std::u32::MAX)))) |
-        (&ty::TyUint(UintTy::Us), Integral(Usize(Us64(::std::u64::MAX)))) |
-        (&ty::TyUint(UintTy::U8), Integral(U8(::std::u8::MAX))) |
-        (&ty::TyUint(UintTy::U16), Integral(U16(::std::u16::MAX))) |
-        (&ty::TyUint(UintTy::U32), Integral(U32(::std::u32::MAX))) |
-        (&ty::TyUint(UintTy::U64), Integral(U64(::std::u64::MAX))) |
-        (&ty::TyUint(UintTy::U128), Integral(U128(::std::u128::MAX))) => Maximum,
-
+    let (which, of) = match cv {
+        Bool(false) => (Minimum, None),
+        Bool(true) => (Maximum, None),
+        Integral(value) => {
+            let value = FullInt::from(value);
+            match Interval::of_target_type(cx, cx.tables.expr_ty(expr)) {
+                Some(range) if value == range.min => (Minimum, None),
+                Some(range) if value == range.max => (Maximum, None),
+                // comparing two known values is not absurd, just constant
+                _ => match interval(cx, other) {
+                    Some(range) if range.is_constant() => return None,
+                    Some(range) if value == range.min => (Minimum, Some(other)),
+                    Some(range) if value == range.max => (Maximum, Some(other)),
+                    _ => return None,
+                },
+            }
+        },
         _ => return None,
     };
     Some(ExtremeExpr {
         which: which,
         expr: expr,
+        of: of,
     })
 }
 
@@ -972,8 +981,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AbsurdExtremeComparisons {
         if let ExprBinary(ref cmp, ref lhs, ref rhs) = expr.node {
             if let Some((culprit, result)) = detect_absurd_comparison(cx, cmp.node, lhs, rhs) {
                 if !in_macro(expr.span) {
-                    let msg = "this comparison involving the minimum or maximum element for this \
-                               type contains a case that is always true or always false";
+                    let msg = if culprit.of.is_some() {
+                        "this comparison involving the minimum or maximum value an operand can have contains a case \
+                         that is always true or always false"
+                    } else {
+                        "this comparison involving the minimum or maximum element for this type contains a case that \
+                         is always true or always false"
+                    };
 
                     let conclusion = match result {
                         AlwaysFalse => "this comparison is always false".to_owned(),
@@ -986,13 +1000,22 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AbsurdExtremeComparisons {
                         },
                     };
 
-                    let help = format!("because {} is the {} value for this type, {}",
-                                       snippet(cx, culprit.expr.span, "x"),
-                                       match culprit.which {
-                                           Minimum => "minimum",
-                                           Maximum => "maximum",
-                                       },
-                                       conclusion);
+                    let which = match culprit.which {
+                        Minimum => "minimum",
+                        Maximum => "maximum",
+                    };
+                    let help = if let Some(other) = culprit.of {
+                        format!("because {} is the {} value {} can have, {}",
+                                snippet(cx, culprit.expr.span, "x"),
+                                which,
+                                snippet(cx, other.span, "y"),
+                                conclusion)
+                    } else {
+                        format!("because {} is the {} value for this type, {}",
+                                snippet(cx, culprit.expr.span, "x"),
+                                which,
+                                conclusion)
+                    };
 
                     span_help_and_lint(cx, ABSURD_EXTREME_COMPARISONS, expr.span, msg, &help);
                 }
@@ -1029,53 +1052,9 @@ impl LintPass for InvalidUpcastComparisons {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq)]
-enum FullInt {
-    S(i128),
-    U(u128),
-}
-
-impl FullInt {
-    #[allow(cast_sign_loss)]
-    fn cmp_s_u(s: i128, u: u128) -> Ordering {
-        if s < 0 {
-            Ordering::Less
-        } else if u > (i128::max_value() as u128) {
-            Ordering::Greater
-        } else {
-            (s as u128).cmp(&u)
-        }
-    }
-}
-
-impl PartialEq for FullInt {
-    fn eq(&self, other: &Self) -> bool {
-        self.partial_cmp(other).expect("partial_cmp only returns Some(_)") == Ordering::Equal
-    }
-}
-
-impl PartialOrd for FullInt {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(match (self, other) {
-            (&FullInt::S(s), &FullInt::S(o)) => s.cmp(&o),
-            (&FullInt::U(s), &FullInt::U(o)) => s.cmp(&o),
-            (&FullInt::S(s), &FullInt::U(o)) => Self::cmp_s_u(s, o),
-            (&FullInt::U(s), &FullInt::S(o)) => Self::cmp_s_u(o, s).reverse(),
-        })
-    }
-}
-impl Ord for FullInt {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).expect("partial_cmp for FullInt can never return None")
-    }
-}
-
-
-fn numeric_cast_precast_bounds<'a>(cx: &LateContext, expr: &'a Expr) -> Option<(FullInt, FullInt)> {
-    use rustc::ty::TypeVariants::{TyInt, TyUint};
-    use syntax::ast::{IntTy, UintTy};
-    use std::*;
-
+/// The values `expr` can have before it is cast, if it is cast to an integer type of another
+/// size.
+fn numeric_cast_precast_bounds(cx: &LateContext, expr: &Expr) -> Option<(FullInt, FullInt)> {
     if let ExprCast(ref cast_exp, _) = expr.node {
         let pre_cast_ty = cx.tables.expr_ty(cast_exp);
         let cast_ty = cx.tables.expr_ty(expr);
@@ -1083,29 +1062,7 @@ fn numeric_cast_precast_bounds<'a>(cx: &LateContext, expr: &'a Expr) -> Option<(
         if type_size(cx, pre_cast_ty) == type_size(cx, cast_ty) {
             return None;
         }
-        match pre_cast_ty.sty {
-            TyInt(int_ty) => {
-                Some(match int_ty {
-                    IntTy::I8 => (FullInt::S(i8::min_value() as i128), FullInt::S(i8::max_value() as i128)),
-                    IntTy::I16 => (FullInt::S(i16::min_value() as i128), FullInt::S(i16::max_value() as i128)),
-                    IntTy::I32 => (FullInt::S(i32::min_value() as i128), FullInt::S(i32::max_value() as i128)),
-                    IntTy::I64 => (FullInt::S(i64::min_value() as i128), FullInt::S(i64::max_value() as i128)),
-                    IntTy::I128 => (FullInt::S(i128::min_value() as i128), FullInt::S(i128::max_value() as i128)),
-                    IntTy::Is => (FullInt::S(isize::min_value() as i128), FullInt::S(isize::max_value() as i128)),
-                })
-            },
-            TyUint(uint_ty) => {
-                Some(match uint_ty {
-                    UintTy::U8 => (FullInt::U(u8::min_value() as u128), FullInt::U(u8::max_value() as u128)),
-                    UintTy::U16 => (FullInt::U(u16::min_value() as u128), FullInt::U(u16::max_value() as u128)),
-                    UintTy::U32 => (FullInt::U(u32::min_value() as u128), FullInt::U(u32::max_value() as u128)),
-                    UintTy::U64 => (FullInt::U(u64::min_value() as u128), FullInt::U(u64::max_value() as u128)),
-                    UintTy::U128 => (FullInt::U(u128::min_value() as u128), FullInt::U(u128::max_value() as u128)),
-                    UintTy::Us => (FullInt::U(usize::min_value() as u128), FullInt::U(usize::max_value() as u128)),
-                })
-            },
-            _ => None,
-        }
+        interval(cx, cast_exp).map(|range| (range.min, range.max))
     } else {
         None
     }
@@ -1118,10 +1075,7 @@ fn node_as_const_fullint(cx: &LateContext, expr: &Expr) -> Option<FullInt> {
     match ConstContext::with_tables(cx.tcx, cx.tables).eval(expr) {
         Ok(val) => {
             if let Integral(const_int) = val {
-                match const_int.int_type() {
-                    IntType::SignedInt(_) => #[allow(cast_possible_wrap)] Some(FullInt::S(const_int.to_u128_unchecked() as i128)),
-                    IntType::UnsignedInt(_) => Some(FullInt::U(const_int.to_u128_unchecked())),
-                }
+                Some(const_int.into())
             } else {
                 None
             }
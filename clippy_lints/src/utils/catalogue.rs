@@ -0,0 +1,4837 @@
+//! The documentation of Clippy's lints, for `cargo clippy --list-lints` and
+//! `cargo clippy --explain`.
+//!
+//! The lists are generated by `util/update_lints.py`, from the doc comments of the lints and the
+//! lint groups registered in `register_plugins`.
+
+#![deny(missing_docs_in_private_items)]
+
+/// A lint, as documented in its module.
+#[derive(Debug)]
+pub struct LintInfo {
+    /// The name of the lint, in lowercase.
+    pub name: &'static str,
+    /// The module the lint is defined in.
+    pub module: &'static str,
+    /// The default level of the lint, or `deprecated`.
+    pub level: &'static str,
+    /// The lint group, empty for deprecated lints.
+    pub group: &'static str,
+    /// The one-line description of the lint.
+    pub desc: &'static str,
+    /// The doc comment of the lint, in Markdown.
+    pub docs: &'static str,
+}
+
+/// A lint group, like `clippy_pedantic` or a preset like `clippy_no_panic`.
+#[derive(Debug)]
+pub struct GroupInfo {
+    /// The name of the group.
+    pub name: &'static str,
+    /// The description of the group.
+    pub desc: &'static str,
+    /// The names of the lints of the group, sorted.
+    pub lints: &'static [&'static str],
+}
+
+/// Find a lint by name. Dashes are accepted in place of underscores.
+pub fn find(name: &str) -> Option<&'static LintInfo> {
+    let name = name.replace('-', "_").to_lowercase();
+    LINTS.iter().find(|lint| lint.name == name)
+}
+
+/// Find a lint group by name. Dashes are accepted in place of underscores.
+pub fn find_group(name: &str) -> Option<&'static GroupInfo> {
+    let name = name.replace('-', "_").to_lowercase();
+    GROUPS.iter().find(|group| group.name == name)
+}
+
+/// Every lint of Clippy, sorted by name.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+pub static LINTS: &[LintInfo] = &[
+    // begin lint list, do not remove this comment, it’s used in `update_lints`
+    LintInfo {
+        name: "absurd_extreme_comparisons",
+        module: "types",
+        level: "warn",
+        group: "clippy",
+        desc: "a comparison with a maximum or minimum value that is always true or false",
+        docs: r#"**What it does:** Checks for comparisons where one side of the relation is
+either the minimum or maximum value for its type, or of the values the other
+side can have, like `255` for `x & 0xff`, and warns if it involves a case
+that is always true or always false. Only integer and boolean types are
+checked.
+
+**Why is this bad?** An expression like `min <= x` may misleadingly imply
+that is is possible for `x` to be less than the minimum. Expressions like
+`max < x` are probably mistakes.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+vec.len() <= 0
+100 > std::i32::MAX
+```"#,
+    },
+    LintInfo {
+        name: "allow_attributes_without_reason",
+        module: "attrs",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "`#[allow(..)]` of Clippy lints without a justification",
+        docs: r#"**What it does:** Checks for `#[allow(..)]` attributes of Clippy lints that do not say why the
+lint is allowed, with a `//` comment at the end of the attribute's line or on the lines just
+above it, or with a `reason = "..."` item.
+
+**Why is this bad?** An unexplained `allow` cannot be audited, and the next reader does not
+know whether the lint is a false positive or a debt.
+
+**Known problems:** Any comment is taken as a justification. rustc does not accept
+`reason = "..."` in lint attributes yet, so a comment is the only way for now.
+
+**Example:**
+```rust
+#[allow(needless_range_loop)]
+fn foo() { .. }
+```"#,
+    },
+    LintInfo {
+        name: "almost_swapped",
+        module: "swap",
+        level: "warn",
+        group: "clippy",
+        desc: "`foo = bar; bar = foo` sequence",
+        docs: r#"**What it does:** Checks for `foo = bar; bar = foo` sequences.
+
+**Why is this bad?** This looks like a failed attempt to swap.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+a = b;
+b = a;
+```"#,
+    },
+    LintInfo {
+        name: "approx_constant",
+        module: "approx_const",
+        level: "warn",
+        group: "clippy",
+        desc: "the approximate of a known float constant (in `std::fXX::consts`)",
+        docs: r#"**What it does:** Checks for floating point literals that approximate
+constants which are defined in
+[`std::f32::consts`](https://doc.rust-lang.org/stable/std/f32/consts/#constants)
+or
+[`std::f64::consts`](https://doc.rust-lang.org/stable/std/f64/consts/#constants),
+respectively, suggesting to use the predefined constant.
+
+**Why is this bad?** Usually, the definition in the standard library is more
+precise than what people come up with. If you find that your definition is
+actually more precise, please [file a Rust
+issue](https://github.com/rust-lang/rust/issues).
+
+**Known problems:** If you happen to have a value that is within 1/8192 of a
+known constant, but is not *and should not* be the same, this lint will
+report your value anyway. We have not yet noticed any false positives in
+code we tested clippy with (this includes servo), but YMMV.
+
+**Example:**
+```rust
+let x = 3.14;
+```"#,
+    },
+    LintInfo {
+        name: "assign_op_pattern",
+        module: "assign_ops",
+        level: "warn",
+        group: "clippy",
+        desc: "assigning the result of an operation on a variable to that same variable",
+        docs: r#"**What it does:** Checks for `a = a op b` or `a = b commutative_op a` patterns.
+
+**Why is this bad?** These can be written as the shorter `a op= b`.
+
+**Known problems:** While forbidden by the spec, `OpAssign` traits may have
+implementations that differ from the regular `Op` impl.
+
+**Example:**
+```rust
+let mut a = 5;
+...
+a = a + b;
+```"#,
+    },
+    LintInfo {
+        name: "assign_ops",
+        module: "assign_ops",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "any compound assignment operation",
+        docs: r#"**What it does:** Checks for compound assignment operations (`+=` and similar).
+
+**Why is this bad?** Projects with many developers from languages without
+those operations may find them unreadable and not worth their weight.
+
+**Known problems:** Types implementing `OpAssign` don't necessarily implement `Op`.
+
+**Example:**
+```rust
+a += 1;
+```"#,
+    },
+    LintInfo {
+        name: "bad_bit_mask",
+        module: "bit_mask",
+        level: "warn",
+        group: "clippy",
+        desc: "expressions of the form `_ & mask == select` that will only ever return `true` or `false`",
+        docs: r#"**What it does:** Checks for incompatible bit masks in comparisons.
+
+The formula for detecting if an expression of the type `_ <bit_op> m
+<cmp_op> c` (where `<bit_op>` is one of {`&`, `|`} and `<cmp_op>` is one of
+{`!=`, `>=`, `>`, `!=`, `>=`, `>`}) can be determined from the following
+table:
+
+|Comparison  |Bit Op|Example     |is always|Formula               |
+|------------|------|------------|---------|----------------------|
+|`==` or `!=`| `&`  |`x & 2 == 3`|`false`  |`c & m != c`          |
+|`<`  or `>=`| `&`  |`x & 2 < 3` |`true`   |`m < c`               |
+|`>`  or `<=`| `&`  |`x & 1 > 1` |`false`  |`m <= c`              |
+|`==` or `!=`| `|`  |`x | 1 == 0`|`false`  |`c | m != c`          |
+|`<`  or `>=`| `|`  |`x | 1 < 1` |`false`  |`m >= c`              |
+|`<=` or `>` | `|`  |`x | 1 > 0` |`true`   |`m > c`               |
+
+**Why is this bad?** If the bits that the comparison cares about are always
+set to zero or one by the bit mask, the comparison is constant `true` or
+`false` (depending on mask, compared value, and operators).
+
+So the code is actively misleading, and the only reason someone would write
+this intentionally is to win an underhanded Rust contest or create a
+test-case for this lint.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+if (x & 1 == 2) { … }
+```"#,
+    },
+    LintInfo {
+        name: "blacklisted_name",
+        module: "blacklisted_name",
+        level: "warn",
+        group: "clippy",
+        desc: "usage of a blacklisted/placeholder name",
+        docs: r#"**What it does:** Checks for usage of blacklisted names for variables, such
+as `foo`.
+
+**Why is this bad?** These names are usually placeholder names and should be
+avoided.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let foo = 3.14;
+```"#,
+    },
+    LintInfo {
+        name: "block_in_if_condition_expr",
+        module: "block_in_if_condition",
+        level: "warn",
+        group: "clippy",
+        desc: "braces that can be eliminated in conditions, e.g. `if { true } ...`",
+        docs: r#"**What it does:** Checks for `if` conditions that use blocks to contain an
+expression.
+
+**Why is this bad?** It isn't really Rust style, same as using parentheses
+to contain expressions.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+if { true } ..
+```"#,
+    },
+    LintInfo {
+        name: "block_in_if_condition_stmt",
+        module: "block_in_if_condition",
+        level: "warn",
+        group: "clippy",
+        desc: "complex blocks in conditions, e.g. `if { let x = true; x } ...`",
+        docs: r#"**What it does:** Checks for `if` conditions that use blocks containing
+statements, or conditions that use closures with blocks.
+
+**Why is this bad?** Using blocks in the condition makes it hard to read.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+if { let x = somefunc(); x } ..
+// or
+if somefunc(|x| { x == 47 }) ..
+```"#,
+    },
+    LintInfo {
+        name: "bool_comparison",
+        module: "needless_bool",
+        level: "warn",
+        group: "clippy",
+        desc: "comparing a variable to a boolean, e.g. `if x == true`",
+        docs: r#"**What it does:** Checks for expressions of the form `x == true` (or vice
+versa) and suggest using the variable directly.
+
+**Why is this bad?** Unnecessary code.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+if x == true { }  // could be `if x { }`
+```"#,
+    },
+    LintInfo {
+        name: "box_vec",
+        module: "types",
+        level: "warn",
+        group: "clippy",
+        desc: "usage of `Box<Vec<T>>`, vector elements are already on the heap",
+        docs: r#"**What it does:** Checks for use of `Box<Vec<_>>` anywhere in the code.
+
+**Why is this bad?** `Vec` already keeps its contents in a separate area on
+the heap. So if you `Box` it, you just add another level of indirection
+without any benefit whatsoever.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+struct X {
+    values: Box<Vec<Foo>>,
+}
+```"#,
+    },
+    LintInfo {
+        name: "boxed_local",
+        module: "escape",
+        level: "warn",
+        group: "clippy",
+        desc: "using `Box<T>` where unnecessary",
+        docs: r#"**What it does:** Checks for usage of `Box<T>` where an unboxed `T` would
+work fine.
+
+**Why is this bad?** This is an unnecessary allocation, and bad for
+performance. It is only necessary to allocate if you wish to move the box
+into something.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn main() {
+    let x = Box::new(1);
+    foo(*x);
+    println!("{}", *x);
+}
+```"#,
+    },
+    LintInfo {
+        name: "builtin_type_shadow",
+        module: "misc_early",
+        level: "warn",
+        group: "clippy",
+        desc: "shadowing a builtin type",
+        docs: r#"**What it does:** Warns if a generic shadows a built-in type.
+
+**Why is this bad?** This gives surprising type errors.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+impl<u32> Foo<u32> {
+    fn impl_func(&self) -> u32 {
+        42
+    }
+}
+```"#,
+    },
+    LintInfo {
+        name: "cast_possible_truncation",
+        module: "types",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "casts that may cause truncation of the value, e.g. `x as u8` where `x: u32`, or `x as i32` where `x: f32`",
+        docs: r#"**What it does:** Checks for on casts between numerical types that may
+truncate large values. This is expected behavior, so the cast is `Allow` by
+default.
+
+**Why is this bad?** In some problem domains, it is good practice to avoid
+truncation. This lint can be activated to help assess where additional
+checks could be beneficial.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn as_u8(x: u64) -> u8 { x as u8 }
+```"#,
+    },
+    LintInfo {
+        name: "cast_possible_wrap",
+        module: "types",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "casts that may cause wrapping around the value, e.g. `x as i32` where `x: u32` and `x > i32::MAX`",
+        docs: r#"**What it does:** Checks for casts from an unsigned type to a signed type of
+the same size. Performing such a cast is a 'no-op' for the compiler,
+i.e. nothing is changed at the bit level, and the binary representation of
+the value is reinterpreted. This can cause wrapping if the value is too big
+for the target signed type. However, the cast works as defined, so this lint
+is `Allow` by default.
+
+**Why is this bad?** While such a cast is not bad in itself, the results can
+be surprising when this is not the intended behavior, as demonstrated by the
+example below.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+u32::MAX as i32  // will yield a value of `-1`
+```"#,
+    },
+    LintInfo {
+        name: "cast_precision_loss",
+        module: "types",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "casts that cause loss of precision, e.g. `x as f32` where `x: u64`",
+        docs: r#"**What it does:** Checks for casts from any numerical to a float type where
+the receiving type cannot store all values from the original type without
+rounding errors. This possible rounding is to be expected, so this lint is
+`Allow` by default.
+
+Basically, this warns on casting any integer with 32 or more bits to `f32`
+or any 64-bit integer to `f64`.
+
+**Why is this bad?** It's not bad at all. But in some applications it can be
+helpful to know where precision loss can take place. This lint can help find
+those places in the code.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = u64::MAX; x as f64
+```"#,
+    },
+    LintInfo {
+        name: "cast_sign_loss",
+        module: "types",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "casts from signed types to unsigned types, e.g. `x as u32` where `x: i32`",
+        docs: r#"**What it does:** Checks for casts from a signed to an unsigned numerical
+type. In this case, negative values wrap around to large positive values,
+which can be quite surprising in practice. However, as the cast works as
+defined, this lint is `Allow` by default.
+
+**Why is this bad?** Possibly surprising results. You can activate this lint
+as a one-time check to see where numerical wrapping can arise.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let y: i8 = -1;
+y as u128  // will return 18446744073709551615
+```"#,
+    },
+    LintInfo {
+        name: "char_lit_as_u8",
+        module: "types",
+        level: "warn",
+        group: "clippy",
+        desc: "casting a character literal to u8",
+        docs: r#"**What it does:** Checks for expressions where a character literal is cast
+to `u8` and suggests using a byte literal instead.
+
+**Why is this bad?** In general, casting values to smaller types is
+error-prone and should be avoided where possible. In the particular case of
+converting a character literal to u8, it is easy to avoid by just using a
+byte literal instead. As an added bonus, `b'a'` is even slightly shorter
+than `'a' as u8`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+'x' as u8
+```"#,
+    },
+    LintInfo {
+        name: "chars_next_cmp",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `.chars().next()` to check if a string starts with a char",
+        docs: r#"**What it does:** Checks for usage of `.chars().next()` on a `str` to check
+if it starts with a given char.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.starts_with(_)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+name.chars().next() == Some('_')
+```"#,
+    },
+    LintInfo {
+        name: "clone_double_ref",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `clone` on `&&T`",
+        docs: r#"**What it does:** Checks for usage of `.clone()` on an `&&T`.
+
+**Why is this bad?** Cloning an `&&T` copies the inner `&T`, instead of
+cloning the underlying `T`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn main() {
+   let x = vec![1];
+   let y = &&x;
+   let z = y.clone();
+   println!("{:p} {:p}",*y, z); // prints out the same pointer
+}
+```"#,
+    },
+    LintInfo {
+        name: "clone_on_copy",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `clone` on a `Copy` type",
+        docs: r#"**What it does:** Checks for usage of `.clone()` on a `Copy` type.
+
+**Why is this bad?** The only reason `Copy` types implement `Clone` is for
+generics, not for using the `clone` method on a concrete type.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+42u64.clone()
+```"#,
+    },
+    LintInfo {
+        name: "cmp_nan",
+        module: "misc",
+        level: "deny",
+        group: "clippy",
+        desc: "comparisons to NAN, which will always return false, probably not intended",
+        docs: r#"**What it does:** Checks for comparisons to NaN.
+
+**Why is this bad?** NaN does not compare meaningfully to anything – not
+even itself – so those comparisons are simply wrong.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x == NAN
+```"#,
+    },
+    LintInfo {
+        name: "cmp_null",
+        module: "ptr",
+        level: "warn",
+        group: "clippy",
+        desc: "comparing a pointer to a null pointer, suggesting to use `.is_null()` instead.",
+        docs: r#"**What it does:** This lint checks for equality comparisons with `ptr::null`
+
+**Why is this bad?** It's easier and more readable to use the inherent `.is_null()`
+method instead
+
+**Known problems:** None.
+
+**Example:**
+```rust
+if x == ptr::null { .. }
+```"#,
+    },
+    LintInfo {
+        name: "cmp_owned",
+        module: "misc",
+        level: "warn",
+        group: "clippy",
+        desc: "creating owned instances for comparing with others, e.g. `x == \"foo\".to_string()`",
+        docs: r#"**What it does:** Checks for conversions to owned values just for the sake
+of a comparison.
+
+**Why is this bad?** The comparison can operate on a reference, so creating
+an owned value effectively throws it away directly afterwards, which is
+needlessly consuming code and heap space.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x.to_owned() == y
+```"#,
+    },
+    LintInfo {
+        name: "collapsible_if",
+        module: "collapsible_if",
+        level: "warn",
+        group: "clippy",
+        desc: "`if`s that can be collapsed (e.g. `if x { if y { ... } }` and `else { if x { ... } }`)",
+        docs: r#"**What it does:** Checks for nested `if` statements which can be collapsed
+by `&&`-combining their conditions and for `else { if ... }` expressions that
+can be collapsed to `else if ...`.
+
+**Why is this bad?** Each `if`-statement adds one level of nesting, which
+makes code look more complex than it really is.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+if x {
+    if y {
+        …
+    }
+}
+
+// or
+
+if x {
+    …
+} else {
+    if y {
+        …
+    }
+}
+```
+
+Should be written:
+
+```rust.ignore
+if x && y {
+    …
+}
+
+// or
+
+if x {
+    …
+} else if y {
+    …
+}
+```"#,
+    },
+    LintInfo {
+        name: "crosspointer_transmute",
+        module: "transmute",
+        level: "warn",
+        group: "clippy",
+        desc: "transmutes that have to or from types that are a pointer to the other",
+        docs: r#"**What it does:** Checks for transmutes between a type `T` and `*T`.
+
+**Why is this bad?** It's easy to mistakenly transmute between a type and a
+pointer to that type.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+core::intrinsics::transmute(t)` // where the result type is the same as `*t` or `&t`'s
+```"#,
+    },
+    LintInfo {
+        name: "cyclomatic_complexity",
+        module: "cyclomatic_complexity",
+        level: "warn",
+        group: "clippy",
+        desc: "functions that should be split up into multiple functions",
+        docs: r#"**What it does:** Checks for methods with high cyclomatic complexity.
+
+**Why is this bad?** Methods of high cyclomatic complexity tend to be badly
+readable. Also LLVM will usually optimize small methods better.
+
+**Known problems:** Sometimes it's hard to find a way to reduce the complexity.
+
+**Example:** No. You'll see it when you get the warning."#,
+    },
+    LintInfo {
+        name: "deprecated_semver",
+        module: "attrs",
+        level: "warn",
+        group: "clippy",
+        desc: "use of `#[deprecated(since = \"x\")]` where x is not semver",
+        docs: r#"**What it does:** Checks for `#[deprecated]` annotations with a `since`
+field that is not a valid semantic version.
+
+**Why is this bad?** For checking the version of the deprecation, it must be
+a valid semver. Failing that, the contained information is useless.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+#[deprecated(since = "forever")]
+fn something_else(..) { ... }
+```"#,
+    },
+    LintInfo {
+        name: "deref_addrof",
+        module: "reference",
+        level: "warn",
+        group: "clippy",
+        desc: "use of `*&` or `*&mut` in an expression",
+        docs: r#"**What it does:** Checks for usage of `*&` and `*&mut` in expressions.
+
+**Why is this bad?** Immediately dereferencing a reference is no-op and
+makes the code less clear.
+
+**Known problems:** Multiple dereference/addrof pairs are not handled so
+the suggested fix for `x = **&&y` is `x = *&y`, which is still incorrect.
+
+**Example:**
+```rust
+let a = f(*&mut b);
+let c = *&d;
+```"#,
+    },
+    LintInfo {
+        name: "derive_hash_xor_eq",
+        module: "derive",
+        level: "warn",
+        group: "clippy",
+        desc: "deriving `Hash` but implementing `PartialEq` explicitly",
+        docs: r#"**What it does:** Checks for deriving `Hash` but implementing `PartialEq`
+explicitly.
+
+**Why is this bad?** The implementation of these traits must agree (for
+example for use with `HashMap`) so it’s probably a bad idea to use a
+default-generated `Hash` implementation with an explicitly defined
+`PartialEq`. In particular, the following must hold for any type:
+
+```rust
+k1 == k2 ⇒ hash(k1) == hash(k2)
+```
+
+**Known problems:** None.
+
+**Example:**
+```rust
+#[derive(Hash)]
+struct Foo;
+
+impl PartialEq for Foo {
+    ...
+}
+```"#,
+    },
+    LintInfo {
+        name: "diverging_sub_expression",
+        module: "eval_order_dependence",
+        level: "warn",
+        group: "clippy",
+        desc: "whether an expression contains a diverging sub expression",
+        docs: r#"**What it does:** Checks for diverging calls that are not match arms or statements.
+
+**Why is this bad?** It is often confusing to read. In addition, the
+sub-expression evaluation order for Rust is not well documented.
+
+**Known problems:** Someone might want to use `some_bool || panic!()` as a shorthand.
+
+**Example:**
+```rust
+let a = b() || panic!() || c();
+// `c()` is dead, `panic!()` is only called if `b()` returns `false`
+let x = (a, b, c, panic!());
+// can simply be replaced by `panic!()`
+```"#,
+    },
+    LintInfo {
+        name: "doc_markdown",
+        module: "doc",
+        level: "warn",
+        group: "clippy",
+        desc: "presence of `_`, `::` or camel-case outside backticks in documentation",
+        docs: r#"**What it does:** Checks for the presence of `_`, `::` or camel-case words
+outside ticks in documentation.
+
+**Why is this bad?** *Rustdoc* supports markdown formatting, `_`, `::` and
+camel-case probably indicates some code which should be included between
+ticks. `_` can also be used for empasis in markdown, this lint tries to
+consider that.
+
+**Known problems:** Lots of bad docs won’t be fixed, what the lint checks
+for is limited, and there are still false positives.
+
+**Examples:**
+```rust
+/// Do something with the foo_bar parameter. See also that::other::module::foo.
+// ^ `foo_bar` and `that::other::module::foo` should be ticked.
+fn doit(foo_bar) { .. }
+```"#,
+    },
+    LintInfo {
+        name: "double_neg",
+        module: "misc_early",
+        level: "warn",
+        group: "clippy",
+        desc: "`--x`, which is a double negation of `x` and not a pre-decrement as in C/C++",
+        docs: r#"**What it does:** Detects expressions of the form `--x`.
+
+**Why is this bad?** It can mislead C/C++ programmers to think `x` was
+decremented.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+--x;
+```"#,
+    },
+    LintInfo {
+        name: "double_parens",
+        module: "double_parens",
+        level: "warn",
+        group: "clippy",
+        desc: "Warn on unnecessary double parentheses",
+        docs: r#"**What it does:** Checks for unnecessary double parentheses.
+
+**Why is this bad?** This makes code harder to read and might indicate a
+mistake.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+((0))
+foo((0))
+((1, 2))
+```"#,
+    },
+    LintInfo {
+        name: "drop_copy",
+        module: "drop_forget_ref",
+        level: "warn",
+        group: "clippy",
+        desc: "calls to `std::mem::drop` with a value that implements Copy",
+        docs: r#"**What it does:** Checks for calls to `std::mem::drop` with a value
+that derives the Copy trait
+
+**Why is this bad?** Calling `std::mem::drop` [does nothing for types that
+implement Copy](https://doc.rust-lang.org/std/mem/fn.drop.html), since the
+value will be copied and moved into the function on invocation.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x:i32 = 42;   // i32 implements Copy
+std::mem::drop(x) // A copy of x is passed to the function, leaving the original unaffected
+```"#,
+    },
+    LintInfo {
+        name: "drop_ref",
+        module: "drop_forget_ref",
+        level: "warn",
+        group: "clippy",
+        desc: "calls to `std::mem::drop` with a reference instead of an owned value",
+        docs: r#"**What it does:** Checks for calls to `std::mem::drop` with a reference
+instead of an owned value.
+
+**Why is this bad?** Calling `drop` on a reference will only drop the
+reference itself, which is a no-op. It will not call the `drop` method (from
+the `Drop` trait implementation) on the underlying referenced value, which
+is likely what was intended.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let mut lock_guard = mutex.lock();
+std::mem::drop(&lock_guard) // Should have been drop(lock_guard), mutex still locked
+operation_that_requires_mutex_to_be_unlocked();
+```"#,
+    },
+    LintInfo {
+        name: "duplicate_underscore_argument",
+        module: "misc_early",
+        level: "warn",
+        group: "clippy",
+        desc: "function arguments having names which only differ by an underscore",
+        docs: r#"**What it does:** Checks for function arguments having the similar names
+differing by an underscore.
+
+**Why is this bad?** It affects code readability.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn foo(a: i32, _a: i32) {}
+```"#,
+    },
+    LintInfo {
+        name: "empty_enum",
+        module: "empty_enum",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "enum with no variants",
+        docs: r#"**What it does:** Checks for `enum`s with no variants.
+
+**Why is this bad?** Enum's with no variants should be replaced with `!`, the uninhabited type,
+or a wrapper around it.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+enum Test {}
+```"#,
+    },
+    LintInfo {
+        name: "empty_loop",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "empty `loop {}`, which should block or sleep",
+        docs: r#"**What it does:** Checks for empty `loop` expressions.
+
+**Why is this bad?** Those busy loops burn CPU cycles without doing
+anything. Think of the environment and either block on something or at least
+make the thread sleep for some microseconds.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+loop {}
+```"#,
+    },
+    LintInfo {
+        name: "enum_clike_unportable_variant",
+        module: "enum_clike",
+        level: "warn",
+        group: "clippy",
+        desc: "C-like enums that are `repr(isize/usize)` and have values that don't fit into an `i32`",
+        docs: r#"**What it does:** Checks for C-like enumerations that are
+`repr(isize/usize)` and have values that don't fit into an `i32`.
+
+**Why is this bad?** This will truncate the variant value on 32 bit
+architectures, but works fine on 64 bit.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+#[repr(usize)]
+enum NonPortable {
+    X = 0x1_0000_0000,
+    Y = 0
+}
+```"#,
+    },
+    LintInfo {
+        name: "enum_glob_use",
+        module: "enum_glob_use",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "use items that import all variants of an enum",
+        docs: r#"**What it does:** Checks for `use Enum::*`.
+
+**Why is this bad?** It is usually better style to use the prefixed name of
+an enumeration variant, rather than importing variants.
+
+**Known problems:** Old-style enumerations that prefix the variants are
+still around.
+
+**Example:**
+```rust
+use std::cmp::Ordering::*;
+```"#,
+    },
+    LintInfo {
+        name: "enum_variant_names",
+        module: "enum_variants",
+        level: "warn",
+        group: "clippy",
+        desc: "enums where all variants share a prefix/postfix",
+        docs: r#"**What it does:** Detects enumeration variants that are prefixed or suffixed
+by the same characters.
+
+**Why is this bad?** Enumeration variant names should specify their variant,
+not repeat the enumeration name.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+enum Cake {
+    BlackForestCake,
+    HummingbirdCake,
+}
+```"#,
+    },
+    LintInfo {
+        name: "eq_op",
+        module: "eq_op",
+        level: "warn",
+        group: "clippy",
+        desc: "equal operands on both sides of a comparison or bitwise combination (e.g. `x == x`)",
+        docs: r#"**What it does:** Checks for equal operands to comparison, logical and
+bitwise, difference and division binary operators (`==`, `>`, etc., `&&`,
+`||`, `&`, `|`, `^`, `-` and `/`).
+
+**Why is this bad?** This is usually just a typo or a copy and paste error.
+
+**Known problems:** False negatives: We had some false positives regarding
+calls (notably [racer](https://github.com/phildawes/racer) had one instance
+of `x.pop() && x.pop()`), so we removed matching any function or method
+calls. We may introduce a whitelist of known pure functions in the future.
+
+**Example:**
+```rust
+x + 1 == x + 1
+```"#,
+    },
+    LintInfo {
+        name: "eval_order_dependence",
+        module: "eval_order_dependence",
+        level: "warn",
+        group: "clippy",
+        desc: "whether a variable read occurs before a write depends on sub-expression evaluation order",
+        docs: r#"**What it does:** Checks for a read and a write to the same variable where
+whether the read occurs before or after the write depends on the evaluation
+order of sub-expressions.
+
+**Why is this bad?** It is often confusing to read. In addition, the
+sub-expression evaluation order for Rust is not well documented.
+
+**Known problems:** Code which intentionally depends on the evaluation
+order, or which is correct for any evaluation order.
+
+**Example:**
+```rust
+let mut x = 0;
+let a = {x = 1; 1} + x;
+// Unclear whether a is 1 or 2.
+```"#,
+    },
+    LintInfo {
+        name: "exit",
+        module: "exit",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "usage of `std::process::exit`",
+        docs: r#"**What it does:** Checks for calls to `std::process::exit`.
+
+**Why is this bad?** `process::exit` ends the whole process without running
+the destructors, taking the decision away from the caller. A library should
+return an error instead.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+std::process::exit(1)
+```"#,
+    },
+    LintInfo {
+        name: "expect_used",
+        module: "methods",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "using `Option.expect()` or `Result.expect()`, which will panic on `None` or `Err`",
+        docs: r#"**What it does:** Checks for `.expect()` calls on `Option`s and `Result`s.
+
+**Why is this bad?** Like `unwrap`, `expect` will let the thread panic on
+`None` or `Err` values. Code that must report all its errors should
+propagate them instead.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x.expect("more helpful message")
+```"#,
+    },
+    LintInfo {
+        name: "expl_impl_clone_on_copy",
+        module: "derive",
+        level: "warn",
+        group: "clippy",
+        desc: "implementing `Clone` explicitly on `Copy` types",
+        docs: r#"**What it does:** Checks for explicit `Clone` implementations for `Copy`
+types.
+
+**Why is this bad?** To avoid surprising behaviour, these traits should
+agree and the behaviour of `Copy` cannot be overridden. In almost all
+situations a `Copy` type should have a `Clone` implementation that does
+nothing more than copy the object, which is what `#[derive(Copy, Clone)]`
+gets you.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+#[derive(Copy)]
+struct Foo;
+
+impl Clone for Foo {
+    ..
+}
+```"#,
+    },
+    LintInfo {
+        name: "explicit_counter_loop",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "for-looping with an explicit counter when `_.enumerate()` would do",
+        docs: r#"**What it does:** Checks `for` loops over slices with an explicit counter
+and suggests the use of `.enumerate()`.
+
+**Why is it bad?** Not only is the version using `.enumerate()` more
+readable, the compiler is able to remove bounds checks which can lead to
+faster code in some instances.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+for i in 0..v.len() { foo(v[i]);
+for i in 0..v.len() { bar(i, v[i]); }
+```"#,
+    },
+    LintInfo {
+        name: "explicit_into_iter_loop",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "for-looping over `_.into_iter()` when `_` would do",
+        docs: r#"**What it does:** Checks for loops on `y.into_iter()` where `y` will do, and
+suggests the latter.
+
+**Why is this bad?** Readability.
+
+**Known problems:** None
+
+**Example:**
+```rust
+// with `y` a `Vec` or slice:
+for x in y.into_iter() { .. }
+```"#,
+    },
+    LintInfo {
+        name: "explicit_iter_loop",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "for-looping over `_.iter()` or `_.iter_mut()` when `&_` or `&mut _` would do",
+        docs: r#"**What it does:** Checks for loops on `x.iter()` where `&x` will do, and
+suggests the latter.
+
+**Why is this bad?** Readability.
+
+**Known problems:** False negatives. We currently only warn on some known
+types.
+
+**Example:**
+```rust
+// with `y` a `Vec` or slice:
+for x in y.iter() { .. }
+```"#,
+    },
+    LintInfo {
+        name: "extend_from_slice",
+        module: "deprecated_lints",
+        level: "deprecated",
+        group: "",
+        desc: "`.extend_from_slice(_)` is a faster way to extend a Vec by a slice",
+        docs: r#"**What it does:** Nothing. This lint has been deprecated.
+
+**Deprecation reason:** This used to check for `Vec::extend`, which was slower than
+`Vec::extend_from_slice`. Thanks to specialization, this is no longer true."#,
+    },
+    LintInfo {
+        name: "filter_map",
+        module: "methods",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using combinations of `filter`, `map`, `filter_map` and `flat_map` which can usually be written as a single method call",
+        docs: r#"**What it does:** Checks for usage of `_.filter(_).map(_)`,
+`_.filter(_).flat_map(_)`, `_.filter_map(_).flat_map(_)` and similar.
+
+**Why is this bad?** Readability, this can be written more concisely as a
+single method call.
+
+**Known problems:** Often requires a condition + Option/Iterator creation
+inside the closure.
+
+**Example:**
+```rust
+iter.filter(|x| x == 0).map(|x| x * 2)
+```"#,
+    },
+    LintInfo {
+        name: "filter_next",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `filter(p).next()`, which is more succinctly expressed as `.find(p)`",
+        docs: r#"**What it does:** Checks for usage of `_.filter(_).next()`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.find(_)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+iter.filter(|x| x == 0).next()
+```"#,
+    },
+    LintInfo {
+        name: "float_arithmetic",
+        module: "arithmetic",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "any floating-point arithmetic statement",
+        docs: r#"**What it does:** Checks for float arithmetic.
+
+**Why is this bad?** For some embedded systems or kernel development, it
+can be useful to rule out floating-point numbers.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+a + 1.0
+```"#,
+    },
+    LintInfo {
+        name: "float_cmp",
+        module: "misc",
+        level: "warn",
+        group: "clippy",
+        desc: "using `==` or `!=` on float values instead of comparing difference with an epsilon",
+        docs: r#"**What it does:** Checks for (in-)equality comparisons on floating-point
+values (apart from zero), except in functions called `*eq*` (which probably
+implement equality for a type involving floats).
+
+**Why is this bad?** Floating point calculations are usually imprecise, so
+asking if two values are *exactly* equal is asking for trouble. For a good
+guide on what to do, see [the floating point
+guide](http://www.floating-point-gui.de/errors/comparison).
+
+**Known problems:** None.
+
+**Example:**
+```rust
+y == 1.23f64
+y != x  // where both are floats
+```"#,
+    },
+    LintInfo {
+        name: "for_kv_map",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "looping on a map using `iter` when `keys` or `values` would do",
+        docs: r#"**What it does:** Checks for iterating a map (`HashMap` or `BTreeMap`) and
+ignoring either the keys or values.
+
+**Why is this bad?** Readability. There are `keys` and `values` methods that
+can be used to express that don't need the values or keys.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+for (k, _) in &map { .. }
+```
+
+could be replaced by
+
+```rust
+for k in map.keys() { .. }
+```"#,
+    },
+    LintInfo {
+        name: "for_loop_over_option",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "for-looping over an `Option`, which is more clearly expressed as an `if let`",
+        docs: r#"**What it does:** Checks for `for` loops over `Option` values.
+
+**Why is this bad?** Readability. This is more clearly expressed as an `if let`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+for x in option { .. }
+```
+
+This should be
+```rust
+if let Some(x) = option { .. }
+```"#,
+    },
+    LintInfo {
+        name: "for_loop_over_result",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "for-looping over a `Result`, which is more clearly expressed as an `if let`",
+        docs: r#"**What it does:** Checks for `for` loops over `Result` values.
+
+**Why is this bad?** Readability. This is more clearly expressed as an `if let`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+for x in result { .. }
+```
+
+This should be
+```rust
+if let Ok(x) = result { .. }
+```"#,
+    },
+    LintInfo {
+        name: "forget_copy",
+        module: "drop_forget_ref",
+        level: "warn",
+        group: "clippy",
+        desc: "calls to `std::mem::forget` with a value that implements Copy",
+        docs: r#"**What it does:** Checks for calls to `std::mem::forget` with a value that
+derives the Copy trait
+
+**Why is this bad?** Calling `std::mem::forget` [does nothing for types that
+implement Copy](https://doc.rust-lang.org/std/mem/fn.drop.html) since the
+value will be copied and moved into the function on invocation.
+
+An alternative, but also valid, explanation is that Copy types do not implement
+the Drop trait, which means they have no destructors. Without a destructor, there
+is nothing for `std::mem::forget` to ignore.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x:i32 = 42;     // i32 implements Copy
+std::mem::forget(x) // A copy of x is passed to the function, leaving the original unaffected
+```"#,
+    },
+    LintInfo {
+        name: "forget_ref",
+        module: "drop_forget_ref",
+        level: "warn",
+        group: "clippy",
+        desc: "calls to `std::mem::forget` with a reference instead of an owned value",
+        docs: r#"**What it does:** Checks for calls to `std::mem::forget` with a reference
+instead of an owned value.
+
+**Why is this bad?** Calling `forget` on a reference will only forget the
+reference itself, which is a no-op. It will not forget the underlying referenced
+value, which is likely what was intended.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = Box::new(1);
+std::mem::forget(&x) // Should have been forget(x), x will still be dropped
+```"#,
+    },
+    LintInfo {
+        name: "get_unwrap",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `.get().unwrap()` or `.get_mut().unwrap()` when using `[]` would work instead",
+        docs: r#"**What it does:** Checks for use of `.get().unwrap()` (or
+`.get_mut().unwrap`) on a standard library type which implements `Index`
+
+**Why is this bad?** Using the Index trait (`[]`) is more clear and more
+concise.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let last = some_vec.get(3).unwrap();
+*some_vec.get_mut(0).unwrap() = 1;
+```
+The correct use would be:
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let last = some_vec[3];
+some_vec[0] = 1;
+```"#,
+    },
+    LintInfo {
+        name: "heap_allocation",
+        module: "heap_allocation",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "usage of `box`, `vec!` or calls returning a `Box`, `Rc`, `Arc`, `String` or collection",
+        docs: r#"**What it does:** Checks for heap allocations: `box` expressions, `vec!`
+and calls returning a `Box`, `Rc`, `Arc`, `String` or a collection.
+
+**Why is this bad?** Some targets have no heap, or only a small one that
+should not be allocated from after startup.
+
+**Known problems:** Calls are linted by the type they return, so
+constructors like `Vec::new()` that do not allocate yet are linted as well,
+and allocations hidden in other types are not.
+
+**Example:**
+```rust
+let buffer = vec![0u8; 1024];
+```"#,
+    },
+    LintInfo {
+        name: "identity_op",
+        module: "identity_op",
+        level: "warn",
+        group: "clippy",
+        desc: "using identity operations, e.g. `x + 0` or `y / 1`",
+        docs: r#"**What it does:** Checks for identity operations, e.g. `x + 0`.
+
+**Why is this bad?** This code can be removed without changing the
+meaning. So it just obscures what's going on. Delete it mercilessly.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x / 1 + 0 * 1 - 0 | 0
+```"#,
+    },
+    LintInfo {
+        name: "if_let_redundant_pattern_matching",
+        module: "if_let_redundant_pattern_matching",
+        level: "warn",
+        group: "clippy",
+        desc: "use the proper utility function avoiding an `if let`",
+        docs: r#"**What it does:*** Lint for redundant pattern matching over `Result` or `Option`
+
+**Why is this bad?** It's more concise and clear to just use the proper utility function
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+if let Ok(_) = Ok::<i32, i32>(42) {}
+if let Err(_) = Err::<i32, i32>(42) {}
+if let None = None::<()> {}
+if let Some(_) = Some(42) {}
+```
+
+The more idiomatic use would be:
+
+```rust
+if Ok::<i32, i32>(42).is_ok() {}
+if Err::<i32, i32>(42).is_err() {}
+if None::<()>.is_none() {}
+if Some(42).is_some() {}
+```"#,
+    },
+    LintInfo {
+        name: "if_let_some_result",
+        module: "ok_if_let",
+        level: "warn",
+        group: "clippy",
+        desc: "usage of `ok()` in `if let Some(pat)` statements is unnecessary, match on `Ok(pat)` instead",
+        docs: r#"**What it does:*** Checks for unnecessary `ok()` in if let.
+
+**Why is this bad?** Calling `ok()` in if let is unnecessary, instead match on `Ok(pat)`
+
+**Known problems:** None.
+
+**Example:**
+```rust
+for result in iter {
+    if let Some(bench) = try!(result).parse().ok() {
+        vec.push(bench)
+    }
+}
+```
+Could be written:
+
+```rust
+for result in iter {
+    if let Ok(bench) = try!(result).parse() {
+        vec.push(bench)
+    }
+}
+```"#,
+    },
+    LintInfo {
+        name: "if_not_else",
+        module: "if_not_else",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "`if` branches that could be swapped so no negation operation is necessary on the condition",
+        docs: r#"**What it does:** Checks for usage of `!` or `!=` in an if condition with an
+else branch.
+
+**Why is this bad?** Negations reduce the readability of statements.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+if !v.is_empty() {
+    a()
+} else {
+    b()
+}
+```
+
+Could be written:
+
+```rust
+if v.is_empty() {
+    b()
+} else {
+    a()
+}
+```"#,
+    },
+    LintInfo {
+        name: "if_same_then_else",
+        module: "copies",
+        level: "warn",
+        group: "clippy",
+        desc: "if with the same *then* and *else* blocks",
+        docs: r#"**What it does:** Checks for `if/else` with the same body as the *then* part
+and the *else* part.
+
+**Why is this bad?** This is probably a copy & paste error.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```rust
+let foo = if … {
+    42
+} else {
+    42
+};
+```"#,
+    },
+    LintInfo {
+        name: "ifs_same_cond",
+        module: "copies",
+        level: "warn",
+        group: "clippy",
+        desc: "consecutive `ifs` with the same condition",
+        docs: r#"**What it does:** Checks for consecutive `if`s with the same condition.
+
+**Why is this bad?** This is probably a copy & paste error.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```rust
+if a == b {
+    …
+} else if a == b {
+    …
+}
+```
+
+Note that this lint ignores all conditions with a function call as it could
+have side effects:
+
+```rust
+if foo() {
+    …
+} else if foo() { // not linted
+    …
+}
+```"#,
+    },
+    LintInfo {
+        name: "indexing_slicing",
+        module: "array_indexing",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "indexing/slicing usage",
+        docs: r#"**What it does:** Checks for usage of indexing or slicing.
+
+**Why is this bad?** Usually, this can be safely allowed. However, in some
+domains such as kernel development, a panic can cause the whole operating
+system to crash.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```rust
+...
+x[2];
+&x[0..2];
+```"#,
+    },
+    LintInfo {
+        name: "ineffective_bit_mask",
+        module: "bit_mask",
+        level: "warn",
+        group: "clippy",
+        desc: "expressions where a bit mask will be rendered useless by a comparison, e.g. `(x | 1) > 2`",
+        docs: r#"**What it does:** Checks for bit masks in comparisons which can be removed
+without changing the outcome. The basic structure can be seen in the
+following table:
+
+|Comparison| Bit Op  |Example    |equals |
+|----------|---------|-----------|-------|
+|`>` / `<=`|`|` / `^`|`x | 2 > 3`|`x > 3`|
+|`<` / `>=`|`|` / `^`|`x ^ 1 < 4`|`x < 4`|
+
+**Why is this bad?** Not equally evil as [`bad_bit_mask`](#bad_bit_mask),
+but still a bit misleading, because the bit mask is ineffective.
+
+**Known problems:** False negatives: This lint will only match instances
+where we have figured out the math (which is for a power-of-two compared
+value). This means things like `x | 1 >= 7` (which would be better written
+as `x >= 6`) will not be reported (but bit masks like this are fairly
+uncommon).
+
+**Example:**
+```rust
+if (x | 1 > 3) { … }
+```"#,
+    },
+    LintInfo {
+        name: "inline_always",
+        module: "attrs",
+        level: "warn",
+        group: "clippy",
+        desc: "use of `#[inline(always)]`",
+        docs: r#"**What it does:** Checks for items annotated with `#[inline(always)]`,
+unless the annotated function is empty or simply panics.
+
+**Why is this bad?** While there are valid uses of this annotation (and once
+you know when to use it, by all means `allow` this lint), it's a common
+newbie-mistake to pepper one's code with it.
+
+As a rule of thumb, before slapping `#[inline(always)]` on a function,
+measure if that additional function call really affects your runtime profile
+sufficiently to make up for the increase in compile time.
+
+**Known problems:** False positives, big time. This lint is meant to be
+deactivated by everyone doing serious performance work. This means having
+done the measurement.
+
+**Example:**
+```rust
+#[inline(always)]
+fn not_quite_hot_code(..) { ... }
+```"#,
+    },
+    LintInfo {
+        name: "integer_arithmetic",
+        module: "arithmetic",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "any integer arithmetic statement",
+        docs: r#"**What it does:** Checks for plain integer arithmetic.
+
+**Why is this bad?** This is only checked against overflow in debug builds.
+In some applications one wants explicitly checked, wrapping or saturating
+arithmetic.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+a + 1
+```"#,
+    },
+    LintInfo {
+        name: "invalid_regex",
+        module: "regex",
+        level: "deny",
+        group: "clippy",
+        desc: "invalid regular expressions",
+        docs: r#"**What it does:** Checks [regex] creation (with `Regex::new`,
+`RegexBuilder::new` or `RegexSet::new`) for correct regex syntax.
+
+[regex]: https://crates.io/crates/regex
+
+**Why is this bad?** This will lead to a runtime panic.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+Regex::new("|")
+```"#,
+    },
+    LintInfo {
+        name: "invalid_upcast_comparisons",
+        module: "types",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "a comparison involving an upcast which is always true or false",
+        docs: r#"**What it does:** Checks for comparisons where the relation is always either
+true or false, but where one side has been upcast so that the comparison is
+necessary. Only integer types are checked.
+
+**Why is this bad?** An expression like `let x : u8 = ...; (x as u32) > 300`
+will mistakenly imply that it is possible for `x` to be outside the range of
+`u8`.
+
+**Known problems:** https://github.com/Manishearth/rust-clippy/issues/886
+
+**Example:**
+```rust
+let x : u8 = ...; (x as u32) > 300
+```"#,
+    },
+    LintInfo {
+        name: "items_after_statements",
+        module: "items_after_statements",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "blocks where an item comes after a statement",
+        docs: r#"**What it does:** Checks for items declared after some statement in a block.
+
+**Why is this bad?** Items live for the entire scope they are declared
+in. But statements are processed in order. This might cause confusion as
+it's hard to figure out which item is meant in a statement.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn foo() {
+    println!("cake");
+}
+
+fn main() {
+    foo(); // prints "foo"
+    fn foo() {
+        println!("foo");
+    }
+    foo(); // prints "foo"
+}
+```"#,
+    },
+    LintInfo {
+        name: "iter_cloned_collect",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `.cloned().collect()` on slice to create a `Vec`",
+        docs: r#"**What it does:** Checks for the use of `.cloned().collect()` on slice to create a `Vec`.
+
+**Why is this bad?** `.to_vec()` is clearer
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let s = [1,2,3,4,5];
+let s2 : Vec<isize> = s[..].iter().cloned().collect();
+```
+The better use would be:
+```rust
+let s = [1,2,3,4,5];
+let s2 : Vec<isize> = s.to_vec();
+```"#,
+    },
+    LintInfo {
+        name: "iter_next_loop",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "for-looping over `_.next()` which is probably not intended",
+        docs: r#"**What it does:** Checks for loops on `x.next()`.
+
+**Why is this bad?** `next()` returns either `Some(value)` if there was a
+value, or `None` otherwise. The insidious thing is that `Option<_>`
+implements `IntoIterator`, so that possibly one value will be iterated,
+leading to some hard to find bugs. No one will want to write such code
+[except to win an Underhanded Rust
+Contest](https://www.reddit.com/r/rust/comments/3hb0wm/underhanded_rust_contest/cu5yuhr).
+
+**Known problems:** None.
+
+**Example:**
+```rust
+for x in y.next() { .. }
+```"#,
+    },
+    LintInfo {
+        name: "iter_nth",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `.iter().nth()` on a standard library type with O(1) element access",
+        docs: r#"**What it does:** Checks for use of `.iter().nth()` (and the related
+`.iter_mut().nth()`) on standard library types with O(1) element access.
+
+**Why is this bad?** `.get()` and `.get_mut()` are more efficient and more
+readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let bad_vec = some_vec.iter().nth(3);
+let bad_slice = &some_vec[..].iter().nth(3);
+```
+The correct use would be:
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let bad_vec = some_vec.get(3);
+let bad_slice = &some_vec[..].get(3);
+```"#,
+    },
+    LintInfo {
+        name: "iter_skip_next",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `.skip(x).next()` on an iterator",
+        docs: r#"**What it does:** Checks for use of `.skip(x).next()` on iterators.
+
+**Why is this bad?** `.nth(x)` is cleaner
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let bad_vec = some_vec.iter().skip(3).next();
+let bad_slice = &some_vec[..].iter().skip(3).next();
+```
+The correct use would be:
+```rust
+let some_vec = vec![0, 1, 2, 3];
+let bad_vec = some_vec.iter().nth(3);
+let bad_slice = &some_vec[..].iter().nth(3);
+```"#,
+    },
+    LintInfo {
+        name: "large_enum_variant",
+        module: "large_enum_variant",
+        level: "warn",
+        group: "clippy",
+        desc: "large size difference between variants on an enum",
+        docs: r#"**What it does:** Checks for large size differences between variants on `enum`s.
+
+**Why is this bad?** Enum size is bounded by the largest variant. Having a large variant
+can penalize the memory layout of that enum.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+enum Test {
+   A(i32),
+   B([i32; 8000]),
+}
+```"#,
+    },
+    LintInfo {
+        name: "len_without_is_empty",
+        module: "len_zero",
+        level: "warn",
+        group: "clippy",
+        desc: "traits or impls with a public `len` method but no corresponding `is_empty` method",
+        docs: r#"**What it does:** Checks for items that implement `.len()` but not
+`.is_empty()`.
+
+**Why is this bad?** It is good custom to have both methods, because for
+some data structures, asking about the length will be a costly operation,
+whereas `.is_empty()` can usually answer in constant time. Also it used to
+lead to false positives on the [`len_zero`](#len_zero) lint – currently that
+lint will ignore such entities.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+impl X {
+    pub fn len(&self) -> usize { .. }
+}
+```"#,
+    },
+    LintInfo {
+        name: "len_zero",
+        module: "len_zero",
+        level: "warn",
+        group: "clippy",
+        desc: "checking `.len() == 0` or `.len() > 0` (or similar) when `.is_empty()` could be used instead",
+        docs: r#"**What it does:** Checks for getting the length of something via `.len()`
+just to compare to zero, and suggests using `.is_empty()` where applicable.
+
+**Why is this bad?** Some structures can answer `.is_empty()` much faster
+than calculating their length. So it is good to get into the habit of using
+`.is_empty()`, and having it is cheap. Besides, it makes the intent clearer
+than a comparison.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+if x.len() == 0 { .. }
+```"#,
+    },
+    LintInfo {
+        name: "let_and_return",
+        module: "returns",
+        level: "warn",
+        group: "clippy",
+        desc: "creating a let-binding and then immediately returning it like `let x = expr; x` at the end of a block",
+        docs: r#"**What it does:** Checks for `let`-bindings, which are subsequently returned.
+
+**Why is this bad?** It is just extraneous code. Remove it to make your code
+more rusty.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+{ let x = ..; x }
+```"#,
+    },
+    LintInfo {
+        name: "let_unit_value",
+        module: "types",
+        level: "warn",
+        group: "clippy",
+        desc: "creating a let binding to a value of unit type, which usually can't be used afterwards",
+        docs: r#"**What it does:** Checks for binding a unit value.
+
+**Why is this bad?** A unit value cannot usefully be used anywhere. So
+binding one is kind of pointless.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = { 1; };
+```"#,
+    },
+    LintInfo {
+        name: "linkedlist",
+        module: "types",
+        level: "warn",
+        group: "clippy",
+        desc: "usage of LinkedList, usually a vector is faster, or a more specialized data structure like a VecDeque",
+        docs: r#"**What it does:** Checks for usage of any `LinkedList`, suggesting to use a
+`Vec` or a `VecDeque` (formerly called `RingBuf`).
+
+**Why is this bad?** Gankro says:
+
+> The TL;DR of `LinkedList` is that it's built on a massive amount of pointers and indirection.
+> It wastes memory, it has terrible cache locality, and is all-around slow. `RingBuf`, while
+> "only" amortized for push/pop, should be faster in the general case for almost every possible
+> workload, and isn't even amortized at all if you can predict the capacity you need.
+>
+> `LinkedList`s are only really good if you're doing a lot of merging or splitting of lists.
+> This is because they can just mangle some pointers instead of actually copying the data. Even
+> if you're doing a lot of insertion in the middle of the list, `RingBuf` can still be better
+> because of how expensive it is to seek to the middle of a `LinkedList`.
+
+**Known problems:** False positives – the instances where using a
+`LinkedList` makes sense are few and far between, but they can still happen.
+
+**Example:**
+```rust
+let x = LinkedList::new();
+```"#,
+    },
+    LintInfo {
+        name: "logic_bug",
+        module: "booleans",
+        level: "warn",
+        group: "clippy",
+        desc: "boolean expressions that contain terminals which can be eliminated",
+        docs: r#"**What it does:** Checks for boolean expressions that contain terminals that
+can be eliminated.
+
+**Why is this bad?** This is most likely a logic bug.
+
+**Known problems:** Ignores short circuiting behavior.
+
+**Example:**
+```rust
+if a && b || a { ... }
+```
+The `b` is unnecessary, the expression is equivalent to `if a`."#,
+    },
+    LintInfo {
+        name: "manual_swap",
+        module: "swap",
+        level: "warn",
+        group: "clippy",
+        desc: "manual swap of two variables",
+        docs: r#"**What it does:** Checks for manual swapping.
+
+**Why is this bad?** The `std::mem::swap` function exposes the intent better
+without deinitializing or copying either variable.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+let t = b;
+b = a;
+a = t;
+```"#,
+    },
+    LintInfo {
+        name: "many_single_char_names",
+        module: "non_expressive_names",
+        level: "warn",
+        group: "clippy",
+        desc: "too many single character bindings",
+        docs: r#"**What it does:** Checks for too many variables whose name consists of a
+single character.
+
+**Why is this bad?** It's hard to memorize what a variable means without a
+descriptive name.
+
+**Known problems:** None?
+
+**Example:**
+```rust
+let (a, b, c, d, e, f, g) = (...);
+```"#,
+    },
+    LintInfo {
+        name: "map_clone",
+        module: "map_clone",
+        level: "warn",
+        group: "clippy",
+        desc: "using `.map(|x| x.clone())` to clone an iterator or option's contents",
+        docs: r#"**What it does:** Checks for mapping `clone()` over an iterator.
+
+**Why is this bad?** It makes the code less readable than using the
+`.cloned()` adapter.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x.map(|e| e.clone());
+```"#,
+    },
+    LintInfo {
+        name: "map_entry",
+        module: "entry",
+        level: "warn",
+        group: "clippy",
+        desc: "use of `contains_key` followed by `insert` on a `HashMap` or `BTreeMap`",
+        docs: r#"**What it does:** Checks for uses of `contains_key` + `insert` on `HashMap`
+or `BTreeMap`.
+
+**Why is this bad?** Using `entry` is more efficient.
+
+**Known problems:** Some false negatives, eg.:
+```rust
+let k = &key;
+if !m.contains_key(k) { m.insert(k.clone(), v); }
+```
+
+**Example:**
+```rust
+if !m.contains_key(&k) { m.insert(k, v) }
+```
+can be rewritten as:
+```rust
+m.entry(k).or_insert(v);
+```"#,
+    },
+    LintInfo {
+        name: "match_bool",
+        module: "matches",
+        level: "warn",
+        group: "clippy",
+        desc: "a match on a boolean expression instead of an `if..else` block",
+        docs: r#"**What it does:** Checks for matches where match expression is a `bool`. It
+suggests to replace the expression with an `if...else` block.
+
+**Why is this bad?** It makes the code less readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let condition: bool = true;
+match condition {
+    true => foo(),
+    false => bar(),
+}
+```"#,
+    },
+    LintInfo {
+        name: "match_overlapping_arm",
+        module: "matches",
+        level: "warn",
+        group: "clippy",
+        desc: "a match with overlapping arms",
+        docs: r#"**What it does:** Checks for overlapping match arms.
+
+**Why is this bad?** It is likely to be an error and if not, makes the code
+less obvious.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = 5;
+match x {
+    1 ... 10 => println!("1 ... 10"),
+    5 ... 15 => println!("5 ... 15"),
+    _ => (),
+}
+```"#,
+    },
+    LintInfo {
+        name: "match_ref_pats",
+        module: "matches",
+        level: "warn",
+        group: "clippy",
+        desc: "a match or `if let` with all arms prefixed with `&` instead of deref-ing the match expression",
+        docs: r#"**What it does:** Checks for matches where all arms match a reference,
+suggesting to remove the reference and deref the matched expression
+instead. It also checks for `if let &foo = bar` blocks.
+
+**Why is this bad?** It just makes the code less readable. That reference
+destructuring adds nothing to the code.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+match x {
+    &A(ref y) => foo(y),
+    &B => bar(),
+    _ => frob(&x),
+}
+```"#,
+    },
+    LintInfo {
+        name: "match_same_arms",
+        module: "copies",
+        level: "warn",
+        group: "clippy",
+        desc: "`match` with identical arm bodies",
+        docs: r#"**What it does:** Checks for `match` with identical arm bodies.
+
+**Why is this bad?** This is probably a copy & paste error. If arm bodies
+are the same on purpose, you can factor them
+[using `|`](https://doc.rust-lang.org/book/patterns.html#multiple-patterns).
+
+**Known problems:** False positive possible with order dependent `match`
+(see issue [#860](https://github.com/Manishearth/rust-clippy/issues/860)).
+
+**Example:**
+```rust,ignore
+match foo {
+    Bar => bar(),
+    Quz => quz(),
+    Baz => bar(), // <= oops
+}
+```
+
+This should probably be
+```rust,ignore
+match foo {
+    Bar => bar(),
+    Quz => quz(),
+    Baz => baz(), // <= fixed
+}
+```
+
+or if the original code was not a typo:
+```rust,ignore
+match foo {
+    Bar | Baz => bar(), // <= shows the intent better
+    Quz => quz(),
+}
+```"#,
+    },
+    LintInfo {
+        name: "match_wild_err_arm",
+        module: "matches",
+        level: "warn",
+        group: "clippy",
+        desc: "a match with `Err(_)` arm and take drastic actions",
+        docs: r#"**What it does:** Checks for arm which matches all errors with `Err(_)`
+and take drastic actions like `panic!`.
+
+**Why is this bad?** It is generally a bad practice, just like
+catching all exceptions in java with `catch(Exception)`
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x : Result(i32, &str) = Ok(3);
+match x {
+    Ok(_) => println!("ok"),
+    Err(_) => panic!("err"),
+}
+```"#,
+    },
+    LintInfo {
+        name: "mem_forget",
+        module: "mem_forget",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "`mem::forget` usage on `Drop` types, likely to cause memory leaks",
+        docs: r#"**What it does:** Checks for usage of `std::mem::forget(t)` where `t` is `Drop`.
+
+**Why is this bad?** `std::mem::forget(t)` prevents `t` from running its
+destructor, possibly causing leaks.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+mem::forget(Rc::new(55)))
+```"#,
+    },
+    LintInfo {
+        name: "min_max",
+        module: "minmax",
+        level: "warn",
+        group: "clippy",
+        desc: "`min(_, max(_, _))` (or vice versa) with bounds clamping the result to a constant",
+        docs: r#"**What it does:** Checks for expressions where `std::cmp::min` and `max` are
+used to clamp values, but switched so that the result is constant.
+
+**Why is this bad?** This is in all probability not the intended outcome. At
+the least it hurts readability of the code.
+
+**Known problems:** None
+
+**Example:**
+```rust
+min(0, max(100, x))
+```
+It will always be equal to `0`. Probably the author meant to clamp the value
+between 0 and 100, but has erroneously swapped `min` and `max`."#,
+    },
+    LintInfo {
+        name: "misrefactored_assign_op",
+        module: "assign_ops",
+        level: "warn",
+        group: "clippy",
+        desc: "having a variable on both sides of an assign op",
+        docs: r#"**What it does:** Checks for `a op= a op b` or `a op= b op a` patterns.
+
+**Why is this bad?** Most likely these are bugs where one meant to write `a op= b`.
+
+**Known problems:** Someone might actually mean `a op= a op b`, but that
+should rather be written as `a = (2 * a) op b` where applicable.
+
+**Example:**
+```rust
+let mut a = 5;
+...
+a += a + b;
+```"#,
+    },
+    LintInfo {
+        name: "missing_docs_in_private_items",
+        module: "missing_doc",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "detects missing documentation for public and private members",
+        docs: r#"**What it does:** Warns if there is missing doc for any documentable item (public or private).
+
+**Why is this bad?** Doc is good. *rustc* has a `MISSING_DOCS` allowed-by-default lint for
+public members, but has no way to enforce documentation of private items. This lint fixes that.
+
+**Known problems:** None."#,
+    },
+    LintInfo {
+        name: "mixed_case_hex_literals",
+        module: "misc_early",
+        level: "warn",
+        group: "clippy",
+        desc: "hex literals whose letter digits are not consistently upper- or lowercased",
+        docs: r#"**What it does:** Warns on hexadecimal literals with mixed-case letter digits.
+
+**Why is this bad?** It looks confusing.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let y = 0x1a9BAcD;
+```"#,
+    },
+    LintInfo {
+        name: "module_inception",
+        module: "enum_variants",
+        level: "warn",
+        group: "clippy",
+        desc: "modules that have the same name as their parent module",
+        docs: r#"**What it does:** Checks for modules that have the same name as their parent module
+
+**Why is this bad?** A typical beginner mistake is to have `mod foo;` and again `mod foo { ..
+}` in `foo.rs`.
+The expectation is that items inside the inner `mod foo { .. }` are then
+available
+                     through `foo::x`, but they are only available through `foo::foo::x`.
+If this is done on purpose, it would be better to choose a more
+representative module name.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+// lib.rs
+mod foo;
+// foo.rs
+mod foo {
+    ...
+}
+```"#,
+    },
+    LintInfo {
+        name: "modulo_one",
+        module: "misc",
+        level: "warn",
+        group: "clippy",
+        desc: "taking a number modulo 1, which always returns 0",
+        docs: r#"**What it does:** Checks for getting the remainder of a division by one.
+
+**Why is this bad?** The result can only ever be zero. No one will write
+such code deliberately, unless trying to win an Underhanded Rust
+Contest. Even for that contest, it's probably a bad idea. Use something more
+underhanded.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x % 1
+```"#,
+    },
+    LintInfo {
+        name: "mut_from_ref",
+        module: "ptr",
+        level: "warn",
+        group: "clippy",
+        desc: "fns that create mutable refs from immutable ref args",
+        docs: r#"**What it does:** This lint checks for functions that take immutable references and return
+mutable ones.
+
+**Why is this bad?** This is trivially unsound, as one can create two mutable references
+from the same (immutable!) source. This [error](https://github.com/rust-lang/rust/issues/39465)
+actually lead to an interim Rust release 1.15.1.
+
+**Known problems:** To be on the conservative side, if there's at least one mutable reference
+with the output lifetime, this lint will not trigger. In practice, this case is unlikely anyway.
+
+**Example:**
+```rust
+fn foo(&Foo) -> &mut Bar { .. }
+```"#,
+    },
+    LintInfo {
+        name: "mut_mut",
+        module: "mut_mut",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "usage of double-mut refs, e.g. `&mut &mut ...`",
+        docs: r#"**What it does:** Checks for instances of `mut mut` references.
+
+**Why is this bad?** Multiple `mut`s don't add anything meaningful to the
+source. This is either a copy'n'paste error, or it shows a fundamental
+misunderstanding of references.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = &mut &mut y;
+```"#,
+    },
+    LintInfo {
+        name: "mutex_atomic",
+        module: "mutex_atomic",
+        level: "warn",
+        group: "clippy",
+        desc: "using a mutex where an atomic value could be used instead",
+        docs: r#"**What it does:** Checks for usages of `Mutex<X>` where an atomic will do.
+
+**Why is this bad?** Using a mutex just to make access to a plain bool or
+reference sequential is shooting flies with cannons.
+`std::atomic::AtomicBool` and `std::atomic::AtomicPtr` are leaner and
+faster.
+
+**Known problems:** This lint cannot detect if the mutex is actually used
+for waiting before a critical section.
+
+**Example:**
+```rust
+let x = Mutex::new(&y);
+```"#,
+    },
+    LintInfo {
+        name: "mutex_integer",
+        module: "mutex_atomic",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using a mutex for an integer type",
+        docs: r#"**What it does:** Checks for usages of `Mutex<X>` where `X` is an integral type.
+
+**Why is this bad?** Using a mutex just to make access to a plain integer sequential is
+shooting flies with cannons. `std::atomic::usize` is leaner and faster.
+
+**Known problems:** This lint cannot detect if the mutex is actually used
+for waiting before a critical section.
+
+**Example:**
+```rust
+let x = Mutex::new(0usize);
+```"#,
+    },
+    LintInfo {
+        name: "needless_bool",
+        module: "needless_bool",
+        level: "warn",
+        group: "clippy",
+        desc: "if-statements with plain booleans in the then- and else-clause, e.g. `if p { true } else { false }`",
+        docs: r#"**What it does:** Checks for expressions of the form `if c { true } else { false }`
+(or vice versa) and suggest using the condition directly.
+
+**Why is this bad?** Redundant code.
+
+**Known problems:** Maybe false positives: Sometimes, the two branches are
+painstakingly documented (which we of course do not detect), so they *may*
+have some value. Even then, the documentation can be rewritten to match the
+shorter code.
+
+**Example:**
+```rust
+if x { false } else { true }
+```"#,
+    },
+    LintInfo {
+        name: "needless_borrow",
+        module: "needless_borrow",
+        level: "warn",
+        group: "clippy",
+        desc: "taking a reference that is going to be automatically dereferenced",
+        docs: r#"**What it does:** Checks for address of operations (`&`) that are going to
+be dereferenced immediately by the compiler.
+
+**Why is this bad?** Suggests that the receiver of the expression borrows
+the expression.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x: &i32 = &&&&&&5;
+```"#,
+    },
+    LintInfo {
+        name: "needless_lifetimes",
+        module: "lifetimes",
+        level: "warn",
+        group: "clippy",
+        desc: "using explicit lifetimes for references in function arguments when elision rules would allow omitting them",
+        docs: r#"**What it does:** Checks for lifetime annotations which can be removed by
+relying on lifetime elision.
+
+**Why is this bad?** The additional lifetimes make the code look more
+complicated, while there is nothing out of the ordinary going on. Removing
+them leads to more readable code.
+
+**Known problems:** Potential false negatives: we bail out if the function
+has a `where` clause where lifetimes are mentioned.
+
+**Example:**
+```rust
+fn in_and_out<'a>(x: &'a u8, y: u8) -> &'a u8 { x }
+```"#,
+    },
+    LintInfo {
+        name: "needless_pass_by_value",
+        module: "needless_pass_by_value",
+        level: "warn",
+        group: "clippy",
+        desc: "functions taking arguments by value, but not consuming them in its body",
+        docs: r#"**What it does:** Checks for functions taking arguments by value, but not consuming them in its
+body.
+
+**Why is this bad?** Taking arguments by reference is more flexible and can sometimes avoid
+unnecessary allocations.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```rust
+fn foo(v: Vec<i32>) {
+    assert_eq!(v.len(), 42);
+}
+```"#,
+    },
+    LintInfo {
+        name: "needless_range_loop",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "for-looping over a range of indices where an iterator over items would do",
+        docs: r#"**What it does:** Checks for looping over the range of `0..len` of some
+collection just to get the values by index.
+
+**Why is this bad?** Just iterating the collection itself makes the intent
+more clear and is probably faster.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+for i in 0..vec.len() {
+    println!("{}", vec[i]);
+}
+```"#,
+    },
+    LintInfo {
+        name: "needless_return",
+        module: "returns",
+        level: "warn",
+        group: "clippy",
+        desc: "using a return statement like `return expr;` where an expression would suffice",
+        docs: r#"**What it does:** Checks for return statements at the end of a block.
+
+**Why is this bad?** Removing the `return` and semicolon will make the code
+more rusty.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn foo(x: usize) { return x; }
+```"#,
+    },
+    LintInfo {
+        name: "needless_update",
+        module: "needless_update",
+        level: "warn",
+        group: "clippy",
+        desc: "using `Foo { ..base }` when there are no missing fields",
+        docs: r#"**What it does:** Checks for needlessly including a base struct on update
+when all fields are changed anyway.
+
+**Why is this bad?** This will cost resources (because the base has to be
+somewhere), and make the code less readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+Point { x: 1, y: 0, ..zero_point }
+```"#,
+    },
+    LintInfo {
+        name: "neg_multiply",
+        module: "neg_multiply",
+        level: "warn",
+        group: "clippy",
+        desc: "multiplying integers with -1",
+        docs: r#"**What it does:** Checks for multiplication by -1 as a form of negation.
+
+**Why is this bad?** It's more readable to just negate.
+
+**Known problems:** This only catches integers (for now).
+
+**Example:**
+```rust
+x * -1
+```"#,
+    },
+    LintInfo {
+        name: "never_loop",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "any loop with an unconditional `break` statement",
+        docs: r#"**What it does:** Checks for loops that contain an unconditional `break`.
+
+**Why is this bad?** This loop never loops, all it does is obfuscating the
+code.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+loop { ..; break; }
+```"#,
+    },
+    LintInfo {
+        name: "new_ret_no_self",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "not returning `Self` in a `new` method",
+        docs: r#"**What it does:** Checks for `new` not returning `Self`.
+
+**Why is this bad?** As a convention, `new` methods are used to make a new
+instance of a type.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+impl Foo {
+    fn new(..) -> NotAFoo {
+    }
+}
+```"#,
+    },
+    LintInfo {
+        name: "new_without_default",
+        module: "new_without_default",
+        level: "warn",
+        group: "clippy",
+        desc: "`fn new() -> Self` method without `Default` implementation",
+        docs: r#"**What it does:** Checks for types with a `fn new() -> Self` method and no
+implementation of
+[`Default`](https://doc.rust-lang.org/std/default/trait.Default.html).
+
+**Why is this bad?** The user might expect to be able to use
+[`Default`](https://doc.rust-lang.org/std/default/trait.Default.html) as the
+type can be constructed without arguments.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+
+```rust,ignore
+struct Foo(Bar);
+
+impl Foo {
+    fn new() -> Self {
+        Foo(Bar::new())
+    }
+}
+```
+
+Instead, use:
+
+```rust
+struct Foo(Bar);
+
+impl Default for Foo {
+    fn default() -> Self {
+        Foo(Bar::new())
+    }
+}
+```
+
+You can also have `new()` call `Default::default()`."#,
+    },
+    LintInfo {
+        name: "new_without_default_derive",
+        module: "new_without_default",
+        level: "warn",
+        group: "clippy",
+        desc: "`fn new() -> Self` without `#[derive]`able `Default` implementation",
+        docs: r#"**What it does:** Checks for types with a `fn new() -> Self` method
+and no implementation of
+[`Default`](https://doc.rust-lang.org/std/default/trait.Default.html),
+where the `Default` can be derived by `#[derive(Default)]`.
+
+**Why is this bad?** The user might expect to be able to use
+[`Default`](https://doc.rust-lang.org/std/default/trait.Default.html) as the
+type can be constructed without arguments.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+
+```rust,ignore
+struct Foo;
+
+impl Foo {
+    fn new() -> Self {
+        Foo
+    }
+}
+```
+
+Just prepend `#[derive(Default)]` before the `struct` definition."#,
+    },
+    LintInfo {
+        name: "no_effect",
+        module: "no_effect",
+        level: "warn",
+        group: "clippy",
+        desc: "statements with no effect",
+        docs: r#"**What it does:** Checks for statements which have no effect.
+
+**Why is this bad?** Similar to dead code, these statements are actually
+executed. However, as they have no effect, all they do is make the code less
+readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+0;
+```"#,
+    },
+    LintInfo {
+        name: "non_ascii_literal",
+        module: "unicode",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using any literal non-ASCII chars in a string literal instead of using the `\\u` escape",
+        docs: r#"**What it does:** Checks for non-ASCII characters in string literals.
+
+**Why is this bad?** Yeah, we know, the 90's called and wanted their charset
+back. Even so, there still are editors and other programs out there that
+don't work well with Unicode. So if the code is meant to be used
+internationally, on multiple operating systems, or has other portability
+requirements, activating this lint could be useful.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let x = "Hä?"
+```"#,
+    },
+    LintInfo {
+        name: "nonminimal_bool",
+        module: "booleans",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "boolean expressions that can be written more concisely",
+        docs: r#"**What it does:** Checks for boolean expressions that can be written more
+concisely.
+
+**Why is this bad?** Readability of boolean expressions suffers from
+unnecessary duplication.
+
+**Known problems:** Ignores short circuiting behavior of `||` and
+`&&`. Ignores `|`, `&` and `^`.
+
+**Example:**
+```rust
+if a && true  // should be: if a
+if !(a == b)  // should be: if a != b
+```"#,
+    },
+    LintInfo {
+        name: "nonsensical_open_options",
+        module: "open_options",
+        level: "warn",
+        group: "clippy",
+        desc: "nonsensical combination of options for opening a file",
+        docs: r#"**What it does:** Checks for duplicate open options as well as combinations
+that make no sense.
+
+**Why is this bad?** In the best case, the code will be harder to read than
+necessary. I don't know the worst case.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+OpenOptions::new().read(true).truncate(true)
+```"#,
+    },
+    LintInfo {
+        name: "not_unsafe_ptr_arg_deref",
+        module: "functions",
+        level: "warn",
+        group: "clippy",
+        desc: "public functions dereferencing raw pointer arguments but not marked `unsafe`",
+        docs: r#"**What it does:** Checks for public functions that dereferences raw pointer
+arguments but are not marked unsafe.
+
+**Why is this bad?** The function should probably be marked `unsafe`, since
+for an arbitrary raw pointer, there is no way of telling for sure if it is
+valid.
+
+**Known problems:**
+
+* It does not check functions recursively so if the pointer is passed to a
+private non-`unsafe` function which does the dereferencing, the lint won't trigger.
+* It only checks for arguments whose type are raw pointers, not raw pointers
+got from an argument in some other way (`fn foo(bar: &[*const u8])` or
+`some_argument.get_raw_ptr()`).
+
+**Example:**
+```rust
+pub fn foo(x: *const u8) { println!("{}", unsafe { *x }); }
+```"#,
+    },
+    LintInfo {
+        name: "ok_expect",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `ok().expect()`, which gives worse error messages than calling `expect` directly on the Result",
+        docs: r#"**What it does:** Checks for usage of `ok().expect(..)`.
+
+**Why is this bad?** Because you usually call `expect()` on the `Result`
+directly to get a better error message.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x.ok().expect("why did I do this again?")
+```"#,
+    },
+    LintInfo {
+        name: "op_ref",
+        module: "eq_op",
+        level: "warn",
+        group: "clippy",
+        desc: "taking a reference to satisfy the type constraints on `==`",
+        docs: r#"**What it does:** Checks for arguments to `==` which have their address taken to satisfy a bound
+and suggests to dereference the other argument instead
+
+**Why is this bad?** It is more idiomatic to dereference the other argument.
+
+**Known problems:** None
+
+**Example:**
+```rust
+&x == y
+```"#,
+    },
+    LintInfo {
+        name: "option_map_unwrap_or",
+        module: "methods",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using `Option.map(f).unwrap_or(a)`, which is more succinctly expressed as `map_or(a, f)`",
+        docs: r#"**What it does:** Checks for usage of `_.map(_).unwrap_or(_)`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.map_or(_, _)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x.map(|a| a + 1).unwrap_or(0)
+```"#,
+    },
+    LintInfo {
+        name: "option_map_unwrap_or_else",
+        module: "methods",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using `Option.map(f).unwrap_or_else(g)`, which is more succinctly expressed as `map_or_else(g, f)`",
+        docs: r#"**What it does:** Checks for usage of `_.map(_).unwrap_or_else(_)`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.map_or_else(_, _)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x.map(|a| a + 1).unwrap_or_else(some_function)
+```"#,
+    },
+    LintInfo {
+        name: "option_unwrap_used",
+        module: "methods",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using `Option.unwrap()`, which should at least get a better message using `expect()`",
+        docs: r#"**What it does:** Checks for `.unwrap()` calls on `Option`s.
+
+**Why is this bad?** Usually it is better to handle the `None` case, or to
+at least call `.expect(_)` with a more helpful message. Still, for a lot of
+quick-and-dirty code, `unwrap` is a good choice, which is why this lint is
+`Allow` by default.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x.unwrap()
+```"#,
+    },
+    LintInfo {
+        name: "or_fun_call",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using any `*or` method with a function call, which suggests `*or_else`",
+        docs: r#"**What it does:** Checks for calls to `.or(foo(..))`, `.unwrap_or(foo(..))`,
+etc., and suggests to use `or_else`, `unwrap_or_else`, etc., or
+`unwrap_or_default` instead.
+
+**Why is this bad?** The function will always be called and potentially
+allocate an object acting as the default.
+
+**Known problems:** If the function has side-effects, not calling it will
+change the semantic of the program, but you shouldn't rely on that anyway.
+
+**Example:**
+```rust
+foo.unwrap_or(String::new())
+```
+this can instead be written:
+```rust
+foo.unwrap_or_else(String::new)
+```
+or
+```rust
+foo.unwrap_or_default()
+```"#,
+    },
+    LintInfo {
+        name: "out_of_bounds_indexing",
+        module: "array_indexing",
+        level: "deny",
+        group: "clippy",
+        desc: "out of bounds constant indexing",
+        docs: r#"**What it does:** Checks for out of bounds array indexing with a constant index.
+
+**Why is this bad?** This will always panic at runtime.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```rust
+let x = [1,2,3,4];
+...
+x[9];
+&x[2..9];
+```"#,
+    },
+    LintInfo {
+        name: "overflow_check_conditional",
+        module: "overflow_check_conditional",
+        level: "warn",
+        group: "clippy",
+        desc: "overflow checks inspired by C which are likely to panic",
+        docs: r#"**What it does:** Detects classic underflow/overflow checks.
+
+**Why is this bad?** Most classic C underflow/overflow checks will fail in
+Rust. Users can use functions like `overflowing_*` and `wrapping_*` instead.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+a + b < a
+```"#,
+    },
+    LintInfo {
+        name: "panic",
+        module: "panic",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "usage of the `panic!` macro",
+        docs: r#"**What it does:** Checks for usage of `panic!`.
+
+**Why is this bad?** Code that must report all its errors to its caller
+should not panic.
+
+**Known problems:** Panics from macros like `assert!` are not linted.
+
+**Example:**
+```rust
+panic!("even with a good reason");
+```"#,
+    },
+    LintInfo {
+        name: "panic_params",
+        module: "panic",
+        level: "warn",
+        group: "clippy",
+        desc: "missing parameters in `panic!` calls",
+        docs: r#"**What it does:** Checks for missing parameters in `panic!`.
+
+**Why is this bad?** Contrary to the `format!` family of macros, there are
+two forms of `panic!`: if there are no parameters given, the first argument
+is not a format string and used literally. So while `format!("{}")` will
+fail to compile, `panic!("{}")` will not.
+
+**Known problems:** Should you want to use curly brackets in `panic!`
+without any parameter, this lint will warn.
+
+**Example:**
+```rust
+panic!("This `panic!` is probably missing a parameter there: {}");
+```"#,
+    },
+    LintInfo {
+        name: "partialeq_ne_impl",
+        module: "partialeq_ne_impl",
+        level: "warn",
+        group: "clippy",
+        desc: "re-implementing `PartialEq::ne`",
+        docs: r#"**What it does:** Checks for manual re-implementations of `PartialEq::ne`.
+
+**Why is this bad?** `PartialEq::ne` is required to always return the
+negated result of `PartialEq::eq`, which is exactly what the default
+implementation does. Therefore, there should never be any need to
+re-implement it.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+struct Foo;
+
+impl PartialEq for Foo {
+   fn eq(&self, other: &Foo) -> bool { ... }
+   fn ne(&self, other: &Foo) -> bool { !(self == other) }
+}
+```"#,
+    },
+    LintInfo {
+        name: "possible_missing_comma",
+        module: "formatting",
+        level: "warn",
+        group: "clippy",
+        desc: "possible missing comma in array",
+        docs: r#"**What it does:** Checks for possible missing comma in an array. It lints if
+an array element is a binary operator expression and it lies on two lines.
+
+**Why is this bad?** This could lead to unexpected results.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+let a = &[
+    -1, -2, -3 // <= no comma here
+    -4, -5, -6
+];
+```"#,
+    },
+    LintInfo {
+        name: "precedence",
+        module: "precedence",
+        level: "warn",
+        group: "clippy",
+        desc: "operations where precedence may be unclear",
+        docs: r#"**What it does:** Checks for operations where precedence may be unclear
+and suggests to add parentheses. Currently it catches the following:
+* mixed usage of arithmetic and bit shifting/combining operators without parentheses
+* a "negative" numeric literal (which is really a unary `-` followed by a numeric literal)
+  followed by a method call
+
+**Why is this bad?** Not everyone knows the precedence of those operators by
+heart, so expressions like these may trip others trying to reason about the
+code.
+
+**Known problems:** None.
+
+**Example:**
+* `1 << 2 + 3` equals 32, while `(1 << 2) + 3` equals 7
+* `-1i32.abs()` equals -1, while `(-1i32).abs()` equals 1"#,
+    },
+    LintInfo {
+        name: "print_stdout",
+        module: "print",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "printing on stdout",
+        docs: r#"**What it does:** Checks for printing on *stdout*. The purpose of this lint
+is to catch debugging remnants.
+
+**Why is this bad?** People often print on *stdout* while debugging an
+application and might forget to remove those prints afterward.
+
+**Known problems:** Only catches `print!` and `println!` calls.
+
+**Example:**
+```rust
+println!("Hello world!");
+```"#,
+    },
+    LintInfo {
+        name: "print_with_newline",
+        module: "print",
+        level: "warn",
+        group: "clippy",
+        desc: "using `print!()` with a format string that ends in a newline",
+        docs: r#"**What it does:** This lint warns when you using `print!()` with a format string that
+ends in a newline.
+
+**Why is this bad?** You should use `println!()` instead, which appends the newline.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+print!("Hello {}!\n", name);
+```"#,
+    },
+    LintInfo {
+        name: "ptr_arg",
+        module: "ptr",
+        level: "warn",
+        group: "clippy",
+        desc: "fn arguments of the type `&Vec<...>` or `&String`, suggesting to use `&[...]` or `&str` instead, respectively",
+        docs: r#"**What it does:** This lint checks for function arguments of type `&String` or `&Vec` unless
+the references are mutable.
+
+**Why is this bad?** Requiring the argument to be of the specific size makes the function less
+useful for no benefit; slices in the form of `&[T]` or `&str` usually suffice and can be
+obtained from other types, too.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn foo(&Vec<u32>) { .. }
+```"#,
+    },
+    LintInfo {
+        name: "pub_enum_variant_names",
+        module: "enum_variants",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "enums where all variants share a prefix/postfix",
+        docs: r#"**What it does:** Detects enumeration variants that are prefixed or suffixed
+by the same characters.
+
+**Why is this bad?** Enumeration variant names should specify their variant,
+not repeat the enumeration name.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+enum Cake {
+    BlackForestCake,
+    HummingbirdCake,
+}
+```"#,
+    },
+    LintInfo {
+        name: "range_step_by_zero",
+        module: "ranges",
+        level: "warn",
+        group: "clippy",
+        desc: "using `Range::step_by(0)`, which produces an infinite iterator",
+        docs: r#"**What it does:** Checks for iterating over ranges with a `.step_by(0)`,
+which never terminates.
+
+**Why is this bad?** This very much looks like an oversight, since with
+`loop { .. }` there is an obvious better way to endlessly loop.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+for x in (5..5).step_by(0) { .. }
+```"#,
+    },
+    LintInfo {
+        name: "range_zip_with_len",
+        module: "ranges",
+        level: "warn",
+        group: "clippy",
+        desc: "zipping iterator with a range when `enumerate()` would do",
+        docs: r#"**What it does:** Checks for zipping a collection with the range of `0.._.len()`.
+
+**Why is this bad?** The code is better expressed with `.enumerate()`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x.iter().zip(0..x.len())
+```"#,
+    },
+    LintInfo {
+        name: "redundant_closure",
+        module: "eta_reduction",
+        level: "warn",
+        group: "clippy",
+        desc: "redundant closures, i.e. `|a| foo(a)` (which can be written as just `foo`)",
+        docs: r#"**What it does:** Checks for closures which just call another function where
+the function can be called directly. `unsafe` functions or calls where types
+get adjusted are ignored.
+
+**Why is this bad?** Needlessly creating a closure adds code for no benefit
+and gives the optimizer more work.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+xs.map(|x| foo(x))
+```
+where `foo(_)` is a plain function that takes the exact argument type of `x`."#,
+    },
+    LintInfo {
+        name: "redundant_closure_call",
+        module: "misc_early",
+        level: "warn",
+        group: "clippy",
+        desc: "throwaway closures called in the expression they are defined",
+        docs: r#"**What it does:** Detects closures called in the same expression where they are defined.
+
+**Why is this bad?** It is unnecessarily adding to the expression's complexity.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+(|| 42)()
+```"#,
+    },
+    LintInfo {
+        name: "redundant_pattern",
+        module: "misc",
+        level: "warn",
+        group: "clippy",
+        desc: "using `name @ _` in a pattern",
+        docs: r#"**What it does:** Checks for patterns in the form `name @ _`.
+
+**Why is this bad?** It's almost always more readable to just use direct bindings.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+match v {
+    Some(x) => (),
+    y @ _   => (), // easier written as `y`,
+}
+```"#,
+    },
+    LintInfo {
+        name: "regex_macro",
+        module: "regex",
+        level: "warn",
+        group: "clippy",
+        desc: "use of `regex!(_)` instead of `Regex::new(_)`",
+        docs: r#"**What it does:** Checks for usage of `regex!(_)` which (as of now) is
+usually slower than `Regex::new(_)` unless called in a loop (which is a bad
+idea anyway).
+
+**Why is this bad?** Performance, at least for now. The macro version is
+likely to catch up long-term, but for now the dynamic version is faster.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+regex!("foo|bar")
+```"#,
+    },
+    LintInfo {
+        name: "result_unwrap_used",
+        module: "methods",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using `Result.unwrap()`, which might be better handled",
+        docs: r#"**What it does:** Checks for `.unwrap()` calls on `Result`s.
+
+**Why is this bad?** `result.unwrap()` will let the thread panic on `Err`
+values. Normally, you want to implement more sophisticated error handling,
+and propagate errors upwards with `try!`.
+
+Even if you want to panic on errors, not all `Error`s implement good
+messages on display.  Therefore it may be beneficial to look at the places
+where they may get displayed. Activate this lint to do just that.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+x.unwrap()
+```"#,
+    },
+    LintInfo {
+        name: "reverse_range_loop",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "iteration over an empty range, such as `10..0` or `5..5`",
+        docs: r#"**What it does:** Checks for loops over ranges `x..y` where both `x` and `y`
+are constant and `x` is greater or equal to `y`, unless the range is
+reversed or has a negative `.step_by(_)`.
+
+**Why is it bad?** Such loops will either be skipped or loop until
+wrap-around (in debug code, this may `panic!()`). Both options are probably
+not intended.
+
+**Known problems:** The lint cannot catch loops over dynamically defined
+ranges. Doing this would require simulating all possible inputs and code
+paths through the program, which would be complex and error-prone.
+
+**Example:**
+```rust
+for x in 5..10-5 { .. } // oops, stray `-`
+```"#,
+    },
+    LintInfo {
+        name: "search_is_some",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using an iterator search followed by `is_some()`, which is more succinctly expressed as a call to `any()`",
+        docs: r#"**What it does:** Checks for an iterator search (such as `find()`,
+`position()`, or `rposition()`) followed by a call to `is_some()`.
+
+**Why is this bad?** Readability, this can be written more concisely as
+`_.any(_)`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+iter.find(|x| x == 0).is_some()
+```"#,
+    },
+    LintInfo {
+        name: "serde_api_misuse",
+        module: "serde",
+        level: "warn",
+        group: "clippy",
+        desc: "various things that will negatively affect your serde experience",
+        docs: r#"**What it does:** Checks for mis-uses of the serde API.
+
+**Why is this bad?** Serde is very finnicky about how its API should be
+used, but the type system can't be used to enforce it (yet).
+
+**Known problems:** None.
+
+**Example:** Implementing `Visitor::visit_string` but not `Visitor::visit_str`."#,
+    },
+    LintInfo {
+        name: "shadow_reuse",
+        module: "shadow",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "rebinding a name to an expression that re-uses the original value, e.g. `let x = x + 1`",
+        docs: r#"**What it does:** Checks for bindings that shadow other bindings already in
+scope, while reusing the original value.
+
+**Why is this bad?** Not too much, in fact it's a common pattern in Rust
+code. Still, some argue that name shadowing like this hurts readability,
+because a value may be bound to different things depending on position in
+the code.
+
+**Known problems:** This lint, as the other shadowing related lints,
+currently only catches very simple patterns.
+
+**Example:**
+```rust
+let x = x + 1;
+```"#,
+    },
+    LintInfo {
+        name: "shadow_same",
+        module: "shadow",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "rebinding a name to itself, e.g. `let mut x = &mut x`",
+        docs: r#"**What it does:** Checks for bindings that shadow other bindings already in
+scope, while just changing reference level or mutability.
+
+**Why is this bad?** Not much, in fact it's a very common pattern in Rust
+code. Still, some may opt to avoid it in their code base, they can set this
+lint to `Warn`.
+
+**Known problems:** This lint, as the other shadowing related lints,
+currently only catches very simple patterns.
+
+**Example:**
+```rust
+let x = &x;
+```"#,
+    },
+    LintInfo {
+        name: "shadow_unrelated",
+        module: "shadow",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "rebinding a name without even using the original value",
+        docs: r#"**What it does:** Checks for bindings that shadow other bindings already in
+scope, either without a initialization or with one that does not even use
+the original value.
+
+**Why is this bad?** Name shadowing can hurt readability, especially in
+large code bases, because it is easy to lose track of the active binding at
+any place in the code. This can be alleviated by either giving more specific
+names to bindings ore introducing more scopes to contain the bindings.
+
+**Known problems:** This lint, as the other shadowing related lints,
+currently only catches very simple patterns.
+
+**Example:**
+```rust
+let x = y; let x = z; // shadows the earlier binding
+```"#,
+    },
+    LintInfo {
+        name: "short_circuit_statement",
+        module: "misc",
+        level: "warn",
+        group: "clippy",
+        desc: "using a short circuit boolean condition as a statement",
+        docs: r#"**What it does:** Checks for the use of short circuit boolean conditions as a
+statement.
+
+**Why is this bad?** Using a short circuit boolean condition as a statement may
+hide the fact that the second part is executed or not depending on the outcome of
+the first part.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+f() && g();  // We should write `if f() { g(); }`.
+```"#,
+    },
+    LintInfo {
+        name: "should_assert_eq",
+        module: "should_assert_eq",
+        level: "warn",
+        group: "clippy",
+        desc: "using `assert` macro for asserting equality",
+        docs: r#"**What it does:** Checks for `assert!(x == y)` or `assert!(x != y)` which can be better written
+using `assert_eq` or `assert_ne` if `x` and `y` implement `Debug` trait.
+
+**Why is this bad?** `assert_eq` and `assert_ne` provide better assertion failure reporting.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```rust
+let (x, y) = (1, 2);
+
+assert!(x == y);  // assertion failed: x == y
+assert_eq!(x, y); // assertion failed: `(left == right)` (left: `1`, right: `2`)
+```"#,
+    },
+    LintInfo {
+        name: "should_implement_trait",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "defining a method that should be implementing a std trait",
+        docs: r#"**What it does:** Checks for methods that should live in a trait
+implementation of a `std` trait (see [llogiq's blog
+post](http://llogiq.github.io/2015/07/30/traits.html) for further
+information) instead of an inherent implementation.
+
+**Why is this bad?** Implementing the traits improve ergonomics for users of
+the code, often with very little cost. Also people seeing a `mul(...)` method
+may expect `*` to work equally, so you should have good reason to disappoint
+them.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+struct X;
+impl X {
+   fn add(&self, other: &X) -> X { .. }
+}
+```"#,
+    },
+    LintInfo {
+        name: "similar_names",
+        module: "non_expressive_names",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "similarly named items and bindings",
+        docs: r#"**What it does:** Checks for names that are very similar and thus confusing.
+
+**Why is this bad?** It's hard to distinguish between names that differ only
+by a single character.
+
+**Known problems:** None?
+
+**Example:**
+```rust
+let checked_exp = something;
+let checked_expr = something_else;
+```"#,
+    },
+    LintInfo {
+        name: "single_char_pattern",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using a single-character str where a char could be used, e.g. `_.split(\"x\")`",
+        docs: r#"**What it does:** Checks for string methods that receive a single-character
+`str` as an argument, e.g. `_.split("x")`.
+
+**Why is this bad?** Performing these methods using a `char` is faster than
+using a `str`.
+
+**Known problems:** Does not catch multi-byte unicode characters.
+
+**Example:**
+`_.split("x")` could be `_.split('x')"#,
+    },
+    LintInfo {
+        name: "single_match",
+        module: "matches",
+        level: "warn",
+        group: "clippy",
+        desc: "a match statement with a single nontrivial arm (i.e. where the other arm is `_ => {}`) instead of `if let`",
+        docs: r#"**What it does:** Checks for matches with a single arm where an `if let`
+will usually suffice.
+
+**Why is this bad?** Just readability – `if let` nests less than a `match`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+match x {
+    Some(ref foo) => bar(foo),
+    _ => ()
+}
+```"#,
+    },
+    LintInfo {
+        name: "single_match_else",
+        module: "matches",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "a match statement with a two arms where the second arm's pattern is a wildcard instead of `if let`",
+        docs: r#"**What it does:** Checks for matches with a two arms where an `if let` will
+usually suffice.
+
+**Why is this bad?** Just readability – `if let` nests less than a `match`.
+
+**Known problems:** Personal style preferences may differ.
+
+**Example:**
+```rust
+match x {
+    Some(ref foo) => bar(foo),
+    _ => bar(other_ref),
+}
+```"#,
+    },
+    LintInfo {
+        name: "str_to_string",
+        module: "deprecated_lints",
+        level: "deprecated",
+        group: "",
+        desc: "using `str::to_string` is common even today and specialization will likely happen soon",
+        docs: r#"**What it does:** Nothing. This lint has been deprecated.
+
+**Deprecation reason:** This used to check for `.to_string()` method calls on values
+of type `&str`. This is not unidiomatic and with specialization coming, `to_string` could be
+specialized to be as efficient as `to_owned`."#,
+    },
+    LintInfo {
+        name: "string_add",
+        module: "strings",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using `x + ..` where x is a `String` instead of `push_str()`",
+        docs: r#"**What it does:** Checks for all instances of `x + _` where `x` is of type
+`String`, but only if [`string_add_assign`](#string_add_assign) does *not*
+match.
+
+**Why is this bad?** It's not bad in and of itself. However, this particular
+`Add` implementation is asymmetric (the other operand need not be `String`,
+but `x` does), while addition as mathematically defined is symmetric, also
+the `String::push_str(_)` function is a perfectly good replacement.
+Therefore some dislike it and wish not to have it in their code.
+
+That said, other people think that string addition, having a long tradition
+in other languages is actually fine, which is why we decided to make this
+particular lint `allow` by default.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let x = "Hello".to_owned();
+x + ", World"
+```"#,
+    },
+    LintInfo {
+        name: "string_add_assign",
+        module: "strings",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using `x = x + ..` where x is a `String` instead of `push_str()`",
+        docs: r#"**What it does:** Checks for string appends of the form `x = x + y` (without
+`let`!).
+
+**Why is this bad?** It's not really bad, but some people think that the
+`.push_str(_)` method is more readable.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+let mut x = "Hello".to_owned();
+x = x + ", World";
+```"#,
+    },
+    LintInfo {
+        name: "string_extend_chars",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "using `x.extend(s.chars())` where s is a `&str` or `String`",
+        docs: r#"**What it does:** Checks for the use of `.extend(s.chars())` where s is a
+`&str` or `String`.
+
+**Why is this bad?** `.push_str(s)` is clearer
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let abc = "abc";
+let def = String::from("def");
+let mut s = String::new();
+s.extend(abc.chars());
+s.extend(def.chars());
+```
+The correct use would be:
+```rust
+let abc = "abc";
+let def = String::from("def");
+let mut s = String::new();
+s.push_str(abc);
+s.push_str(&def));
+```"#,
+    },
+    LintInfo {
+        name: "string_lit_as_bytes",
+        module: "strings",
+        level: "warn",
+        group: "clippy",
+        desc: "calling `as_bytes` on a string literal instead of using a byte string literal",
+        docs: r#"**What it does:** Checks for the `as_bytes` method called on string literals
+that contain only ASCII characters.
+
+**Why is this bad?** Byte string literals (e.g. `b"foo"`) can be used
+instead. They are shorter but less discoverable than `as_bytes()`.
+
+**Known Problems:** None.
+
+**Example:**
+```rust
+let bs = "a byte string".as_bytes();
+```"#,
+    },
+    LintInfo {
+        name: "string_to_string",
+        module: "deprecated_lints",
+        level: "deprecated",
+        group: "",
+        desc: "using `string::to_string` is common even today and specialization will likely happen soon",
+        docs: r#"**What it does:** Nothing. This lint has been deprecated.
+
+**Deprecation reason:** This used to check for `.to_string()` method calls on values
+of type `String`. This is not unidiomatic and with specialization coming, `to_string` could be
+specialized to be as efficient as `clone`."#,
+    },
+    LintInfo {
+        name: "stutter",
+        module: "enum_variants",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "type names prefixed/postfixed with their containing module's name",
+        docs: r#"**What it does:** Detects type names that are prefixed or suffixed by the
+containing module's name.
+
+**Why is this bad?** It requires the user to type the module name twice.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+mod cake {
+    struct BlackForestCake;
+}
+```"#,
+    },
+    LintInfo {
+        name: "suspicious_assignment_formatting",
+        module: "formatting",
+        level: "warn",
+        group: "clippy",
+        desc: "suspicious formatting of `*=`, `-=` or `!=`",
+        docs: r#"**What it does:** Checks for use of the non-existent `=*`, `=!` and `=-` operators.
+
+**Why is this bad?** This is either a typo of `*=`, `!=` or `-=` or confusing.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+a =- 42; // confusing, should it be `a -= 42` or `a = -42`?
+```"#,
+    },
+    LintInfo {
+        name: "suspicious_else_formatting",
+        module: "formatting",
+        level: "warn",
+        group: "clippy",
+        desc: "suspicious formatting of `else if`",
+        docs: r#"**What it does:** Checks for formatting of `else if`. It lints if the `else`
+and `if` are not on the same line or the `else` seems to be missing.
+
+**Why is this bad?** This is probably some refactoring remnant, even if the
+code is correct, it might look confusing.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+if foo {
+} if bar { // looks like an `else` is missing here
+}
+
+if foo {
+} else
+
+if bar { // this is the `else` block of the previous `if`, but should it be?
+}
+```"#,
+    },
+    LintInfo {
+        name: "temporary_assignment",
+        module: "temporary_assignment",
+        level: "warn",
+        group: "clippy",
+        desc: "assignments to temporaries",
+        docs: r#"**What it does:** Checks for construction of a structure or tuple just to
+assign a value in it.
+
+**Why is this bad?** Readability. If the structure is only created to be
+updated, why not write the structure you want in the first place?
+
+**Known problems:** None.
+
+**Example:**
+```rust
+(0, 0).0 = 1
+```"#,
+    },
+    LintInfo {
+        name: "temporary_cstring_as_ptr",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "getting the inner pointer of a temporary `CString`",
+        docs: r#"**What it does:** Checks for getting the inner pointer of a temporary `CString`.
+
+**Why is this bad?** The inner pointer of a `CString` is only valid as long
+as the `CString` is alive.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+let c_str = CString::new("foo").unwrap().as_ptr();
+unsafe {
+call_some_ffi_func(c_str);
+}
+```
+Here `c_str` point to a freed address. The correct use would be:
+```rust,ignore
+let c_str = CString::new("foo").unwrap();
+unsafe {
+    call_some_ffi_func(c_str.as_ptr());
+}
+```"#,
+    },
+    LintInfo {
+        name: "too_many_arguments",
+        module: "functions",
+        level: "warn",
+        group: "clippy",
+        desc: "functions with too many arguments",
+        docs: r#"**What it does:** Checks for functions with too many parameters.
+
+**Why is this bad?** Functions with lots of parameters are considered bad
+style and reduce readability (“what does the 5th parameter mean?”). Consider
+grouping some parameters into a new type.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn foo(x: u32, y: u32, name: &str, c: Color, w: f32, h: f32, a: f32, b: f32) { .. }
+```"#,
+    },
+    LintInfo {
+        name: "toplevel_ref_arg",
+        module: "misc",
+        level: "warn",
+        group: "clippy",
+        desc: "an entire binding declared as `ref`, in a function argument or a `let` statement",
+        docs: r#"**What it does:** Checks for function arguments and let bindings denoted as `ref`.
+
+**Why is this bad?** The `ref` declaration makes the function take an owned
+value, but turns the argument into a reference (which means that the value
+is destroyed when exiting the function). This adds not much value: either
+take a reference type, or take an owned value and create references in the
+body.
+
+For let bindings, `let x = &foo;` is preferred over `let ref x = foo`. The
+type of `x` is more obvious with the former.
+
+**Known problems:** If the argument is dereferenced within the function,
+removing the `ref` will lead to errors. This can be fixed by removing the
+dereferences, e.g. changing `*x` to `x` within the function.
+
+**Example:**
+```rust
+fn foo(ref x: u8) -> bool { .. }
+```"#,
+    },
+    LintInfo {
+        name: "transmute_ptr_to_ref",
+        module: "transmute",
+        level: "warn",
+        group: "clippy",
+        desc: "transmutes from a pointer to a reference type",
+        docs: r#"**What it does:** Checks for transmutes from a pointer to a reference.
+
+**Why is this bad?** This can always be rewritten with `&` and `*`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let _: &T = std::mem::transmute(p); // where p: *const T
+// can be written:
+let _: &T = &*p;
+```"#,
+    },
+    LintInfo {
+        name: "trivial_regex",
+        module: "regex",
+        level: "warn",
+        group: "clippy",
+        desc: "trivial regular expressions",
+        docs: r#"**What it does:** Checks for trivial [regex] creation (with `Regex::new`,
+`RegexBuilder::new` or `RegexSet::new`).
+
+[regex]: https://crates.io/crates/regex
+
+**Why is this bad?** Matching the regex can likely be replaced by `==` or
+`str::starts_with`, `str::ends_with` or `std::contains` or other `str`
+methods.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+Regex::new("^foobar")
+```"#,
+    },
+    LintInfo {
+        name: "type_complexity",
+        module: "types",
+        level: "warn",
+        group: "clippy",
+        desc: "usage of very complex types that might be better factored into `type` definitions",
+        docs: r#"**What it does:** Checks for types used in structs, parameters and `let`
+declarations above a certain complexity threshold.
+
+**Why is this bad?** Too complex types make the code less readable. Consider
+using a `type` definition to simplify them.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+struct Foo { inner: Rc<Vec<Vec<Box<(u32, u32, u32, u32)>>>> }
+```"#,
+    },
+    LintInfo {
+        name: "unicode_not_nfc",
+        module: "unicode",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using a unicode literal not in NFC normal form (see [unicode tr15](http://www.unicode.org/reports/tr15/) for further information)",
+        docs: r#"**What it does:** Checks for string literals that contain Unicode in a form
+that is not equal to its
+[NFC-recomposition](http://www.unicode.org/reports/tr15/#Norm_Forms).
+
+**Why is this bad?** If such a string is compared to another, the results
+may be surprising.
+
+**Known problems** None.
+
+**Example:** You may not see it, but “à” and “à” aren't the same string. The
+former when escaped is actually `"a\u{300}"` while the latter is `"\u{e0}"`."#,
+    },
+    LintInfo {
+        name: "unit_cmp",
+        module: "types",
+        level: "warn",
+        group: "clippy",
+        desc: "comparing unit values",
+        docs: r#"**What it does:** Checks for comparisons to unit.
+
+**Why is this bad?** Unit is always equal to itself, and thus is just a
+clumsily written constant. Mostly this happens when someone accidentally
+adds semicolons at the end of the operands.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+if { foo(); } == { bar(); } { baz(); }
+```
+is equal to
+```rust
+{ foo(); bar(); baz(); }
+```"#,
+    },
+    LintInfo {
+        name: "unnecessary_cast",
+        module: "types",
+        level: "warn",
+        group: "clippy",
+        desc: "cast to the same type, e.g. `x as i32` where `x: i32`",
+        docs: r#"**What it does:** Checks for casts to the same type.
+
+**Why is this bad?** It's just unnecessary.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let _ = 2i32 as i32
+```"#,
+    },
+    LintInfo {
+        name: "unnecessary_mut_passed",
+        module: "mut_reference",
+        level: "warn",
+        group: "clippy",
+        desc: "an argument passed as a mutable reference although the callee only demands an immutable reference",
+        docs: r#"**What it does:** Detects giving a mutable reference to a function that only
+requires an immutable reference.
+
+**Why is this bad?** The immutable reference rules out all other references
+to the value. Also the code misleads about the intent of the call site.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+my_vec.push(&mut value)
+```"#,
+    },
+    LintInfo {
+        name: "unnecessary_operation",
+        module: "no_effect",
+        level: "warn",
+        group: "clippy",
+        desc: "outer expressions with no effect",
+        docs: r#"**What it does:** Checks for expression statements that can be reduced to a
+sub-expression.
+
+**Why is this bad?** Expressions by themselves often have no side-effects.
+Having such expressions reduces readability.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+compute_array()[0];
+```"#,
+    },
+    LintInfo {
+        name: "unneeded_field_pattern",
+        module: "misc_early",
+        level: "warn",
+        group: "clippy",
+        desc: "struct fields bound to a wildcard instead of using `..`",
+        docs: r#"**What it does:** Checks for structure field patterns bound to wildcards.
+
+**Why is this bad?** Using `..` instead is shorter and leaves the focus on
+the fields that are actually bound.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let { a: _, b: ref b, c: _ } = ..
+```"#,
+    },
+    LintInfo {
+        name: "unreachable",
+        module: "panic",
+        level: "allow",
+        group: "clippy_restrictions",
+        desc: "usage of the `unreachable!` macro",
+        docs: r#"**What it does:** Checks for usage of `unreachable!`.
+
+**Why is this bad?** Like `panic!`, it panics at runtime when the
+supposedly unreachable code is reached.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+unreachable!();
+```"#,
+    },
+    LintInfo {
+        name: "unsafe_removed_from_name",
+        module: "unsafe_removed_from_name",
+        level: "warn",
+        group: "clippy",
+        desc: "`unsafe` removed from API names on import",
+        docs: r#"**What it does:** Checks for imports that remove "unsafe" from an item's
+name.
+
+**Why is this bad?** Renaming makes it less clear which traits and
+structures are unsafe.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+use std::cell::{UnsafeCell as TotallySafeCell};
+
+extern crate crossbeam;
+use crossbeam::{spawn_unsafe as spawn};
+```"#,
+    },
+    LintInfo {
+        name: "unseparated_literal_suffix",
+        module: "misc_early",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "literals whose suffix is not separated by an underscore",
+        docs: r#"**What it does:** Warns if literal suffixes are not separated by an underscore.
+
+**Why is this bad?** It is much less readable.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+let y = 123832i32;
+```"#,
+    },
+    LintInfo {
+        name: "unstable_as_mut_slice",
+        module: "deprecated_lints",
+        level: "deprecated",
+        group: "",
+        desc: "`Vec::as_mut_slice` has been stabilized in 1.7",
+        docs: r#"**What it does:** Nothing. This lint has been deprecated.
+
+**Deprecation reason:** This used to check for `Vec::as_mut_slice`, which was unstable with good
+stable alternatives. `Vec::as_mut_slice` has now been stabilized."#,
+    },
+    LintInfo {
+        name: "unstable_as_slice",
+        module: "deprecated_lints",
+        level: "deprecated",
+        group: "",
+        desc: "`Vec::as_slice` has been stabilized in 1.7",
+        docs: r#"**What it does:** Nothing. This lint has been deprecated.
+
+**Deprecation reason:** This used to check for `Vec::as_slice`, which was unstable with good
+stable alternatives. `Vec::as_slice` has now been stabilized."#,
+    },
+    LintInfo {
+        name: "unused_collect",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "`collect()`ing an iterator without using the result; this is usually better written as a for loop",
+        docs: r#"**What it does:** Checks for using `collect()` on an iterator without using
+the result.
+
+**Why is this bad?** It is more idiomatic to use a `for` loop over the
+iterator instead.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+vec.iter().map(|x| /* some operation returning () */).collect::<Vec<_>>();
+```"#,
+    },
+    LintInfo {
+        name: "unused_io_amount",
+        module: "unused_io_amount",
+        level: "deny",
+        group: "clippy",
+        desc: "unused written/read amount",
+        docs: r#"**What it does:** Checks for unused written/read amount.
+
+**Why is this bad?** `io::Write::write` and `io::Read::read` are not guaranteed to
+process the entire buffer. They return how many bytes were processed, which might be smaller
+than a given buffer's length. If you don't need to deal with partial-write/read, use
+`write_all`/`read_exact` instead.
+
+**Known problems:** Detects only common patterns.
+
+**Example:**
+```rust,ignore
+use std::io;
+fn foo<W: io::Write>(w: &mut W) -> io::Result<()> {
+    // must be `w.write_all(b"foo")?;`
+    w.write(b"foo")?;
+    Ok(())
+}
+```"#,
+    },
+    LintInfo {
+        name: "unused_label",
+        module: "unused_label",
+        level: "warn",
+        group: "clippy",
+        desc: "unused labels",
+        docs: r#"**What it does:** Checks for unused labels.
+
+**Why is this bad?** Maybe the label should be used in which case there is
+an error in the code or it should be removed.
+
+**Known problems:** Hopefully none.
+
+**Example:**
+```rust,ignore
+fn unused_label() {
+    'label: for i in 1..2 {
+        if i > 4 { continue }
+    }
+```"#,
+    },
+    LintInfo {
+        name: "unused_lifetimes",
+        module: "lifetimes",
+        level: "warn",
+        group: "clippy",
+        desc: "unused lifetimes in function definitions",
+        docs: r#"**What it does:** Checks for lifetimes in generics that are never used
+anywhere else.
+
+**Why is this bad?** The additional lifetimes make the code look more
+complicated, while there is nothing out of the ordinary going on. Removing
+them leads to more readable code.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+fn unused_lifetime<'a>(x: u8) { .. }
+```"#,
+    },
+    LintInfo {
+        name: "unused_suppression",
+        module: "suppression",
+        level: "warn",
+        group: "clippy",
+        desc: "a `// clippy: allow(..)` comment that does not suppress any lint",
+        docs: r#"**What it does:** Checks for `// clippy: allow(lint_name)` comments that do not suppress
+anything, because the lint is not emitted on the statement or item that follows the comment,
+because nothing follows it, or because the comment is malformed.
+
+A suppression comment allows Clippy lints on the next statement or item, where attributes
+cannot always be used. It may give a justification for audits, which is part of the output of
+`cargo clippy --message-format=json`:
+
+```rust
+// clippy: allow(needless_range_loop) reason="`i` is also used to index `other`"
+for i in 0..vec.len() { .. }
+```
+
+**Why is this bad?** An unused suppression is noise, and it would hide the lint if the code it
+is about came back.
+
+**Known problems:** Only Clippy's lints can be suppressed, not rustc's. A lint that is allowed
+where the comment is is never emitted, so suppressing it is reported as unused.
+
+**Example:**
+```rust
+// clippy: allow(needless_range_loop)
+let x = 42;
+```"#,
+    },
+    LintInfo {
+        name: "use_debug",
+        module: "print",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "use of `Debug`-based formatting",
+        docs: r#"**What it does:** Checks for use of `Debug` formatting. The purpose of this
+lint is to catch debugging remnants.
+
+**Why is this bad?** The purpose of the `Debug` trait is to facilitate
+debugging Rust code. It should not be used in in user-facing output.
+
+**Example:**
+```rust
+println!("{:?}", foo);
+```"#,
+    },
+    LintInfo {
+        name: "used_underscore_binding",
+        module: "misc",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "using a binding which is prefixed with an underscore",
+        docs: r#"**What it does:** Checks for the use of bindings with a single leading underscore.
+
+**Why is this bad?** A single leading underscore is usually used to indicate
+that a binding will not be used. Using such a binding breaks this
+expectation.
+
+**Known problems:** The lint does not work properly with desugaring and
+macro, it has been allowed in the mean time.
+
+**Example:**
+```rust
+let _x = 0;
+let y = _x + 1; // Here we are using `_x`, even though it has a leading underscore.
+                // We should rename `_x` to `x`
+```"#,
+    },
+    LintInfo {
+        name: "useless_attribute",
+        module: "attrs",
+        level: "warn",
+        group: "clippy",
+        desc: "use of lint attributes on `extern crate` items",
+        docs: r#"**What it does:** Checks for `extern crate` and `use` items annotated with lint attributes
+
+**Why is this bad?** Lint attributes have no effect on crate imports. Most likely a `!` was
+forgotten
+
+**Known problems:** Technically one might allow `unused_import` on a `use` item,
+but it's easier to remove the unused item.
+
+**Example:**
+```rust
+#[deny(dead_code)]
+extern crate foo;
+#[allow(unused_import)]
+use foo::bar;
+```"#,
+    },
+    LintInfo {
+        name: "useless_format",
+        module: "format",
+        level: "warn",
+        group: "clippy",
+        desc: "useless use of `format!`",
+        docs: r#"**What it does:** Checks for the use of `format!("string literal with no
+argument")` and `format!("{}", foo)` where `foo` is a string.
+
+**Why is this bad?** There is no point of doing that. `format!("too")` can
+be replaced by `"foo".to_owned()` if you really need a `String`. The even
+worse `&format!("foo")` is often encountered in the wild. `format!("{}",
+foo)` can be replaced by `foo.clone()` if `foo: String` or `foo.to_owned()`
+if `foo: &str`.
+
+**Known problems:** None.
+
+**Examples:**
+```rust
+format!("foo")
+format!("{}", foo)
+```"#,
+    },
+    LintInfo {
+        name: "useless_let_if_seq",
+        module: "let_if_seq",
+        level: "warn",
+        group: "clippy",
+        desc: "unidiomatic `let mut` declaration followed by initialization in `if`",
+        docs: r#"**What it does:** Checks for variable declarations immediately followed by a
+conditional affectation.
+
+**Why is this bad?** This is not idiomatic Rust.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+let foo;
+
+if bar() {
+    foo = 42;
+} else {
+    foo = 0;
+}
+
+let mut baz = None;
+
+if bar() {
+    baz = Some(42);
+}
+```
+
+should be written
+
+```rust,ignore
+let foo = if bar() {
+    42
+} else {
+    0
+};
+
+let baz = if bar() {
+    Some(42)
+} else {
+    None
+};
+```"#,
+    },
+    LintInfo {
+        name: "useless_transmute",
+        module: "transmute",
+        level: "warn",
+        group: "clippy",
+        desc: "transmutes that have the same to and from types or could be a cast/coercion",
+        docs: r#"**What it does:** Checks for transmutes to the original type of the object
+and transmutes that could be a cast.
+
+**Why is this bad?** Readability. The code tricks people into thinking that
+something complex is going on.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+core::intrinsics::transmute(t) // where the result type is the same as `t`'s
+```"#,
+    },
+    LintInfo {
+        name: "useless_vec",
+        module: "vec",
+        level: "warn",
+        group: "clippy",
+        desc: "useless `vec!`",
+        docs: r#"**What it does:** Checks for usage of `&vec![..]` when using `&[..]` would
+be possible.
+
+**Why is this bad?** This is less efficient.
+
+**Known problems:** None.
+
+**Example:**
+```rust,ignore
+foo(&vec![1, 2])
+```"#,
+    },
+    LintInfo {
+        name: "while_let_loop",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "`loop { if let { ... } else break }`, which can be written as a `while let` loop",
+        docs: r#"**What it does:** Detects `loop + match` combinations that are easier
+written as a `while let` loop.
+
+**Why is this bad?** The `while let` loop is usually shorter and more readable.
+
+**Known problems:** Sometimes the wrong binding is displayed (#383).
+
+**Example:**
+```rust
+loop {
+    let x = match y {
+        Some(x) => x,
+        None => break,
+    }
+    // .. do something with x
+}
+// is easier written as
+while let Some(x) = y {
+    // .. do something with x
+}
+```"#,
+    },
+    LintInfo {
+        name: "while_let_on_iterator",
+        module: "loops",
+        level: "warn",
+        group: "clippy",
+        desc: "using a while-let loop instead of a for loop on an iterator",
+        docs: r#"**What it does:** Checks for `while let` expressions on iterators.
+
+**Why is this bad?** Readability. A simple `for` loop is shorter and conveys
+the intent better.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+while let Some(val) = iter() { .. }
+```"#,
+    },
+    LintInfo {
+        name: "wrong_pub_self_convention",
+        module: "methods",
+        level: "allow",
+        group: "clippy_pedantic",
+        desc: "defining a public method named with an established prefix (like \"into_\") that takes `self` with the wrong convention",
+        docs: r#"**What it does:** This is the same as
+[`wrong_self_convention`](#wrong_self_convention), but for public items.
+
+**Why is this bad?** See [`wrong_self_convention`](#wrong_self_convention).
+
+**Known problems:** Actually *renaming* the function may break clients if
+the function is part of the public interface. In that case, be mindful of
+the stability guarantees you've given your users.
+
+**Example:**
+```rust
+impl X {
+    pub fn as_str(self) -> &str { .. }
+}
+```"#,
+    },
+    LintInfo {
+        name: "wrong_self_convention",
+        module: "methods",
+        level: "warn",
+        group: "clippy",
+        desc: "defining a method named with an established prefix (like \"into_\") that takes `self` with the wrong convention",
+        docs: r#"**What it does:** Checks for methods with certain name prefixes and which
+doesn't match how self is taken. The actual rules are:
+
+|Prefix |`self` taken          |
+|-------|----------------------|
+|`as_`  |`&self` or `&mut self`|
+|`from_`| none                 |
+|`into_`|`self`                |
+|`is_`  |`&self` or none       |
+|`to_`  |`&self`               |
+
+**Why is this bad?** Consistency breeds readability. If you follow the
+conventions, your users won't be surprised that they, e.g., need to supply a
+mutable reference to a `as_..` function.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+impl X {
+    fn as_str(self) -> &str { .. }
+}
+```"#,
+    },
+    LintInfo {
+        name: "wrong_transmute",
+        module: "transmute",
+        level: "warn",
+        group: "clippy",
+        desc: "transmutes that are confusing at best, undefined behaviour at worst and always useless",
+        docs: r#"**What it does:** Checks for transmutes that can't ever be correct on any
+architecture.
+
+**Why is this bad?** It's basically guaranteed to be undefined behaviour.
+
+**Known problems:** When accessing C, users might want to store pointer
+sized objects in `extradata` arguments to save an allocation.
+
+**Example:**
+```rust
+let ptr: *const T = core::intrinsics::transmute('x')`
+```"#,
+    },
+    LintInfo {
+        name: "zero_divided_by_zero",
+        module: "zero_div_zero",
+        level: "warn",
+        group: "clippy",
+        desc: "usage of `0.0 / 0.0` to obtain NaN instead of std::f32::NaN or std::f64::NaN",
+        docs: r#"**What it does:** Checks for `0.0 / 0.0`.
+
+**Why is this bad?** It's less readable than `std::f32::NAN` or `std::f64::NAN`.
+
+**Known problems:** None.
+
+**Example:**
+```rust
+0.0f32 / 0.0
+```"#,
+    },
+    LintInfo {
+        name: "zero_prefixed_literal",
+        module: "misc_early",
+        level: "warn",
+        group: "clippy",
+        desc: "integer literals starting with `0`",
+        docs: r#"**What it does:** Warns if an integral constant literal starts with `0`.
+
+**Why is this bad?** In some languages (including the infamous C language and most of its
+familly), this marks an octal constant. In Rust however, this is a decimal constant. This could
+be confusing for both the writer and a reader of the constant.
+
+**Known problems:** None.
+
+**Example:**
+
+In Rust:
+```rust
+fn main() {
+    let a = 0123;
+    println!("{}", a);
+}
+```
+
+prints `123`, while in C:
+
+```c
+#include <stdio.h>
+
+int main() {
+    int a = 0123;
+    printf("%d\n", a);
+}
+```
+
+prints `83` (as `83 == 0o123` while `123 == 0o173`)."#,
+    },
+    LintInfo {
+        name: "zero_ptr",
+        module: "misc",
+        level: "warn",
+        group: "clippy",
+        desc: "using 0 as *{const, mut} T",
+        docs: r#"**What it does:** Catch casts from `0` to some pointer type
+
+**Why is this bad?** This generally means `null` and is better expressed as
+{`std`, `core`}`::ptr::`{`null`, `null_mut`}.
+
+**Known problems:** None.
+
+**Example:**
+
+```rust
+0 as *const u32
+```"#,
+    },
+    LintInfo {
+        name: "zero_width_space",
+        module: "unicode",
+        level: "deny",
+        group: "clippy",
+        desc: "using a zero-width space in a string literal, which is confusing",
+        docs: r#"**What it does:** Checks for the Unicode zero-width space in the code.
+
+**Why is this bad?** Having an invisible character in the code makes for all
+sorts of April fools, but otherwise is very much frowned upon.
+
+**Known problems:** None.
+
+**Example:** You don't see it, but there may be a zero-width space somewhere in this text."#,
+    },
+    // end lint list, do not remove this comment, it’s used in `update_lints`
+];
+
+/// The lint groups of Clippy, the groups made from the default levels of the lints first, then the
+/// presets.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+pub static GROUPS: &[GroupInfo] = &[
+    // begin group list, do not remove this comment, it’s used in `update_lints`
+    GroupInfo {
+        name: "clippy",
+        desc: "the lints that warn or deny by default",
+        lints: &[
+            "absurd_extreme_comparisons",
+            "almost_swapped",
+            "approx_constant",
+            "assign_op_pattern",
+            "bad_bit_mask",
+            "blacklisted_name",
+            "block_in_if_condition_expr",
+            "block_in_if_condition_stmt",
+            "bool_comparison",
+            "box_vec",
+            "boxed_local",
+            "builtin_type_shadow",
+            "char_lit_as_u8",
+            "chars_next_cmp",
+            "clone_double_ref",
+            "clone_on_copy",
+            "cmp_nan",
+            "cmp_null",
+            "cmp_owned",
+            "collapsible_if",
+            "crosspointer_transmute",
+            "cyclomatic_complexity",
+            "deprecated_semver",
+            "deref_addrof",
+            "derive_hash_xor_eq",
+            "diverging_sub_expression",
+            "doc_markdown",
+            "double_neg",
+            "double_parens",
+            "drop_copy",
+            "drop_ref",
+            "duplicate_underscore_argument",
+            "empty_loop",
+            "enum_clike_unportable_variant",
+            "enum_variant_names",
+            "eq_op",
+            "eval_order_dependence",
+            "expl_impl_clone_on_copy",
+            "explicit_counter_loop",
+            "explicit_into_iter_loop",
+            "explicit_iter_loop",
+            "filter_next",
+            "float_cmp",
+            "for_kv_map",
+            "for_loop_over_option",
+            "for_loop_over_result",
+            "forget_copy",
+            "forget_ref",
+            "get_unwrap",
+            "identity_op",
+            "if_let_redundant_pattern_matching",
+            "if_let_some_result",
+            "if_same_then_else",
+            "ifs_same_cond",
+            "ineffective_bit_mask",
+            "inline_always",
+            "invalid_regex",
+            "iter_cloned_collect",
+            "iter_next_loop",
+            "iter_nth",
+            "iter_skip_next",
+            "large_enum_variant",
+            "len_without_is_empty",
+            "len_zero",
+            "let_and_return",
+            "let_unit_value",
+            "linkedlist",
+            "logic_bug",
+            "manual_swap",
+            "many_single_char_names",
+            "map_clone",
+            "map_entry",
+            "match_bool",
+            "match_overlapping_arm",
+            "match_ref_pats",
+            "match_same_arms",
+            "match_wild_err_arm",
+            "min_max",
+            "misrefactored_assign_op",
+            "mixed_case_hex_literals",
+            "module_inception",
+            "modulo_one",
+            "mut_from_ref",
+            "mutex_atomic",
+            "needless_bool",
+            "needless_borrow",
+            "needless_lifetimes",
+            "needless_pass_by_value",
+            "needless_range_loop",
+            "needless_return",
+            "needless_update",
+            "neg_multiply",
+            "never_loop",
+            "new_ret_no_self",
+            "new_without_default",
+            "new_without_default_derive",
+            "no_effect",
+            "nonsensical_open_options",
+            "not_unsafe_ptr_arg_deref",
+            "ok_expect",
+            "op_ref",
+            "or_fun_call",
+            "out_of_bounds_indexing",
+            "overflow_check_conditional",
+            "panic_params",
+            "partialeq_ne_impl",
+            "possible_missing_comma",
+            "precedence",
+            "print_with_newline",
+            "ptr_arg",
+            "range_step_by_zero",
+            "range_zip_with_len",
+            "redundant_closure",
+            "redundant_closure_call",
+            "redundant_pattern",
+            "regex_macro",
+            "reverse_range_loop",
+            "search_is_some",
+            "serde_api_misuse",
+            "short_circuit_statement",
+            "should_assert_eq",
+            "should_implement_trait",
+            "single_char_pattern",
+            "single_match",
+            "string_extend_chars",
+            "string_lit_as_bytes",
+            "suspicious_assignment_formatting",
+            "suspicious_else_formatting",
+            "temporary_assignment",
+            "temporary_cstring_as_ptr",
+            "too_many_arguments",
+            "toplevel_ref_arg",
+            "transmute_ptr_to_ref",
+            "trivial_regex",
+            "type_complexity",
+            "unit_cmp",
+            "unnecessary_cast",
+            "unnecessary_mut_passed",
+            "unnecessary_operation",
+            "unneeded_field_pattern",
+            "unsafe_removed_from_name",
+            "unused_collect",
+            "unused_io_amount",
+            "unused_label",
+            "unused_lifetimes",
+            "unused_suppression",
+            "useless_attribute",
+            "useless_format",
+            "useless_let_if_seq",
+            "useless_transmute",
+            "useless_vec",
+            "while_let_loop",
+            "while_let_on_iterator",
+            "wrong_self_convention",
+            "wrong_transmute",
+            "zero_divided_by_zero",
+            "zero_prefixed_literal",
+            "zero_ptr",
+            "zero_width_space",
+        ],
+    },
+    GroupInfo {
+        name: "clippy_pedantic",
+        desc: "stricter lints that are allowed by default, as they have some false positives",
+        lints: &[
+            "cast_possible_truncation",
+            "cast_possible_wrap",
+            "cast_precision_loss",
+            "cast_sign_loss",
+            "empty_enum",
+            "enum_glob_use",
+            "filter_map",
+            "if_not_else",
+            "invalid_upcast_comparisons",
+            "items_after_statements",
+            "mem_forget",
+            "missing_docs_in_private_items",
+            "mut_mut",
+            "mutex_integer",
+            "non_ascii_literal",
+            "nonminimal_bool",
+            "option_map_unwrap_or",
+            "option_map_unwrap_or_else",
+            "option_unwrap_used",
+            "print_stdout",
+            "pub_enum_variant_names",
+            "result_unwrap_used",
+            "shadow_reuse",
+            "shadow_same",
+            "shadow_unrelated",
+            "similar_names",
+            "single_match_else",
+            "string_add",
+            "string_add_assign",
+            "stutter",
+            "unicode_not_nfc",
+            "unseparated_literal_suffix",
+            "use_debug",
+            "used_underscore_binding",
+            "wrong_pub_self_convention",
+        ],
+    },
+    GroupInfo {
+        name: "clippy_restrictions",
+        desc: "lints that forbid some language features, allowed by default",
+        lints: &[
+            "allow_attributes_without_reason",
+            "assign_ops",
+            "exit",
+            "expect_used",
+            "float_arithmetic",
+            "heap_allocation",
+            "indexing_slicing",
+            "integer_arithmetic",
+            "panic",
+            "unreachable",
+        ],
+    },
+    GroupInfo {
+        name: "clippy_no_panic",
+        desc: "code that may panic at runtime, for code that must report all its errors",
+        lints: &[
+            "expect_used",
+            "indexing_slicing",
+            "option_unwrap_used",
+            "out_of_bounds_indexing",
+            "panic",
+            "result_unwrap_used",
+            "unreachable",
+        ],
+    },
+    GroupInfo {
+        name: "clippy_embedded",
+        desc: "features that are slow or unavailable on small targets without an FPU, a heap or standard output",
+        lints: &[
+            "float_arithmetic",
+            "heap_allocation",
+            "print_stdout",
+            "use_debug",
+        ],
+    },
+    GroupInfo {
+        name: "clippy_library",
+        desc: "Printing, exiting and missing docs, which a library should leave to its users or avoid, and public API naming: repetitive variant and item names, `self` conventions, `Debug` output",
+        lints: &[
+            "exit",
+            "missing_docs_in_private_items",
+            "print_stdout",
+            "pub_enum_variant_names",
+            "stutter",
+            "use_debug",
+            "wrong_pub_self_convention",
+        ],
+    },
+    // end group list, do not remove this comment, it’s used in `update_lints`
+];
@@ -1,10 +1,13 @@
-//! Read configurations files.
+//! Read configurations files, and the `[package.metadata.clippy]` and
+//! `[workspace.metadata.clippy]` tables of `Cargo.toml`.
 
 #![deny(missing_docs_in_private_items)]
 
+use rustc::lint::Level;
 use std::{env, fmt, fs, io, path};
 use std::io::Read;
 use syntax::{ast, codemap};
+use syntax::util::lev_distance::lev_distance;
 use toml;
 
 /// Get the configuration file from arguments.
@@ -35,7 +38,7 @@ pub enum Error {
     /// An I/O error.
     Io(io::Error),
     /// The file is not valid TOML.
-    Toml(Vec<toml::ParserError>),
+    Toml(toml::ParserError),
     /// Type error.
     Type(/// The name of the key.
          &'static str,
@@ -45,33 +48,172 @@ pub enum Error {
          &'static str),
     /// There is an unknown key is the file.
     UnknownKey(String),
+    /// A lint of the `[lints]` table has an invalid level.
+    LintLevel(/// The name of the lint.
+              String,
+              /// The invalid level.
+              String),
+    /// A name of the `[lints]` table is neither a Clippy lint nor a Clippy lint group.
+    UnknownLint(String),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match *self {
             Error::Io(ref err) => err.fmt(f),
-            Error::Toml(ref errs) => {
-                let mut first = true;
-                for err in errs {
-                    if !first {
-                        try!(", ".fmt(f));
-                        first = false;
-                    }
-
-                    try!(err.fmt(f));
-                }
-
-                Ok(())
-            },
+            Error::Toml(ref err) => err.fmt(f),
             Error::Type(key, expected, got) => {
                 write!(f, "`{}` is expected to be a `{}` but is a `{}`", key, expected, got)
             },
             Error::UnknownKey(ref key) => write!(f, "unknown key `{}`", key),
+            Error::LintLevel(ref lint, ref level) => {
+                write!(f,
+                       "invalid level `{}` for `{}`, expected `allow`, `warn`, `deny` or `forbid`",
+                       level,
+                       lint)
+            },
+            Error::UnknownLint(ref lint) => write!(f, "unknown lint `{}` in `[lints]`", lint),
+        }
+    }
+}
+
+impl Error {
+    /// The table and key of the configuration file this error is about, if any. The table is empty
+    /// for keys at the top level.
+    fn key(&self) -> Option<(&str, &str)> {
+        match *self {
+            Error::Io(_) |
+            Error::Toml(_) => None,
+            Error::Type(key, _, _) => Some(("", key)),
+            Error::UnknownKey(ref key) => Some(("", key)),
+            Error::LintLevel(ref lint, _) |
+            Error::UnknownLint(ref lint) => Some(("lints", lint)),
+        }
+    }
+}
+
+/// An error in a configuration file, with where it is.
+#[derive(Debug)]
+pub struct FileError {
+    /// The configuration file.
+    pub path: path::PathBuf,
+    /// The line and column of the error in the file, starting at 1, when they are known.
+    pub position: Option<(usize, usize)>,
+    /// The error itself.
+    pub error: Error,
+}
+
+impl FileError {
+    /// An error of the configuration file at `path`, whose content is `source`.
+    fn new(path: &path::Path, source: &str, error: Error) -> FileError {
+        FileError::in_table(path, source, "", error)
+    }
+
+    /// An error of the configuration in the table `prefix` of the file at `path`, like
+    /// `package.metadata.clippy` in a `Cargo.toml`.
+    fn in_table(path: &path::Path, source: &str, prefix: &str, error: Error) -> FileError {
+        let offset = match error {
+            Error::Toml(ref err) => Some(err.lo),
+            _ => {
+                error.key().and_then(|(table, key)| match (prefix.is_empty(), table.is_empty()) {
+                    (true, _) => key_offset(source, table, key),
+                    (false, true) => key_offset(source, prefix, key),
+                    (false, false) => key_offset(source, &format!("{}.{}", prefix, table), key),
+                })
+            },
+        };
+        FileError {
+            path: path.to_path_buf(),
+            position: offset.map(|offset| line_col(source, offset)),
+            error: error,
+        }
+    }
+
+    /// An error of the configuration file at `path` with no position, like an I/O error.
+    fn without_position(path: &path::Path, error: Error) -> FileError {
+        FileError {
+            path: path.to_path_buf(),
+            position: None,
+            error: error,
+        }
+    }
+
+    /// The location of the error, as `path:line:column`.
+    pub fn location(&self) -> String {
+        match self.position {
+            Some((line, col)) => format!("{}:{}:{}", self.path.display(), line, col),
+            None => self.path.display().to_string(),
+        }
+    }
+
+    /// The valid key closest to the unknown key of this error, if any.
+    pub fn suggestion(&self) -> Option<&'static str> {
+        if let Error::UnknownKey(ref key) = self.error {
+            similar_name(key, KEYS.iter().cloned())
+        } else {
+            None
         }
     }
 }
 
+/// The error for the name `lint` of the `[lints]` table, which is neither a Clippy lint nor a
+/// Clippy lint group, located in the last of the configuration `files` that sets its level.
+pub fn unknown_lint(files: &[path::PathBuf], lint: &str) -> FileError {
+    for path in files.iter().rev() {
+        let mut source = String::new();
+        if fs::File::open(path).and_then(|mut file| file.read_to_string(&mut source)).is_err() {
+            continue;
+        }
+        let tables: &[&str] = if path.ends_with("Cargo.toml") {
+            &[PACKAGE_TABLE, WORKSPACE_TABLE]
+        } else {
+            &[""]
+        };
+        for table in tables {
+            let error = FileError::in_table(path, &source, table, Error::UnknownLint(lint.to_owned()));
+            if error.position.is_some() {
+                return error;
+            }
+        }
+    }
+    // the levels are only read from configuration files, so there is always one
+    let path = files.last().map_or(path::Path::new(CONFIG_FILE_NAMES[1]), |path| path.as_path());
+    FileError::without_position(path, Error::UnknownLint(lint.to_owned()))
+}
+
+/// The byte offset of `key` in the configuration `source`, either in the `[table]` table or at
+/// the top level if `table` is empty. A key that is itself a table points to its header.
+fn key_offset(source: &str, table: &str, key: &str) -> Option<usize> {
+    let mut offset = 0;
+    let mut current_table = "";
+    for line in source.split('\n') {
+        let trimmed = line.trim_left();
+        let indent = line.len() - trimmed.len();
+        if trimmed.starts_with('[') {
+            current_table = trimmed.trim_left_matches('[').split(']').next().unwrap_or("").trim();
+            let header = current_table.split('.').next().unwrap_or("").trim();
+            if table.is_empty() && header.trim_matches('"') == key {
+                return Some(offset + indent);
+            }
+        } else if current_table == table && trimmed.contains('=') {
+            let name = trimmed.split('=').next().unwrap_or("").trim().trim_matches('"');
+            if name == key {
+                return Some(offset + indent);
+            }
+        }
+        // `split` removed the newline
+        offset += line.len() + 1;
+    }
+    None
+}
+
+/// The line and column, starting at 1, of the byte `offset` of `source`.
+fn line_col(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
 impl From<io::Error> for Error {
     fn from(e: io::Error) -> Self {
         Error::Io(e)
@@ -83,17 +225,39 @@ macro_rules! define_Conf {
         /// Type used to store lint configuration.
         pub struct Conf {
             $(#[$doc] pub $rust_name: define_Conf!(TY $($ty)+),)+
+            /// The level of lints and lint groups, from the `[lints]` table.
+            pub lints: Vec<(String, Level)>,
+            /// The configuration files that were read, the inherited ones first.
+            pub files: Vec<path::PathBuf>,
         }
 
+        /// The keys a configuration file can have.
+        pub const KEYS: &[&str] = &[$(define_Conf!(EXPR $toml_name),)+ "inherit", "lints", "third-party"];
+
         impl Default for Conf {
             fn default() -> Conf {
                 Conf {
                     $($rust_name: define_Conf!(DEFAULT $($ty)+, $default),)+
+                    lints: Vec::new(),
+                    files: Vec::new(),
                 }
             }
         }
 
         impl Conf {
+            /// The values of the configuration, with their defaults.
+            #[allow(cast_possible_wrap)]
+            pub fn fields(&self) -> Vec<Field> {
+                vec![$(
+                    Field {
+                        name: define_Conf!(EXPR $toml_name),
+                        lints: lints_of(stringify!($doc)),
+                        value: define_Conf!(TOML $($ty)+, self.$rust_name.clone()),
+                        default: define_Conf!(TOML $($ty)+, define_Conf!(DEFAULT $($ty)+, $default)),
+                    },
+                )+]
+            }
+
             /// Set the property `name` (which must be the `toml` name) to the given value
             #[allow(cast_sign_loss)]
             fn set(&mut self, name: String, value: toml::Value) -> Result<(), Error> {
@@ -101,7 +265,7 @@ macro_rules! define_Conf {
                     $(
                         define_Conf!(PAT $toml_name) => {
                             if let Some(value) = define_Conf!(CONV $($ty)+, value) {
-                                self.$rust_name = value;
+                                self.$rust_name = define_Conf!(MERGE $($ty)+, self.$rust_name, value);
                             }
                             else {
                                 return Err(Error::Type(define_Conf!(EXPR $toml_name),
@@ -114,6 +278,14 @@ macro_rules! define_Conf {
                         // for external tools such as clippy-service
                         return Ok(());
                     }
+                    "inherit" => {
+                        // handled by `read_chain`
+                        return Ok(());
+                    }
+                    "lints" => {
+                        // the levels of a configuration file override those it inherits
+                        self.lints.extend(try!(lint_levels(value)));
+                    }
                     _ => {
                         return Err(Error::UnknownKey(name));
                     }
@@ -152,6 +324,18 @@ macro_rules! define_Conf {
         }
     }};
 
+    // how to show the value?
+    (TOML Vec<String>, $value: expr) => {{
+        let value: Vec<String> = $value;
+        toml::Value::Array(value.into_iter().map(toml::Value::String).collect())
+    }};
+    (TOML String, $value: expr) => { toml::Value::String($value) };
+    (TOML $ty: ty, $value: expr) => { toml::Value::Integer($value as i64) };
+
+    // how to combine the value of a configuration file with the inherited one?
+    (MERGE Vec<String>, $inherited: expr, $value: expr) => { extend(&$inherited, $value) };
+    (MERGE $ty: ty, $inherited: expr, $value: expr) => { $value };
+
     // provide a nicer syntax to declare the default value of `Vec<String>` variables
     (DEFAULT Vec<String>, $e: expr) => { $e.iter().map(|&e| e.to_owned()).collect() };
     (DEFAULT $ty: ty, $e: expr) => { $e };
@@ -192,15 +376,114 @@ define_Conf! {
     ("enum-variant-size-threshold", enum_variant_size_threshold, 200 => u64),
 }
 
-/// Search for the configuration file.
+/// A value of the configuration, as shown by `cargo clippy --print-config`.
+#[derive(Debug)]
+pub struct Field {
+    /// The key of the value in the configuration file.
+    pub name: &'static str,
+    /// The lints that use the value.
+    pub lints: Vec<String>,
+    /// The value in effect.
+    pub value: toml::Value,
+    /// The value used without configuration file.
+    pub default: toml::Value,
+}
+
+/// The lints named in the documentation of a configuration value, which starts with
+/// `Lint: LINT_NAME.` like in `/// Lint: BLACKLISTED_NAME. The list of blacklisted names`.
+fn lints_of(doc: &str) -> Vec<String> {
+    doc.find("Lint: ")
+        .map_or("", |start| &doc[start + "Lint: ".len()..])
+        .split('.')
+        .next()
+        .unwrap_or("")
+        .split(',')
+        .map(|lint| lint.trim().to_lowercase())
+        .filter(|lint| !lint.is_empty())
+        .collect()
+}
+
+/// Read the `[lints]` table, which maps lint and lint group names to a level.
+fn lint_levels(value: toml::Value) -> Result<Vec<(String, Level)>, Error> {
+    let table = if let toml::Value::Table(table) = value {
+        table
+    } else {
+        return Err(Error::Type("lints", "table", value.type_str()));
+    };
+
+    let mut lints = Vec::new();
+    for (lint, level) in table {
+        match level.as_str().and_then(Level::from_str) {
+            Some(level) => lints.push((lint, level)),
+            None => {
+                let level = level.as_str().map_or_else(|| level.to_string(), Into::into);
+                return Err(Error::LintLevel(lint, level));
+            },
+        }
+    }
+    Ok(lints)
+}
+
+/// The element of a list that stands for the inherited list, as in `blacklisted-names = ["..", "toto"]`.
+const INHERITED: &str = "..";
+
+/// Replace the `".."` elements of `value` with the inherited list, which is the default one unless
+/// the configuration file inherits another one.
+fn extend(inherited: &[String], value: Vec<String>) -> Vec<String> {
+    let mut list = Vec::new();
+    for item in value {
+        if item == INHERITED {
+            list.extend(inherited.iter().cloned());
+        } else {
+            list.push(item);
+        }
+    }
+    list
+}
+
+/// Find the candidate closest to `name`, to suggest it in place of a misspelled name.
+pub fn similar_name<'a, I: IntoIterator<Item = &'a str>>(name: &str, candidates: I) -> Option<&'a str> {
+    let max_distance = ::std::cmp::max(name.len(), 3) / 3;
+    candidates.into_iter()
+        .map(|candidate| (lev_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Possible filename to search for.
+const CONFIG_FILE_NAMES: &[&str] = &[".clippy.toml", "clippy.toml"];
+
+/// The table of a package's `Cargo.toml` holding its configuration.
+const PACKAGE_TABLE: &str = "package.metadata.clippy";
+
+/// The table of a workspace's `Cargo.toml` holding the configuration of its packages.
+const WORKSPACE_TABLE: &str = "workspace.metadata.clippy";
+
+/// Search for the configuration file, from the current directory up.
 pub fn lookup_conf_file() -> io::Result<Option<path::PathBuf>> {
-    /// Possible filename to search for.
-    const CONFIG_FILE_NAMES: [&'static str; 2] = [".clippy.toml", "clippy.toml"];
+    lookup_file_from(try!(env::current_dir()), CONFIG_FILE_NAMES)
+}
+
+/// Search for the manifest of the package being compiled, whose directory cargo gives to rustc
+/// in `CARGO_MANIFEST_DIR`, or else for the closest `Cargo.toml` from the current directory up.
+pub fn lookup_manifest() -> io::Result<Option<path::PathBuf>> {
+    if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+        let manifest = path::Path::new(&dir).join("Cargo.toml");
+        return Ok(if manifest.is_file() { Some(manifest) } else { None });
+    }
+    lookup_file_from(try!(env::current_dir()), &["Cargo.toml"])
+}
 
-    let mut current = try!(env::current_dir());
+/// Search for a configuration file in `current` and its ancestors.
+fn lookup_conf_file_from(current: path::PathBuf) -> io::Result<Option<path::PathBuf>> {
+    lookup_file_from(current, CONFIG_FILE_NAMES)
+}
 
+/// Search for a file named like one of `names` in `current` and its ancestors.
+fn lookup_file_from(mut current: path::PathBuf, names: &[&str]) -> io::Result<Option<path::PathBuf>> {
     loop {
-        for config_file_name in &CONFIG_FILE_NAMES {
+        for config_file_name in names {
             let config_file = current.join(config_file_name);
             match fs::metadata(&config_file) {
                 // Only return if it's a file to handle the unlikely situation of a directory named
@@ -224,49 +507,182 @@ pub fn lookup_conf_file() -> io::Result<Option<path::PathBuf>> {
     }
 }
 
-/// Read the `toml` configuration file.
+/// Read the configuration of the package whose manifest is `manifest`, and the `toml`
+/// configuration file at `path`.
+///
+/// The `[workspace.metadata.clippy]` table of the workspace's `Cargo.toml` is read first, then the
+/// `[package.metadata.clippy]` table of the package's `Cargo.toml` and then the configuration
+/// file, each overriding the values set before, and extending the lists that contain `".."`.
+///
+/// If the file has `inherit = true`, the closest configuration file in the parent directories of
+/// its directory is read before it, and so on, so that the file only overrides some values.
 ///
 /// In case of error, the function tries to continue as much as possible.
-pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
+pub fn read(manifest: Option<&path::Path>, path: Option<&path::Path>) -> (Conf, Vec<FileError>) {
     let mut conf = Conf::default();
     let mut errors = Vec::new();
 
-    let path = if let Some(path) = path {
-        path
+    if let Some(manifest) = manifest {
+        read_manifests(&mut conf, manifest, &mut errors);
+    }
+    if let Some(path) = path {
+        read_chain(&mut conf, path, &mut errors);
+    }
+
+    (conf, errors)
+}
+
+/// A parsed `Cargo.toml`.
+struct Manifest {
+    /// The path of the file.
+    path: path::PathBuf,
+    /// The content of the file, to locate errors.
+    source: String,
+    /// The parsed content.
+    toml: toml::Table,
+}
+
+/// Read and parse the `toml` file at `path`.
+fn parse(path: &path::Path, errors: &mut Vec<FileError>) -> Option<Manifest> {
+    let mut source = String::new();
+    if let Err(err) = fs::File::open(path).and_then(|mut file| file.read_to_string(&mut source)) {
+        errors.push(FileError::without_position(path, err.into()));
+        return None;
+    }
+
+    let mut parser = toml::Parser::new(&source);
+    if let Some(toml) = parser.parse() {
+        Some(Manifest {
+            path: path.to_path_buf(),
+            source: source,
+            toml: toml,
+        })
     } else {
-        return (conf, errors);
-    };
+        errors.extend(parser.errors.into_iter().map(|err| FileError::new(path, &source, Error::Toml(err))));
+        None
+    }
+}
 
-    let file = match fs::File::open(path) {
-        Ok(mut file) => {
-            let mut buf = String::new();
+/// The value at the dotted `path` of `table`, like `package.metadata.clippy`.
+fn lookup<'a>(table: &'a toml::Table, path: &str) -> Option<&'a toml::Value> {
+    let mut keys = path.split('.');
+    let first = keys.next().and_then(|key| table.get(key));
+    keys.fold(first, |value, key| match value {
+        Some(&toml::Value::Table(ref table)) => table.get(key),
+        _ => None,
+    })
+}
 
-            if let Err(err) = file.read_to_string(&mut buf) {
-                errors.push(err.into());
-                return (conf, errors);
-            }
+/// The manifest of the workspace the package of `manifest` is a member of, if it is not its own
+/// workspace root. Like cargo, this is the `package.workspace` key if it is set, or else the
+/// closest `Cargo.toml` with a `[workspace]` table in the parent directories.
+fn workspace_manifest(manifest: &Manifest) -> Option<path::PathBuf> {
+    if manifest.toml.contains_key("workspace") {
+        return None;
+    }
+    let dir = if let Some(dir) = manifest.path.parent() {
+        dir
+    } else {
+        return None;
+    };
+    if let Some(root) = lookup(&manifest.toml, "package.workspace").and_then(toml::Value::as_str) {
+        return Some(dir.join(root).join("Cargo.toml"));
+    }
+    let mut current = dir.to_path_buf();
+    while current.pop() {
+        let candidate = current.join("Cargo.toml");
+        // errors are reported by cargo, not by Clippy
+        if candidate.is_file() &&
+           parse(&candidate, &mut Vec::new()).map_or(false, |root| root.toml.contains_key("workspace")) {
+            return Some(candidate);
+        }
+    }
+    None
+}
 
-            buf
-        },
-        Err(err) => {
-            errors.push(err.into());
-            return (conf, errors);
+/// Read the configuration tables of the package's manifest at `path` and of its workspace's
+/// manifest into `conf`.
+fn read_manifests(conf: &mut Conf, path: &path::Path, errors: &mut Vec<FileError>) {
+    let package = if let Some(package) = parse(path, errors) {
+        package
+    } else {
+        return;
+    };
+    match workspace_manifest(&package).and_then(|root| parse(&root, errors)) {
+        Some(root) => read_table(conf, &root, WORKSPACE_TABLE, errors),
+        None => read_table(conf, &package, WORKSPACE_TABLE, errors),
+    }
+    read_table(conf, &package, PACKAGE_TABLE, errors);
+}
+
+/// Read the configuration in the table `name` of `manifest` into `conf`, if it has one.
+fn read_table(conf: &mut Conf, manifest: &Manifest, name: &str, errors: &mut Vec<FileError>) {
+    let table = match lookup(&manifest.toml, name) {
+        Some(&toml::Value::Table(ref table)) => table.clone(),
+        Some(value) => {
+            let parent = &name[..name.len() - ".clippy".len()];
+            let error = Error::Type("clippy", "table", value.type_str());
+            errors.push(FileError::in_table(&manifest.path, &manifest.source, parent, error));
+            return;
         },
+        None => return,
     };
 
-    let mut parser = toml::Parser::new(&file);
+    if !conf.files.contains(&manifest.path) {
+        conf.files.push(manifest.path.clone());
+    }
+    for (key, value) in table {
+        if let Err(err) = conf.set(key, value) {
+            errors.push(FileError::in_table(&manifest.path, &manifest.source, name, err));
+        }
+    }
+}
+
+/// Read the configuration file at `path` into `conf`, after the files it inherits.
+fn read_chain(conf: &mut Conf, path: &path::Path, errors: &mut Vec<FileError>) {
+    let mut source = String::new();
+    if let Err(err) = fs::File::open(path).and_then(|mut file| file.read_to_string(&mut source)) {
+        errors.push(FileError::without_position(path, err.into()));
+        return;
+    }
+
+    let mut parser = toml::Parser::new(&source);
     let toml = if let Some(toml) = parser.parse() {
         toml
     } else {
-        errors.push(Error::Toml(parser.errors));
-        return (conf, errors);
+        errors.extend(parser.errors.into_iter().map(|err| FileError::new(path, &source, Error::Toml(err))));
+        return;
+    };
+
+    let inherit = match toml.get("inherit") {
+        None => false,
+        Some(&toml::Value::Boolean(inherit)) => inherit,
+        Some(value) => {
+            errors.push(FileError::new(path, &source, Error::Type("inherit", "bool", value.type_str())));
+            false
+        },
     };
 
+    if inherit {
+        // look from the parent of the directory containing the file, which always exists as we
+        // could read the file, so the chain cannot loop
+        let parent = fs::canonicalize(path).and_then(|path| match path.parent().and_then(path::Path::parent) {
+            Some(dir) => lookup_conf_file_from(dir.to_path_buf()),
+            None => Ok(None),
+        });
+        match parent {
+            Ok(Some(parent)) => read_chain(conf, &parent, errors),
+            // nothing to inherit but the default configuration
+            Ok(None) => (),
+            Err(err) => errors.push(FileError::without_position(path, err.into())),
+        }
+    }
+
+    conf.files.push(path.to_path_buf());
+
     for (key, value) in toml {
         if let Err(err) = conf.set(key, value) {
-            errors.push(err);
+            errors.push(FileError::new(path, &source, err));
         }
     }
-
-    (conf, errors)
 }
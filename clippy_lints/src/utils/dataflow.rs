@@ -0,0 +1,459 @@
+//! Dataflow analyses over the control flow graph of a function or closure body, and the liveness,
+//! reaching definitions and "used after" queries lints make with them.
+//!
+//! The graph is rustc's, where each expression and pattern is a node coming after the nodes of its
+//! subexpressions and subpatterns, and where a closure is a single node. The analyses know about
+//! the locals of the body, which are read by paths and written by bindings and assignments.
+//! Assigning to a field of a local only reads the local. Borrowing a local mutably, or assigning
+//! to it in a closure, is a write that may not happen.
+
+#![deny(missing_docs_in_private_items)]
+
+use reexport::*;
+use rustc::cfg::{CFG, CFGNodeData};
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, walk_pat, NestedVisitorMap, Visitor};
+use rustc::hir::map::Map;
+use rustc::hir::map::Node::{NodeExpr, NodeImplItem, NodeItem, NodeTraitItem};
+use rustc::lint::LateContext;
+use std::cell::{Ref, RefCell};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// How a node accesses a local.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccessKind {
+    /// The node reads the value of the local.
+    Read,
+    /// The node gives the local a new value.
+    Write,
+    /// The node may give the local a new value, through a mutable borrow or in a closure.
+    MayWrite,
+}
+
+/// An access to a local.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Access {
+    /// The binding of the local.
+    pub local: NodeId,
+    /// The HIR node making the access: a path, a binding, an assignment, a mutable borrow or a
+    /// closure.
+    pub node: NodeId,
+    /// Whether the node reads or writes the local.
+    pub kind: AccessKind,
+}
+
+/// The direction an analysis goes through the graph in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// From the entry of the body to its exit, for facts about what happened.
+    Forward,
+    /// From the exit of the body to its entry, for facts about what will happen.
+    Backward,
+}
+
+/// A dataflow analysis, which computes the facts that hold at each point of a body.
+///
+/// A fact holds at a point if it holds along some path through it: the facts coming from several
+/// nodes are joined by their union.
+pub trait Analysis {
+    /// What the analysis computes, like the locals that are live.
+    type Fact: Copy + Eq + Hash;
+
+    /// The direction the analysis goes in.
+    fn direction(&self) -> Direction;
+
+    /// Turn the facts holding before the node `index`, in the direction of the analysis, into the
+    /// facts holding after it.
+    fn transfer(&self, index: usize, accesses: &[Access], facts: &mut HashSet<Self::Fact>);
+}
+
+/// The locals that may be read before being written again, like rustc's liveness pass.
+pub struct Liveness;
+
+impl Analysis for Liveness {
+    type Fact = NodeId;
+
+    fn direction(&self) -> Direction {
+        Direction::Backward
+    }
+
+    fn transfer(&self, _: usize, accesses: &[Access], facts: &mut HashSet<NodeId>) {
+        for access in accesses.iter().rev() {
+            match access.kind {
+                AccessKind::Write => {
+                    facts.remove(&access.local);
+                },
+                // the value may be read through the mutable borrow
+                AccessKind::Read | AccessKind::MayWrite => {
+                    facts.insert(access.local);
+                },
+            }
+        }
+    }
+}
+
+/// The writes whose value a local may still have.
+pub struct ReachingDefinitions;
+
+impl Analysis for ReachingDefinitions {
+    type Fact = Access;
+
+    fn direction(&self) -> Direction {
+        Direction::Forward
+    }
+
+    fn transfer(&self, _: usize, accesses: &[Access], facts: &mut HashSet<Access>) {
+        for access in accesses {
+            match access.kind {
+                AccessKind::Write => {
+                    facts.retain(|definition| definition.local != access.local);
+                    facts.insert(*access);
+                },
+                AccessKind::MayWrite => {
+                    facts.insert(*access);
+                },
+                AccessKind::Read => (),
+            }
+        }
+    }
+}
+
+/// The locals that may be read or written later, not counting the accesses of one node.
+struct UsedLater {
+    /// The node whose accesses are not counted.
+    except: usize,
+}
+
+impl Analysis for UsedLater {
+    type Fact = NodeId;
+
+    fn direction(&self) -> Direction {
+        Direction::Backward
+    }
+
+    fn transfer(&self, index: usize, accesses: &[Access], facts: &mut HashSet<NodeId>) {
+        if index != self.except {
+            facts.extend(accesses.iter().map(|access| access.local));
+        }
+    }
+}
+
+/// The facts an analysis found to hold at the entry and at the exit of each node of a body.
+pub struct Results<F> {
+    /// The facts holding before each node is evaluated.
+    entry: Vec<HashSet<F>>,
+    /// The facts holding after each node is evaluated.
+    exit: Vec<HashSet<F>>,
+}
+
+impl<F> Results<F> {
+    /// The facts holding before the node `index` is evaluated.
+    pub fn entry(&self, index: usize) -> &HashSet<F> {
+        &self.entry[index]
+    }
+
+    /// The facts holding after the node `index` is evaluated.
+    pub fn exit(&self, index: usize) -> &HashSet<F> {
+        &self.exit[index]
+    }
+}
+
+/// The control flow graph of a body, with the accesses to locals of its nodes.
+pub struct Flow {
+    /// The index of the node of each HIR expression and pattern.
+    indices: HashMap<NodeId, usize>,
+    /// The nodes control can come from, by node.
+    predecessors: Vec<Vec<usize>>,
+    /// The nodes control can go to, by node.
+    successors: Vec<Vec<usize>>,
+    /// The accesses each node makes, in order.
+    accesses: Vec<Vec<Access>>,
+    /// The results of `Liveness`, once solved.
+    liveness: RefCell<Option<Results<NodeId>>>,
+    /// The results of `ReachingDefinitions`, once solved.
+    reaching_definitions: RefCell<Option<Results<Access>>>,
+}
+
+impl Flow {
+    /// The control flow graph of `body`.
+    pub fn new<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, body: &'tcx Body) -> Flow {
+        let cfg = CFG::new(cx.tcx, body);
+        let len = cfg.graph.len_nodes();
+
+        let mut indices = HashMap::new();
+        cfg.graph.each_node(|index, node| {
+            if let CFGNodeData::AST(id) = node.data {
+                indices.insert(id, index.node_id());
+            }
+            true
+        });
+        let mut predecessors = vec![Vec::new(); len];
+        let mut successors = vec![Vec::new(); len];
+        cfg.graph.each_edge(|_, edge| {
+            predecessors[edge.target().node_id()].push(edge.source().node_id());
+            successors[edge.source().node_id()].push(edge.target().node_id());
+            true
+        });
+
+        // the arguments are bound before the body is entered
+        let mut arguments = AccessVisitor::new(cx);
+        for arg in &body.arguments {
+            arguments.visit_pat(&arg.pat);
+        }
+        let mut visitor = AccessVisitor::new(cx);
+        visitor.visit_expr(&body.value);
+
+        let mut accesses = vec![Vec::new(); len];
+        accesses[cfg.entry.node_id()] = arguments.accesses
+            .into_iter()
+            .flat_map(|(_, node_accesses)| node_accesses)
+            .collect();
+        for (id, node_accesses) in visitor.accesses {
+            if let Some(&index) = indices.get(&id) {
+                accesses[index].extend(node_accesses);
+            }
+        }
+
+        Flow {
+            indices: indices,
+            predecessors: predecessors,
+            successors: successors,
+            accesses: accesses,
+            liveness: RefCell::new(None),
+            reaching_definitions: RefCell::new(None),
+        }
+    }
+
+    /// The control flow graph of the innermost function, method, closure or constant the HIR node
+    /// `id` is in, `None` if there is none.
+    pub fn enclosing<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, id: NodeId) -> Option<Flow> {
+        enclosing_body(&cx.tcx.hir, id).map(|body| Flow::new(cx, cx.tcx.hir.body(body)))
+    }
+
+    /// The index of the node of the HIR expression or pattern `id`, `None` if it is not part of
+    /// the body, like the expressions of a closure in the body.
+    pub fn index(&self, id: NodeId) -> Option<usize> {
+        self.indices.get(&id).cloned()
+    }
+
+    /// Run `analysis` until the facts at each node do not change anymore.
+    pub fn solve<A: Analysis>(&self, analysis: &A) -> Results<A::Fact> {
+        let len = self.accesses.len();
+        let (inputs, outputs) = match analysis.direction() {
+            Direction::Forward => (&self.predecessors, &self.successors),
+            Direction::Backward => (&self.successors, &self.predecessors),
+        };
+
+        // the facts holding before and after each node, in the direction of the analysis
+        let mut before = vec![HashSet::new(); len];
+        let mut after = vec![HashSet::new(); len];
+        let mut queue: VecDeque<usize> = (0..len).collect();
+        let mut queued = vec![true; len];
+        while let Some(index) = queue.pop_front() {
+            queued[index] = false;
+            let mut facts = HashSet::new();
+            for &input in &inputs[index] {
+                facts.extend(after[input].iter().cloned());
+            }
+            before[index] = facts.clone();
+            analysis.transfer(index, &self.accesses[index], &mut facts);
+            if facts != after[index] {
+                after[index] = facts;
+                for &output in &outputs[index] {
+                    if !queued[output] {
+                        queued[output] = true;
+                        queue.push_back(output);
+                    }
+                }
+            }
+        }
+
+        match analysis.direction() {
+            Direction::Forward => {
+                Results {
+                    entry: before,
+                    exit: after,
+                }
+            },
+            Direction::Backward => {
+                Results {
+                    entry: after,
+                    exit: before,
+                }
+            },
+        }
+    }
+
+    /// The results of `analysis`, kept in `cache` so that queries on several locals solve it once.
+    fn solved<'r, A: Analysis>(&self, cache: &'r RefCell<Option<Results<A::Fact>>>, analysis: &A)
+                               -> Ref<'r, Results<A::Fact>> {
+        if cache.borrow().is_none() {
+            *cache.borrow_mut() = Some(self.solve(analysis));
+        }
+        Ref::map(cache.borrow(), |results| results.as_ref().expect("the analysis was just solved"))
+    }
+
+    /// Whether the value the local `local` has after the HIR node `id` is evaluated may be read.
+    /// This is `true` if `id` is not part of the body.
+    pub fn is_live_after(&self, local: NodeId, id: NodeId) -> bool {
+        self.index(id).map_or(true, |index| self.solved(&self.liveness, &Liveness).exit(index).contains(&local))
+    }
+
+    /// The HIR nodes that may have given the local `local` the value it has after the HIR node
+    /// `id` is evaluated, `None` if `id` is not part of the body.
+    pub fn definitions_reaching(&self, local: NodeId, id: NodeId) -> Option<Vec<NodeId>> {
+        self.index(id).map(|index| {
+            self.solved(&self.reaching_definitions, &ReachingDefinitions)
+                .exit(index)
+                .iter()
+                .filter(|definition| definition.local == local)
+                .map(|definition| definition.node)
+                .collect()
+        })
+    }
+
+    /// Whether the local `local` may be read or written once the HIR node `id` is evaluated, by
+    /// other nodes than `id` itself. This is `true` if `id` is not part of the body.
+    pub fn is_used_after(&self, local: NodeId, id: NodeId) -> bool {
+        self.index(id).map_or(true, |index| self.solve(&UsedLater { except: index }).exit(index).contains(&local))
+    }
+}
+
+/// The body of the innermost function, method, closure or constant the HIR node `id` is in.
+fn enclosing_body(map: &Map, id: NodeId) -> Option<BodyId> {
+    let mut child = id;
+    loop {
+        let parent = map.get_parent_node(child);
+        if parent == child {
+            return None;
+        }
+        match map.find(parent) {
+            Some(NodeExpr(&Expr { node: ExprClosure(_, _, body, _), .. })) => return Some(body),
+            Some(NodeItem(item)) => {
+                return match item.node {
+                    ItemFn(_, _, _, _, _, body) |
+                    ItemConst(_, body) |
+                    ItemStatic(_, _, body) => Some(body),
+                    _ => None,
+                };
+            },
+            Some(NodeImplItem(item)) => {
+                return match item.node {
+                    ImplItemKind::Method(_, body) |
+                    ImplItemKind::Const(_, body) => Some(body),
+                    ImplItemKind::Type(_) => None,
+                };
+            },
+            Some(NodeTraitItem(item)) => {
+                return match item.node {
+                    TraitItemKind::Method(_, TraitMethod::Provided(body)) |
+                    TraitItemKind::Const(_, Some(body)) => Some(body),
+                    _ => None,
+                };
+            },
+            None => return None,
+            _ => (),
+        }
+        child = parent;
+    }
+}
+
+/// Collects the accesses to locals of a body, by HIR node.
+struct AccessVisitor<'a, 'tcx: 'a> {
+    /// The context of the lint pass.
+    cx: &'a LateContext<'a, 'tcx>,
+    /// The accesses of each HIR node, in order.
+    accesses: HashMap<NodeId, Vec<Access>>,
+    /// The paths that are assigned to, which do not read the local.
+    assigned: HashSet<NodeId>,
+    /// The outermost closure being visited, which makes the accesses of its body.
+    closure: Option<NodeId>,
+}
+
+impl<'a, 'tcx: 'a> AccessVisitor<'a, 'tcx> {
+    /// A visitor which has not seen any access yet.
+    fn new(cx: &'a LateContext<'a, 'tcx>) -> Self {
+        AccessVisitor {
+            cx: cx,
+            accesses: HashMap::new(),
+            assigned: HashSet::new(),
+            closure: None,
+        }
+    }
+
+    /// The binding of the local `expr` is a path to.
+    fn local(&self, expr: &Expr) -> Option<NodeId> {
+        if let ExprPath(ref qpath) = expr.node {
+            if let Def::Local(def_id) = self.cx.tables.qpath_def(qpath, expr.id) {
+                return self.cx.tcx.hir.as_local_node_id(def_id);
+            }
+        }
+        None
+    }
+
+    /// Record an access by the HIR node `node`, or by the closure it is in.
+    fn record(&mut self, node: NodeId, local: NodeId, kind: AccessKind) {
+        let (node, kind) = match (self.closure, kind) {
+            (Some(closure), AccessKind::Write) => (closure, AccessKind::MayWrite),
+            (Some(closure), _) => (closure, kind),
+            (None, _) => (node, kind),
+        };
+        self.accesses.entry(node).or_insert_with(Vec::new).push(Access {
+            local: local,
+            node: node,
+            kind: kind,
+        });
+    }
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for AccessVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprAssign(ref lhs, _) => {
+                if let Some(local) = self.local(lhs) {
+                    self.assigned.insert(lhs.id);
+                    self.record(expr.id, local, AccessKind::Write);
+                }
+            },
+            // the path on the left reads the local
+            ExprAssignOp(_, ref lhs, _) => {
+                if let Some(local) = self.local(lhs) {
+                    self.record(expr.id, local, AccessKind::Write);
+                }
+            },
+            ExprAddrOf(MutMutable, ref borrowed) => {
+                if let Some(local) = self.local(borrowed) {
+                    self.record(expr.id, local, AccessKind::MayWrite);
+                }
+            },
+            ExprPath(_) if !self.assigned.contains(&expr.id) => {
+                if let Some(local) = self.local(expr) {
+                    self.record(expr.id, local, AccessKind::Read);
+                }
+            },
+            ExprClosure(_, _, body_id, _) => {
+                let outer = self.closure;
+                self.closure = Some(outer.unwrap_or(expr.id));
+                let body = self.cx.tcx.hir.body(body_id);
+                self.visit_body(body);
+                self.closure = outer;
+                return;
+            },
+            _ => (),
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx Pat) {
+        if let PatKind::Binding(..) = pat.node {
+            self.record(pat.id, pat.id, AccessKind::Write);
+        }
+        walk_pat(self, pat);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
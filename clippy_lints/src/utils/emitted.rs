@@ -0,0 +1,72 @@
+//! Keep track of the lints emitted by Clippy.
+//!
+//! rustc's diagnostics do not say which lint produced them, so the `cargo-clippy` driver enables
+//! recording before running the compiler and matches the records against the diagnostics it gets
+//! back.
+
+#![deny(missing_docs_in_private_items)]
+
+use rustc::lint::{Lint, LintContext};
+use std::cell::RefCell;
+use syntax::codemap::Span;
+use utils::sugg::Applicability;
+
+/// A lint emitted at some span.
+#[derive(Clone, Debug)]
+pub struct Emitted {
+    /// The name of the lint, in lowercase.
+    pub lint: String,
+    /// The main message of the diagnostic.
+    pub message: String,
+    /// The name of the file containing the primary span, as known by the codemap.
+    pub file_name: String,
+    /// The byte offset of the start of the primary span in its file.
+    pub byte_start: usize,
+    /// The byte offset of the end of the primary span in its file.
+    pub byte_end: usize,
+    /// The applicability of each suggestion of the diagnostic, in the order they were made.
+    pub suggestions: Vec<Applicability>,
+}
+
+thread_local! {
+    /// The lints emitted so far, `None` unless recording was started.
+    static EMITTED: RefCell<Option<Vec<Emitted>>> = RefCell::new(None);
+}
+
+/// Start recording the lints emitted on the current thread.
+pub fn start_recording() {
+    EMITTED.with(|emitted| *emitted.borrow_mut() = Some(Vec::new()));
+}
+
+/// Stop recording and return the lints emitted since `start_recording` was called.
+pub fn take() -> Vec<Emitted> {
+    EMITTED.with(|emitted| emitted.borrow_mut().take().unwrap_or_default())
+}
+
+/// Record that `lint` was emitted at `span` with the message `msg`. This does nothing unless
+/// recording was started.
+pub fn record<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, span: Span, msg: &str) {
+    EMITTED.with(|emitted| if let Some(ref mut emitted) = *emitted.borrow_mut() {
+        let codemap = cx.sess().codemap();
+        let start = codemap.lookup_byte_offset(span.lo);
+        let end = codemap.lookup_byte_offset(span.hi);
+        emitted.push(Emitted {
+            lint: lint.name_lower(),
+            message: msg.to_owned(),
+            file_name: start.fm.name.clone(),
+            byte_start: start.pos.0 as usize,
+            byte_end: end.pos.0 as usize,
+            suggestions: Vec::new(),
+        });
+    });
+}
+
+/// Record that the lint emitted last made a suggestion with the given applicability. This does
+/// nothing unless recording was started.
+pub fn record_suggestion(applicability: Applicability) {
+    EMITTED.with(|emitted| if let Some(ref mut emitted) = *emitted.borrow_mut() {
+        if let Some(last) = emitted.last_mut() {
+            last.suggestions.push(applicability);
+        }
+    });
+}
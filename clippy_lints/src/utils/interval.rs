@@ -0,0 +1,562 @@
+//! The ranges of values integer expressions can have, as far as their function body tells.
+//!
+//! The ranges come from the types of the expressions, and are narrowed by constants, arithmetic,
+//! masks, `%`, shifts, casts, `cmp::min` and `cmp::max`, by the initializers of immutable locals,
+//! and by the conditions of the `if`s and `while`s the expressions are in.
+
+#![deny(missing_docs_in_private_items)]
+
+use consts::{constant_with_locals, Constant};
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::map::Node::{NodeExpr, NodeImplItem, NodeItem, NodeTraitItem};
+use rustc::lint::LateContext;
+use rustc::ty;
+use rustc_const_math::ConstInt;
+use std::cmp::{self, Ordering};
+use syntax::ast::{IntTy, NodeId, UintTy};
+use utils::{is_immutable_binding, let_init, match_def_path, paths};
+use utils::comparisons::{normalize_comparison, Rel};
+
+/// How many locals and conditions deep the analysis looks for the range of an expression.
+const MAX_DEPTH: u32 = 8;
+
+/// An integer of any integer type.
+#[derive(Copy, Clone, Debug, Eq)]
+pub enum FullInt {
+    /// A signed integer.
+    S(i128),
+    /// An unsigned integer.
+    U(u128),
+}
+
+impl FullInt {
+    /// Compare a signed and an unsigned integer.
+    #[allow(cast_sign_loss)]
+    fn cmp_s_u(s: i128, u: u128) -> Ordering {
+        if s < 0 {
+            Ordering::Less
+        } else if u > (i128::max_value() as u128) {
+            Ordering::Greater
+        } else {
+            (s as u128).cmp(&u)
+        }
+    }
+
+    /// The integer as an `i128`, `None` if it is too large.
+    #[allow(cast_possible_wrap)]
+    pub fn to_i128(self) -> Option<i128> {
+        match self {
+            FullInt::S(s) => Some(s),
+            FullInt::U(u) => if u > (i128::max_value() as u128) { None } else { Some(u as i128) },
+        }
+    }
+
+    /// The integer just before this one.
+    fn pred(self) -> Option<FullInt> {
+        match self {
+            FullInt::S(s) => s.checked_sub(1).map(FullInt::S),
+            FullInt::U(0) => Some(FullInt::S(-1)),
+            FullInt::U(u) => Some(FullInt::U(u - 1)),
+        }
+    }
+
+    /// The integer just after this one.
+    #[allow(cast_sign_loss)]
+    fn succ(self) -> Option<FullInt> {
+        match self {
+            FullInt::S(s) if s < 0 => Some(FullInt::S(s + 1)),
+            FullInt::S(s) => (s as u128).checked_add(1).map(FullInt::U),
+            FullInt::U(u) => u.checked_add(1).map(FullInt::U),
+        }
+    }
+}
+
+impl PartialEq for FullInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other).expect("partial_cmp only returns Some(_)") == Ordering::Equal
+    }
+}
+
+impl PartialOrd for FullInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(match (self, other) {
+            (&FullInt::S(s), &FullInt::S(o)) => s.cmp(&o),
+            (&FullInt::U(s), &FullInt::U(o)) => s.cmp(&o),
+            (&FullInt::S(s), &FullInt::U(o)) => Self::cmp_s_u(s, o),
+            (&FullInt::U(s), &FullInt::S(o)) => Self::cmp_s_u(o, s).reverse(),
+        })
+    }
+}
+
+impl Ord for FullInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.partial_cmp(other).expect("partial_cmp for FullInt can never return None")
+    }
+}
+
+impl From<ConstInt> for FullInt {
+    #[allow(cast_possible_wrap)]
+    fn from(value: ConstInt) -> FullInt {
+        if value.is_negative() {
+            FullInt::S(value.to_u128_unchecked() as i128)
+        } else {
+            FullInt::U(value.to_u128_unchecked())
+        }
+    }
+}
+
+/// The integers between `min` and `max`, inclusive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Interval {
+    /// The smallest value.
+    pub min: FullInt,
+    /// The largest value.
+    pub max: FullInt,
+}
+
+/// Zero, which masks and remainders keep their results close to.
+const ZERO: FullInt = FullInt::S(0);
+
+impl Interval {
+    /// The integers between `min` and `max`, inclusive.
+    pub fn new(min: FullInt, max: FullInt) -> Interval {
+        Interval {
+            min: min,
+            max: max,
+        }
+    }
+
+    /// The integers between `min` and `max` as `i128`s, inclusive.
+    fn signed(min: i128, max: i128) -> Interval {
+        Interval::new(FullInt::S(min), FullInt::S(max))
+    }
+
+    /// All the values of the integer type `ty`, where `isize` and `usize` are `pointer_width` bits
+    /// wide. `None` if `ty` is not an integer type.
+    pub fn of_type(ty: ty::Ty, pointer_width: u32) -> Option<Interval> {
+        let (signed, bits) = if let Some(int_ty) = int_type(ty, pointer_width) {
+            int_ty
+        } else {
+            return None;
+        };
+        let max = u128::max_value() >> (128 - bits);
+        Some(if signed {
+            #[allow(cast_possible_wrap)]
+            let signed_max = (max >> 1) as i128;
+            Interval::signed(-signed_max - 1, signed_max)
+        } else {
+            Interval::new(FullInt::U(0), FullInt::U(max))
+        })
+    }
+
+    /// All the values of the integer type `ty` on the target being compiled for.
+    pub fn of_target_type(cx: &LateContext, ty: ty::Ty) -> Option<Interval> {
+        Interval::of_type(ty, pointer_width(cx))
+    }
+
+    /// Whether the interval is a single value.
+    pub fn is_constant(&self) -> bool {
+        self.min == self.max
+    }
+
+    /// Whether all the values of `other` are in this interval.
+    pub fn contains(&self, other: &Interval) -> bool {
+        self.min <= other.min && other.max <= self.max
+    }
+
+    /// The values in both intervals, `None` if there are none.
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let (min, max) = (cmp::max(self.min, other.min), cmp::min(self.max, other.max));
+        if min <= max {
+            Some(Interval::new(min, max))
+        } else {
+            None
+        }
+    }
+
+    /// The smallest interval containing both intervals.
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval::new(cmp::min(self.min, other.min), cmp::max(self.max, other.max))
+    }
+
+    /// The bounds as `i128`s, `None` if they are too large.
+    pub fn bounds(&self) -> Option<(i128, i128)> {
+        match (self.min.to_i128(), self.max.to_i128()) {
+            (Some(min), Some(max)) => Some((min, max)),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the integer type `ty` is signed, and its width in bits, where `isize` and `usize` are
+/// `pointer_width` bits wide. `None` if `ty` is not an integer type.
+fn int_type(ty: ty::Ty, pointer_width: u32) -> Option<(bool, u32)> {
+    match ty.sty {
+        ty::TyInt(IntTy::Is) => Some((true, pointer_width)),
+        ty::TyInt(IntTy::I8) => Some((true, 8)),
+        ty::TyInt(IntTy::I16) => Some((true, 16)),
+        ty::TyInt(IntTy::I32) => Some((true, 32)),
+        ty::TyInt(IntTy::I64) => Some((true, 64)),
+        ty::TyInt(IntTy::I128) => Some((true, 128)),
+        ty::TyUint(UintTy::Us) => Some((false, pointer_width)),
+        ty::TyUint(UintTy::U8) => Some((false, 8)),
+        ty::TyUint(UintTy::U16) => Some((false, 16)),
+        ty::TyUint(UintTy::U32) => Some((false, 32)),
+        ty::TyUint(UintTy::U64) => Some((false, 64)),
+        ty::TyUint(UintTy::U128) => Some((false, 128)),
+        _ => None,
+    }
+}
+
+/// The width in bits of `isize` and `usize` on the target being compiled for.
+fn pointer_width(cx: &LateContext) -> u32 {
+    match cx.tcx.sess.target.uint_type {
+        UintTy::U16 => 16,
+        UintTy::U32 => 32,
+        _ => 64,
+    }
+}
+
+/// The range of the values the integer expression `e` can have, `None` if `e` is not an integer.
+///
+/// This is at most the range of its type: an expression that may overflow can have any value.
+pub fn interval(cx: &LateContext, e: &Expr) -> Option<Interval> {
+    expr_interval(cx, e, 0)
+}
+
+/// The range of `e`, looking at most `MAX_DEPTH - depth` locals and conditions deep.
+fn expr_interval(cx: &LateContext, e: &Expr, depth: u32) -> Option<Interval> {
+    let ty_interval = if let Some(ty_interval) = Interval::of_target_type(cx, cx.tables.expr_ty(e)) {
+        ty_interval
+    } else {
+        return None;
+    };
+    if let Some((Constant::Int(value), _)) = constant_with_locals(cx, e) {
+        return Some(Interval::new(value.into(), value.into()));
+    }
+    if depth >= MAX_DEPTH {
+        return Some(ty_interval);
+    }
+
+    let computed = match e.node {
+        ExprBinary(op, ref left, ref right) => binop_interval(cx, op.node, left, right, depth + 1),
+        ExprUnary(UnNeg, ref operand) => {
+            expr_interval(cx, operand, depth + 1)
+                .and_then(|range| range.bounds())
+                .and_then(|(min, max)| match (max.checked_neg(), min.checked_neg()) {
+                    (Some(neg_max), Some(neg_min)) => Some(Interval::signed(neg_max, neg_min)),
+                    _ => None,
+                })
+        },
+        ExprUnary(UnNot, ref operand) => {
+            // `!x` is `-1 - x` for signed integers and `MAX - x` for unsigned ones
+            let offset = if ty_interval.min < ZERO { Some(-1) } else { ty_interval.max.to_i128() };
+            match (offset, expr_interval(cx, operand, depth + 1).and_then(|range| range.bounds())) {
+                (Some(offset), Some((min, max))) => Some(Interval::signed(offset - max, offset - min)),
+                _ => None,
+            }
+        },
+        ExprCast(ref operand, _) => {
+            match cx.tables.expr_ty(operand).sty {
+                ty::TyInt(_) | ty::TyUint(_) => expr_interval(cx, operand, depth + 1),
+                ty::TyBool => Some(Interval::signed(0, 1)),
+                ty::TyChar => Some(Interval::signed(0, i128::from(::std::char::MAX as u32))),
+                _ => None,
+            }
+        },
+        ExprCall(ref fun, ref args) if args.len() == 2 => {
+            if let ExprPath(ref qpath) = fun.node {
+                let def_id = cx.tables.qpath_def(qpath, fun.id).def_id();
+                match (expr_interval(cx, &args[0], depth + 1), expr_interval(cx, &args[1], depth + 1)) {
+                    (Some(a), Some(b)) if match_def_path(cx.tcx, def_id, &paths::CMP_MIN) => {
+                        Some(Interval::new(cmp::min(a.min, b.min), cmp::min(a.max, b.max)))
+                    },
+                    (Some(a), Some(b)) if match_def_path(cx.tcx, def_id, &paths::CMP_MAX) => {
+                        Some(Interval::new(cmp::max(a.min, b.min), cmp::max(a.max, b.max)))
+                    },
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        },
+        ExprPath(ref qpath) => {
+            if let Def::Local(def_id) = cx.tables.qpath_def(qpath, e.id) {
+                local_interval(cx, def_id, e.id, depth + 1)
+            } else {
+                None
+            }
+        },
+        ExprBlock(ref block) if block.stmts.is_empty() => {
+            block.expr.as_ref().and_then(|value| expr_interval(cx, value, depth + 1))
+        },
+        ExprIf(_, ref then, Some(ref otherwise)) => {
+            match (then.expr.as_ref().and_then(|value| expr_interval(cx, value, depth + 1)),
+                   expr_interval(cx, otherwise, depth + 1)) {
+                (Some(then_range), Some(else_range)) => Some(then_range.hull(&else_range)),
+                _ => None,
+            }
+        },
+        _ => None,
+    };
+
+    // the expression may overflow, and wrap around to any value
+    Some(computed.map_or(ty_interval, |computed| if ty_interval.contains(&computed) {
+        computed
+    } else {
+        ty_interval
+    }))
+}
+
+/// The range of `left op right`, which may exceed the range of their type.
+fn binop_interval(cx: &LateContext, op: BinOp_, left: &Expr, right: &Expr, depth: u32) -> Option<Interval> {
+    let (l, r) = if let (Some(l), Some(r)) = (expr_interval(cx, left, depth), expr_interval(cx, right, depth)) {
+        (l, r)
+    } else {
+        return None;
+    };
+
+    match op {
+        // these only clear bits, so a non-negative operand is an upper bound
+        BiBitAnd => {
+            match (l.min >= ZERO, r.min >= ZERO) {
+                (true, true) => Some(Interval::new(ZERO, cmp::min(l.max, r.max))),
+                (true, false) => Some(Interval::new(ZERO, l.max)),
+                (false, true) => Some(Interval::new(ZERO, r.max)),
+                (false, false) => None,
+            }
+        },
+        BiRem => {
+            // the remainder is smaller than the divisor and has the sign of the dividend
+            let divisor = if r.min >= ZERO {
+                r.max
+            } else if let Some((min, max)) = r.bounds() {
+                match min.checked_neg() {
+                    Some(neg) => FullInt::S(cmp::max(neg, max)),
+                    None => return None,
+                }
+            } else {
+                return None;
+            };
+            let bound = if let Some(bound) = divisor.pred() {
+                bound
+            } else {
+                return None;
+            };
+            let min = if l.min < ZERO {
+                bound.to_i128().and_then(i128::checked_neg).map_or(l.min, |neg| cmp::max(l.min, FullInt::S(neg)))
+            } else {
+                ZERO
+            };
+            let max = if l.max > ZERO { cmp::min(l.max, bound) } else { ZERO };
+            if bound < ZERO {
+                // always divides by zero
+                None
+            } else {
+                Some(Interval::new(min, max))
+            }
+        },
+        _ => {
+            let width = int_type(cx.tables.expr_ty(left), pointer_width(cx)).map(|(_, bits)| bits);
+            if let (Some(l), Some(r), Some(width)) = (l.bounds(), r.bounds(), width) {
+                arithmetic_interval(op, l, r, width)
+            } else {
+                None
+            }
+        },
+    }
+}
+
+/// The range of `l op r` for the arithmetic and bitwise operators, given the bounds of `l` and
+/// `r`, and the width in bits of the type of `l`. Shifting by `width` bits or more overflows.
+#[allow(cast_possible_truncation, cast_possible_wrap, cast_sign_loss)]
+fn arithmetic_interval(
+    op: BinOp_,
+    (lmin, lmax): (i128, i128),
+    (rmin, rmax): (i128, i128),
+    width: u32
+) -> Option<Interval> {
+    /// The smallest interval containing all the values.
+    fn corners(values: &[Option<i128>]) -> Option<Interval> {
+        let mut values = values.iter();
+        let first = if let Some(&Some(first)) = values.next() {
+            first
+        } else {
+            return None;
+        };
+        values.fold(Some(Interval::signed(first, first)), |range, value| match (range, *value) {
+            (Some(range), Some(corner)) => Some(range.hull(&Interval::signed(corner, corner))),
+            _ => None,
+        })
+    }
+
+    match op {
+        BiAdd => corners(&[lmin.checked_add(rmin), lmax.checked_add(rmax)]),
+        BiSub => corners(&[lmin.checked_sub(rmax), lmax.checked_sub(rmin)]),
+        BiMul => {
+            corners(&[lmin.checked_mul(rmin), lmin.checked_mul(rmax), lmax.checked_mul(rmin), lmax.checked_mul(rmax)])
+        },
+        BiDiv => {
+            if rmin > 0 || rmax < 0 {
+                corners(&[lmin.checked_div(rmin),
+                          lmin.checked_div(rmax),
+                          lmax.checked_div(rmin),
+                          lmax.checked_div(rmax)])
+            } else if rmin == 0 && rmax > 0 {
+                // dividing by a positive integer brings the dividend closer to zero
+                Some(Interval::signed(cmp::min(lmin, 0), cmp::max(lmax, 0)))
+            } else {
+                None
+            }
+        },
+        BiBitOr | BiBitXor if lmin >= 0 && rmin >= 0 => {
+            // the bits of the result are at most the bits of the largest operand
+            let max = cmp::max(lmax, rmax) as u128;
+            let bits = 128 - max.leading_zeros();
+            let all_ones = if bits == 0 { 0 } else { u128::max_value() >> (128 - bits) };
+            let min = if op == BiBitOr { cmp::max(lmin, rmin) } else { 0 };
+            Some(Interval::signed(min, all_ones as i128))
+        },
+        BiShr if rmin >= 0 && rmax < i128::from(width) => {
+            // arithmetic shifts bring the value closer to zero, or to -1
+            let (low, high) = (rmin as u32, rmax as u32);
+            Some(Interval::signed(if lmin < 0 { lmin >> low } else { lmin >> high },
+                                  if lmax < 0 { lmax >> high } else { lmax >> low }))
+        },
+        // `1 << 127` does not fit in an `i128`
+        BiShl if lmin >= 0 && rmin >= 0 && rmax < i128::from(cmp::min(width, 127)) => {
+            let (low, high) = (rmin as u32, rmax as u32);
+            corners(&[lmin.checked_mul(1 << low), lmax.checked_mul(1 << high)])
+        },
+        _ => None,
+    }
+}
+
+/// The range of the local `def_id` where it is used by the expression `id`.
+///
+/// Only immutable bindings keep the value they were initialized with, and still satisfy the
+/// conditions they were checked against.
+fn local_interval(cx: &LateContext, def_id: DefId, id: NodeId, depth: u32) -> Option<Interval> {
+    let map = &cx.tcx.hir;
+    let binding = if let Some(binding) = map.as_local_node_id(def_id) {
+        binding
+    } else {
+        return None;
+    };
+    if !is_immutable_binding(map, binding) {
+        return None;
+    }
+    let mut interval = if let Some(init) = let_init(map, binding) {
+        expr_interval(cx, init, depth)
+    } else {
+        Interval::of_target_type(cx, cx.tables.node_id_to_type(binding))
+    };
+
+    // look for the conditions of the `if`s and `while`s the expression is in
+    let mut child = id;
+    loop {
+        let parent = map.get_parent_node(child);
+        if parent == child {
+            break;
+        }
+        match map.find(parent) {
+            Some(NodeExpr(expr)) => {
+                let condition = match expr.node {
+                    ExprIf(ref cond, ref then, _) if then.id == child => Some((&**cond, true)),
+                    ExprIf(ref cond, _, Some(ref otherwise)) if otherwise.id == child => Some((&**cond, false)),
+                    ExprWhile(ref cond, ref body, _) if body.id == child => Some((&**cond, true)),
+                    _ => None,
+                };
+                if let (Some((cond, holds)), Some(current)) = (condition, interval) {
+                    interval = Some(constrain(cx, def_id, cond, holds, current, depth));
+                }
+            },
+            Some(NodeItem(_)) |
+            Some(NodeImplItem(_)) |
+            Some(NodeTraitItem(_)) |
+            None => break,
+            _ => (),
+        }
+        child = parent;
+    }
+    interval
+}
+
+/// Narrow the range of the local `def_id` knowing that `cond` is `holds`.
+fn constrain(cx: &LateContext, def_id: DefId, cond: &Expr, holds: bool, interval: Interval, depth: u32) -> Interval {
+    match cond.node {
+        ExprUnary(UnNot, ref operand) => constrain(cx, def_id, operand, !holds, interval, depth),
+        ExprBinary(op, ref left, ref right) => {
+            match (op.node, holds) {
+                (BiAnd, true) | (BiOr, false) => {
+                    let narrowed = constrain(cx, def_id, left, holds, interval, depth);
+                    constrain(cx, def_id, right, holds, narrowed, depth)
+                },
+                (cmp, _) => {
+                    if let Some((rel, lhs, rhs)) = normalize_comparison(cmp, left, right) {
+                        // the relation that holds
+                        let (rel, lhs, rhs) = match (rel, holds) {
+                            (_, true) => (rel, lhs, rhs),
+                            (Rel::Lt, false) => (Rel::Le, rhs, lhs),
+                            (Rel::Le, false) => (Rel::Lt, rhs, lhs),
+                            (Rel::Eq, false) => (Rel::Ne, lhs, rhs),
+                            (Rel::Ne, false) => (Rel::Eq, lhs, rhs),
+                        };
+                        constrain_comparison(cx, def_id, rel, lhs, rhs, interval, depth).unwrap_or(interval)
+                    } else {
+                        interval
+                    }
+                },
+            }
+        },
+        _ => interval,
+    }
+}
+
+/// Narrow the range of the local `def_id` knowing that `lhs rel rhs`, `None` if this tells
+/// nothing about it, or if it contradicts the range, in code that is never run.
+fn constrain_comparison(
+    cx: &LateContext,
+    def_id: DefId,
+    rel: Rel,
+    lhs: &Expr,
+    rhs: &Expr,
+    interval: Interval,
+    depth: u32
+) -> Option<Interval> {
+    let is_local = |e: &Expr| if let ExprPath(ref qpath) = e.node {
+        cx.tables.qpath_def(qpath, e.id) == Def::Local(def_id)
+    } else {
+        false
+    };
+    let (bound, local_is_lhs) = if is_local(lhs) {
+        (expr_interval(cx, rhs, depth), true)
+    } else if is_local(rhs) {
+        (expr_interval(cx, lhs, depth), false)
+    } else {
+        return None;
+    };
+    let bound = if let Some(bound) = bound {
+        bound
+    } else {
+        return None;
+    };
+
+    let narrowed = match (rel, local_is_lhs) {
+        (Rel::Lt, true) => bound.max.pred().map(|max| Interval::new(interval.min, max)),
+        (Rel::Le, true) => Some(Interval::new(interval.min, bound.max)),
+        (Rel::Lt, false) => bound.min.succ().map(|min| Interval::new(min, interval.max)),
+        (Rel::Le, false) => Some(Interval::new(bound.min, interval.max)),
+        (Rel::Eq, _) => Some(bound),
+        (Rel::Ne, _) if bound.is_constant() && bound.min == interval.min => {
+            interval.min.succ().map(|min| Interval::new(min, interval.max))
+        },
+        (Rel::Ne, _) if bound.is_constant() && bound.max == interval.max => {
+            interval.max.pred().map(|max| Interval::new(interval.min, max))
+        },
+        (Rel::Ne, _) => None,
+    };
+    narrowed.and_then(|narrowed| interval.intersect(&narrowed))
+}
@@ -2,7 +2,7 @@ use reexport::*;
 use rustc::hir::*;
 use rustc::hir::def_id::{DefId, CRATE_DEF_INDEX};
 use rustc::hir::def::Def;
-use rustc::hir::map::Node;
+use rustc::hir::map::{Map, Node};
 use rustc::lint::{LintContext, LateContext, Level, Lint};
 use rustc::session::Session;
 use rustc::traits::Reveal;
@@ -22,15 +22,22 @@ use syntax::codemap::{ExpnFormat, ExpnInfo, MultiSpan, Span, DUMMY_SP};
 use syntax::errors::DiagnosticBuilder;
 use syntax::ptr::P;
 use syntax::symbol::keywords;
+use self::sugg::{Applicability, SuggestionExt};
 
+pub mod catalogue;
 pub mod comparisons;
 pub mod conf;
 pub mod constants;
+pub mod dataflow;
+pub mod emitted;
 mod hir;
 pub mod paths;
+pub mod profile;
 pub mod sugg;
+pub mod suppression;
 pub mod inspector;
 pub mod internal_lints;
+pub mod interval;
 pub use self::hir::{SpanlessEq, SpanlessHash};
 
 pub type MethodArgs = HirVec<P<Expr>>;
@@ -395,6 +402,31 @@ pub fn snippet<'a, 'b, T: LintContext<'b>>(cx: &T, span: Span, default: &'a str)
     snippet_opt(cx, span).map_or_else(|| Cow::Borrowed(default), From::from)
 }
 
+/// Same as `snippet`, but adapts `applicability` to the result: a snippet from a macro expansion
+/// makes the suggestion `MaybeIncorrect`, and falling back to `default` makes it
+/// `HasPlaceholders`.
+///
+/// # Example
+/// ```rust,ignore
+/// let mut applicability = Applicability::MachineApplicable;
+/// let sugg = snippet_with_applicability(cx, expr.span, "..", &mut applicability);
+/// ```
+pub fn snippet_with_applicability<'a, 'b, T: LintContext<'b>>(
+    cx: &T,
+    span: Span,
+    default: &'a str,
+    applicability: &mut Applicability
+) -> Cow<'a, str> {
+    if in_macro(span) {
+        applicability.downgrade(Applicability::MaybeIncorrect);
+    }
+    snippet_opt(cx, span).map_or_else(|| {
+                                          applicability.downgrade(Applicability::HasPlaceholders);
+                                          Cow::Borrowed(default)
+                                      },
+                                      From::from)
+}
+
 /// Convert a span to a code snippet. Returns `None` if not available.
 pub fn snippet_opt<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Option<String> {
     cx.sess().codemap().span_to_snippet(span).ok()
@@ -413,6 +445,17 @@ pub fn snippet_block<'a, 'b, T: LintContext<'b>>(cx: &T, span: Span, default: &'
     trim_multiline(snip, true)
 }
 
+/// Same as `snippet_block`, but adapts `applicability` like `snippet_with_applicability` does.
+pub fn snippet_block_with_applicability<'a, 'b, T: LintContext<'b>>(
+    cx: &T,
+    span: Span,
+    default: &'a str,
+    applicability: &mut Applicability
+) -> Cow<'a, str> {
+    let snip = snippet_with_applicability(cx, span, default, applicability);
+    trim_multiline(snip, true)
+}
+
 /// Like `snippet_block`, but add braces if the expr is not an `ExprBlock`.
 /// Also takes an `Option<String>` which can be put inside the braces.
 pub fn expr_block<'a, 'b, T: LintContext<'b>>(
@@ -421,7 +464,19 @@ pub fn expr_block<'a, 'b, T: LintContext<'b>>(
     option: Option<String>,
     default: &'a str
 ) -> Cow<'a, str> {
-    let code = snippet_block(cx, expr.span, default);
+    let mut applicability = Applicability::Unspecified;
+    expr_block_with_applicability(cx, expr, option, default, &mut applicability)
+}
+
+/// Same as `expr_block`, but adapts `applicability` like `snippet_with_applicability` does.
+pub fn expr_block_with_applicability<'a, 'b, T: LintContext<'b>>(
+    cx: &T,
+    expr: &Expr,
+    option: Option<String>,
+    default: &'a str,
+    applicability: &mut Applicability
+) -> Cow<'a, str> {
+    let code = snippet_block_with_applicability(cx, expr.span, default, applicability);
     let string = option.unwrap_or_default();
     if let ExprBlock(_) = expr.node {
         Cow::Owned(format!("{}{}", code, string))
@@ -485,6 +540,30 @@ pub fn get_parent_expr<'c>(cx: &'c LateContext, e: &Expr) -> Option<&'c Expr> {
     })
 }
 
+/// Whether `id` is an immutable binding by value, like `x` in `let x = 42;` or `fn foo(x: u8)`,
+/// which keeps the value it was given.
+pub fn is_immutable_binding(map: &Map, id: NodeId) -> bool {
+    if let Some(Node::NodeLocal(pat)) = map.find(id) {
+        if let PatKind::Binding(BindByValue(MutImmutable), _, _, None) = pat.node {
+            return true;
+        }
+    }
+    false
+}
+
+/// Get the initializer of the `let` statement binding `id`, like `42` in `let x = 42;`.
+pub fn let_init<'hir>(map: &Map<'hir>, id: NodeId) -> Option<&'hir Expr> {
+    if_let_chain! {[
+        let Some(Node::NodeStmt(stmt)) = map.find(map.get_parent_node(id)),
+        let StmtDecl(ref decl, _) = stmt.node,
+        let DeclLocal(ref local) = decl.node,
+        local.pat.id == id,
+    ], {
+        return local.init.as_ref().map(|init| &**init);
+    }}
+    None
+}
+
 pub fn get_enclosing_block<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, node: NodeId) -> Option<&'tcx Block> {
     let map = &cx.tcx.hir;
     let enclosing_node = map.get_enclosing_scope(node)
@@ -522,9 +601,22 @@ impl<'a> DiagnosticWrapper<'a> {
     }
 }
 
+/// Whether a suppression comment allows `lint` at `sp`, see `utils::suppression`. Like the `allow`
+/// attribute, this cannot override `forbid`.
+fn is_suppressed<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, sp: Span) -> bool {
+    match cx.current_level(lint) {
+        Level::Allow | Level::Forbid => false,
+        Level::Warn | Level::Deny => suppression::suppresses(&lint.name_lower(), sp),
+    }
+}
+
 pub fn span_lint<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, sp: Span, msg: &str) {
+    if is_suppressed(cx, lint, sp) {
+        return;
+    }
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
     if cx.current_level(lint) != Level::Allow {
+        emitted::record(cx, lint, sp, msg);
         db.wiki_link(lint);
     }
 }
@@ -536,8 +628,12 @@ pub fn span_help_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     msg: &str,
     help: &str
 ) {
+    if is_suppressed(cx, lint, span) {
+        return;
+    }
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
     if cx.current_level(lint) != Level::Allow {
+        emitted::record(cx, lint, span, msg);
         db.0.help(help);
         db.wiki_link(lint);
     }
@@ -551,8 +647,12 @@ pub fn span_note_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     note_span: Span,
     note: &str
 ) {
+    if is_suppressed(cx, lint, span) {
+        return;
+    }
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
     if cx.current_level(lint) != Level::Allow {
+        emitted::record(cx, lint, span, msg);
         if note_span == span {
             db.0.note(note);
         } else {
@@ -570,8 +670,12 @@ pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
     f: F
 ) where F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>)
 {
+    if is_suppressed(cx, lint, sp) {
+        return;
+    }
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
     if cx.current_level(lint) != Level::Allow {
+        emitted::record(cx, lint, sp, msg);
         f(&mut db.0);
         db.wiki_link(lint);
     }
@@ -583,16 +687,25 @@ pub fn span_lint_and_sugg<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     sp: Span,
     msg: &str,
     help: &str,
-    sugg: String
+    sugg: String,
+    applicability: Applicability
 ) {
-    span_lint_and_then(cx, lint, sp, msg, |db| { db.span_suggestion(sp, help, sugg); });
+    span_lint_and_then(cx, lint, sp, msg, |db| {
+        db.span_suggestion_with_applicability(sp, help, sugg, applicability);
+    });
 }
 
 /// Create a suggestion made from several `span → replacement`.
 ///
 /// Note: in the JSON format (used by `compiletest_rs`), the help message will appear once per
 /// replacement. In human-readable format though, it only appears once before the whole suggestion.
-pub fn multispan_sugg(db: &mut DiagnosticBuilder, help_msg: String, sugg: Vec<(Span, String)>) {
+pub fn multispan_sugg(
+    db: &mut DiagnosticBuilder,
+    help_msg: String,
+    sugg: Vec<(Span, String)>,
+    applicability: Applicability
+) {
+    emitted::record_suggestion(applicability);
     let sugg = rustc_errors::RenderSpan::Suggestion(rustc_errors::CodeSuggestion {
         msp: MultiSpan::from_spans(sugg.iter().map(|&(span, _)| span).collect()),
         substitutes: sugg.into_iter().map(|(_, subs)| subs).collect(),
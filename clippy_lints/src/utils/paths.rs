@@ -1,7 +1,9 @@
 //! This module contains paths to types and functions Clippy needs to know about.
 
+pub const ARC: [&'static str; 3] = ["alloc", "arc", "Arc"];
 pub const ASREF_TRAIT: [&'static str; 3] = ["core", "convert", "AsRef"];
 pub const BEGIN_PANIC: [&'static str; 3] = ["std", "panicking", "begin_panic"];
+pub const BEGIN_PANIC_FMT: [&'static str; 3] = ["std", "panicking", "begin_panic_fmt"];
 pub const BINARY_HEAP: [&'static str; 3] = ["collections", "binary_heap", "BinaryHeap"];
 pub const BORROW_TRAIT: [&'static str; 3] = ["core", "borrow", "Borrow"];
 pub const BOX: [&'static str; 3] = ["std", "boxed", "Box"];
@@ -20,6 +22,7 @@ pub const DEBUG_FMT_METHOD: [&'static str; 4] = ["core", "fmt", "Debug", "fmt"];
 pub const DEFAULT_TRAIT: [&'static str; 3] = ["core", "default", "Default"];
 pub const DISPLAY_FMT_METHOD: [&'static str; 4] = ["core", "fmt", "Display", "fmt"];
 pub const DROP: [&'static str; 3] = ["core", "mem", "drop"];
+pub const EXIT: [&'static str; 3] = ["std", "process", "exit"];
 pub const FMT_ARGUMENTS_NEWV1: [&'static str; 4] = ["core", "fmt", "Arguments", "new_v1"];
 pub const FMT_ARGUMENTV1_NEW: [&'static str; 4] = ["core", "fmt", "ArgumentV1", "new"];
 pub const HASH: [&'static str; 2] = ["hash", "Hash"];
@@ -57,6 +60,7 @@ pub const RANGE_TO: [&'static str; 3] = ["core", "ops", "RangeTo"];
 pub const RANGE_TO_INCLUSIVE: [&'static str; 3] = ["core", "ops", "RangeToInclusive"];
 pub const RANGE_TO_INCLUSIVE_STD: [&'static str; 3] = ["std", "ops", "RangeToInclusive"];
 pub const RANGE_TO_STD: [&'static str; 3] = ["std", "ops", "RangeTo"];
+pub const RC: [&'static str; 3] = ["alloc", "rc", "Rc"];
 pub const REGEX: [&'static str; 3] = ["regex", "re_unicode", "Regex"];
 pub const REGEX_BUILDER_NEW: [&'static str; 5] = ["regex", "re_builder", "unicode", "RegexBuilder", "new"];
 pub const REGEX_BYTES: [&'static str; 3] = ["regex", "re_bytes", "Regex"];
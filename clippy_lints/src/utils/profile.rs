@@ -0,0 +1,214 @@
+//! Measure the time spent in each lint pass, for `cargo clippy --profile-lints`.
+//!
+//! When profiling is started before the passes are registered, `register_plugins` wraps each pass
+//! in a `Timed` pass that measures the time spent in the methods of the pass it wraps.
+
+#![deny(missing_docs_in_private_items)]
+
+use rustc::hir;
+use rustc::hir::intravisit;
+use rustc::lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintArray, LintPass};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use syntax::ast;
+use syntax::codemap::Span;
+use syntax::visit;
+
+/// The time spent in a lint pass.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PassTime {
+    /// The total time spent in the pass.
+    pub total: Duration,
+    /// The number of calls to the methods of the pass, that is the number of items, expressions,
+    /// etc. it checked.
+    pub calls: u64,
+}
+
+impl PassTime {
+    /// The total time spent in the pass, in nanoseconds.
+    pub fn nanos(&self) -> u64 {
+        self.total.as_secs() * 1_000_000_000 + u64::from(self.total.subsec_nanos())
+    }
+
+    /// The average time of a call to the pass, in nanoseconds.
+    pub fn nanos_per_call(&self) -> u64 {
+        if self.calls == 0 {
+            0
+        } else {
+            self.nanos() / self.calls
+        }
+    }
+}
+
+thread_local! {
+    /// The time of the passes registered so far, `None` unless profiling was started.
+    static PROFILE: RefCell<Option<Vec<(&'static str, Rc<Cell<PassTime>>)>>> = RefCell::new(None);
+}
+
+/// Start profiling the lint passes registered on the current thread.
+pub fn start() {
+    PROFILE.with(|profile| *profile.borrow_mut() = Some(Vec::new()));
+}
+
+/// Whether profiling was started.
+pub fn is_enabled() -> bool {
+    PROFILE.with(|profile| profile.borrow().is_some())
+}
+
+/// Stop profiling and return the time of each pass registered since `start` was called, slowest
+/// first.
+pub fn take() -> Vec<(&'static str, PassTime)> {
+    let passes = PROFILE.with(|profile| profile.borrow_mut().take().unwrap_or_default());
+    let mut times: Vec<_> = passes.into_iter().map(|(name, time)| (name, time.get())).collect();
+    times.sort_by(|&(_, ref a), &(_, ref b)| b.total.cmp(&a.total));
+    times
+}
+
+/// A lint pass that measures the time spent in the pass it wraps.
+pub struct Timed<P> {
+    /// The wrapped pass.
+    pass: Box<P>,
+    /// The time spent in `pass` so far, shared with `PROFILE`.
+    time: Rc<Cell<PassTime>>,
+}
+
+impl<P> Timed<P> {
+    /// Wrap `pass`, which is reported as `name`.
+    pub fn new(name: &'static str, pass: Box<P>) -> Self {
+        let time = Rc::new(Cell::new(PassTime::default()));
+        PROFILE.with(|profile| if let Some(ref mut passes) = *profile.borrow_mut() {
+            passes.push((name, time.clone()));
+        });
+        Timed {
+            pass: pass,
+            time: time,
+        }
+    }
+
+    /// Record a call to the pass that started at `start`.
+    fn record(&self, start: Instant) {
+        let mut time = self.time.get();
+        time.total += start.elapsed();
+        time.calls += 1;
+        self.time.set(time);
+    }
+}
+
+impl<P: LintPass> LintPass for Timed<P> {
+    fn get_lints(&self) -> LintArray {
+        self.pass.get_lints()
+    }
+}
+
+/// Implement the methods of a lint pass trait by timing the calls to the wrapped pass.
+macro_rules! timed_methods {
+    ($cx: ty; $($name: ident($($arg: ident: $ty: ty),*);)*) => {
+        $(
+            fn $name(&mut self, cx: &$cx, $($arg: $ty),*) {
+                let start = Instant::now();
+                self.pass.$name(cx, $($arg),*);
+                self.record(start);
+            }
+        )*
+    };
+}
+
+impl<'a, 'tcx, P: LateLintPass<'a, 'tcx>> LateLintPass<'a, 'tcx> for Timed<P> {
+    timed_methods! {
+        LateContext<'a, 'tcx>;
+        check_body(body: &'tcx hir::Body);
+        check_body_post(body: &'tcx hir::Body);
+        check_name(span: Span, name: ast::Name);
+        check_crate(krate: &'tcx hir::Crate);
+        check_crate_post(krate: &'tcx hir::Crate);
+        check_mod(module: &'tcx hir::Mod, span: Span, id: ast::NodeId);
+        check_mod_post(module: &'tcx hir::Mod, span: Span, id: ast::NodeId);
+        check_foreign_item(item: &'tcx hir::ForeignItem);
+        check_foreign_item_post(item: &'tcx hir::ForeignItem);
+        check_item(item: &'tcx hir::Item);
+        check_item_post(item: &'tcx hir::Item);
+        check_local(local: &'tcx hir::Local);
+        check_block(block: &'tcx hir::Block);
+        check_block_post(block: &'tcx hir::Block);
+        check_stmt(stmt: &'tcx hir::Stmt);
+        check_arm(arm: &'tcx hir::Arm);
+        check_pat(pat: &'tcx hir::Pat);
+        check_decl(decl: &'tcx hir::Decl);
+        check_expr(expr: &'tcx hir::Expr);
+        check_expr_post(expr: &'tcx hir::Expr);
+        check_ty(ty: &'tcx hir::Ty);
+        check_generics(generics: &'tcx hir::Generics);
+        check_fn(kind: intravisit::FnKind<'tcx>,
+                 decl: &'tcx hir::FnDecl,
+                 body: &'tcx hir::Body,
+                 span: Span,
+                 id: ast::NodeId);
+        check_fn_post(kind: intravisit::FnKind<'tcx>,
+                      decl: &'tcx hir::FnDecl,
+                      body: &'tcx hir::Body,
+                      span: Span,
+                      id: ast::NodeId);
+        check_trait_item(item: &'tcx hir::TraitItem);
+        check_trait_item_post(item: &'tcx hir::TraitItem);
+        check_impl_item(item: &'tcx hir::ImplItem);
+        check_impl_item_post(item: &'tcx hir::ImplItem);
+        check_struct_def(def: &'tcx hir::VariantData, name: ast::Name, generics: &'tcx hir::Generics, id: ast::NodeId);
+        check_struct_def_post(def: &'tcx hir::VariantData,
+                              name: ast::Name,
+                              generics: &'tcx hir::Generics,
+                              id: ast::NodeId);
+        check_struct_field(field: &'tcx hir::StructField);
+        check_variant(variant: &'tcx hir::Variant, generics: &'tcx hir::Generics);
+        check_variant_post(variant: &'tcx hir::Variant, generics: &'tcx hir::Generics);
+        check_lifetime(lifetime: &'tcx hir::Lifetime);
+        check_lifetime_def(lifetime: &'tcx hir::LifetimeDef);
+        check_path(path: &'tcx hir::Path, id: ast::NodeId);
+        check_attribute(attr: &'tcx ast::Attribute);
+        enter_lint_attrs(attrs: &'tcx [ast::Attribute]);
+        exit_lint_attrs(attrs: &'tcx [ast::Attribute]);
+    }
+}
+
+impl<P: EarlyLintPass> EarlyLintPass for Timed<P> {
+    timed_methods! {
+        EarlyContext;
+        check_ident(span: Span, ident: ast::Ident);
+        check_crate(krate: &ast::Crate);
+        check_crate_post(krate: &ast::Crate);
+        check_mod(module: &ast::Mod, span: Span, id: ast::NodeId);
+        check_mod_post(module: &ast::Mod, span: Span, id: ast::NodeId);
+        check_foreign_item(item: &ast::ForeignItem);
+        check_foreign_item_post(item: &ast::ForeignItem);
+        check_item(item: &ast::Item);
+        check_item_post(item: &ast::Item);
+        check_local(local: &ast::Local);
+        check_block(block: &ast::Block);
+        check_block_post(block: &ast::Block);
+        check_stmt(stmt: &ast::Stmt);
+        check_arm(arm: &ast::Arm);
+        check_pat(pat: &ast::Pat);
+        check_expr(expr: &ast::Expr);
+        check_expr_post(expr: &ast::Expr);
+        check_ty(ty: &ast::Ty);
+        check_generics(generics: &ast::Generics);
+        check_fn(kind: visit::FnKind, decl: &ast::FnDecl, span: Span, id: ast::NodeId);
+        check_fn_post(kind: visit::FnKind, decl: &ast::FnDecl, span: Span, id: ast::NodeId);
+        check_trait_item(item: &ast::TraitItem);
+        check_trait_item_post(item: &ast::TraitItem);
+        check_impl_item(item: &ast::ImplItem);
+        check_impl_item_post(item: &ast::ImplItem);
+        check_struct_def(def: &ast::VariantData, ident: ast::Ident, generics: &ast::Generics, id: ast::NodeId);
+        check_struct_def_post(def: &ast::VariantData, ident: ast::Ident, generics: &ast::Generics, id: ast::NodeId);
+        check_struct_field(field: &ast::StructField);
+        check_variant(variant: &ast::Variant, generics: &ast::Generics);
+        check_variant_post(variant: &ast::Variant, generics: &ast::Generics);
+        check_lifetime(lifetime: &ast::Lifetime);
+        check_lifetime_def(lifetime: &ast::LifetimeDef);
+        check_path(path: &ast::Path, id: ast::NodeId);
+        check_path_list_item(item: &ast::PathListItem);
+        check_attribute(attr: &ast::Attribute);
+        enter_lint_attrs(attrs: &[ast::Attribute]);
+        exit_lint_attrs(attrs: &[ast::Attribute]);
+    }
+}
@@ -11,7 +11,7 @@ use syntax::codemap::{CharPos, Span};
 use syntax::print::pprust::binop_to_string;
 use syntax::util::parser::AssocOp;
 use syntax::ast;
-use utils::{higher, snippet, snippet_opt};
+use utils::{emitted, higher, in_macro, snippet_opt, snippet_with_applicability};
 
 /// A helper type to build suggestion correctly handling parenthesis.
 pub enum Sugg<'a> {
@@ -23,6 +23,49 @@ pub enum Sugg<'a> {
     BinOp(AssocOp, Cow<'a, str>),
 }
 
+/// How confident a lint is that a suggestion is correct.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended and can be applied automatically.
+    MachineApplicable,
+    /// The suggestion may be incorrect or may not be what the user intended.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders like `..` that the user needs to fill in.
+    HasPlaceholders,
+    /// Nothing is known about the suggestion.
+    Unspecified,
+}
+
+impl Applicability {
+    /// Lower the confidence in a suggestion to `other`, unless it is already lower.
+    pub fn downgrade(&mut self, other: Applicability) {
+        if other > *self {
+            *self = other;
+        }
+    }
+
+    /// The name of the applicability, as shown in the JSON output of `cargo clippy`.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Applicability::MachineApplicable => "MachineApplicable",
+            Applicability::MaybeIncorrect => "MaybeIncorrect",
+            Applicability::HasPlaceholders => "HasPlaceholders",
+            Applicability::Unspecified => "Unspecified",
+        }
+    }
+
+    /// The applicability named `name` by `as_str`.
+    pub fn from_name(name: &str) -> Option<Applicability> {
+        match name {
+            "MachineApplicable" => Some(Applicability::MachineApplicable),
+            "MaybeIncorrect" => Some(Applicability::MaybeIncorrect),
+            "HasPlaceholders" => Some(Applicability::HasPlaceholders),
+            "Unspecified" => Some(Applicability::Unspecified),
+            _ => None,
+        }
+    }
+}
+
 /// Literal constant `1`, for convenience.
 pub const ONE: Sugg<'static> = Sugg::NonParen(Cow::Borrowed("1"));
 
@@ -81,11 +124,40 @@ impl<'a> Sugg<'a> {
         Self::hir_opt(cx, expr).unwrap_or_else(|| Sugg::NonParen(Cow::Borrowed(default)))
     }
 
+    /// Same as `hir`, but adapts `applicability` to the result like `snippet_with_applicability`
+    /// does.
+    pub fn hir_with_applicability(
+        cx: &LateContext,
+        expr: &hir::Expr,
+        default: &'a str,
+        applicability: &mut Applicability
+    ) -> Sugg<'a> {
+        if in_macro(expr.span) {
+            applicability.downgrade(Applicability::MaybeIncorrect);
+        }
+        Self::hir_opt(cx, expr).unwrap_or_else(|| {
+            applicability.downgrade(Applicability::HasPlaceholders);
+            Sugg::NonParen(Cow::Borrowed(default))
+        })
+    }
+
     /// Prepare a suggestion from an expression.
     pub fn ast(cx: &EarlyContext, expr: &ast::Expr, default: &'a str) -> Sugg<'a> {
+        // `Unspecified` is never downgraded
+        Self::ast_with_applicability(cx, expr, default, &mut Applicability::Unspecified)
+    }
+
+    /// Same as `ast`, but adapts `applicability` to the result like `snippet_with_applicability`
+    /// does.
+    pub fn ast_with_applicability(
+        cx: &EarlyContext,
+        expr: &ast::Expr,
+        default: &'a str,
+        applicability: &mut Applicability
+    ) -> Sugg<'a> {
         use syntax::ast::RangeLimits;
 
-        let snippet = snippet(cx, expr.span, default);
+        let snippet = snippet_with_applicability(cx, expr.span, default, applicability);
 
         match expr.node {
             ast::ExprKind::AddrOf(..) |
@@ -392,6 +464,38 @@ fn indentation<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Option<String> {
     }
 }
 
+/// Extension trait to make suggestions together with their `Applicability`.
+pub trait SuggestionExt {
+    /// Suggest to replace `span` with `sugg`, recording how confident the lint is that the
+    /// suggestion is correct.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.span_suggestion_with_applicability(expr.span, "try", sugg, Applicability::MachineApplicable);
+    /// ```
+    fn span_suggestion_with_applicability(
+        &mut self,
+        span: Span,
+        msg: &str,
+        sugg: String,
+        applicability: Applicability
+    );
+}
+
+impl<'a> SuggestionExt for rustc_errors::DiagnosticBuilder<'a> {
+    fn span_suggestion_with_applicability(
+        &mut self,
+        span: Span,
+        msg: &str,
+        sugg: String,
+        applicability: Applicability
+    ) {
+        emitted::record_suggestion(applicability);
+        self.span_suggestion(span, msg, sugg);
+    }
+}
+
 /// Convenience extension trait for `DiagnosticBuilder`.
 pub trait DiagnosticBuilderExt<'a, T: LintContext<'a>> {
     /// Suggests to add an attribute to an item.
@@ -401,9 +505,16 @@ pub trait DiagnosticBuilderExt<'a, T: LintContext<'a>> {
     /// # Example
     ///
     /// ```rust,ignore
-    /// db.suggest_item_with_attr(cx, item, "#[derive(Default)]");
+    /// db.suggest_item_with_attr(cx, item, "#[derive(Default)]", Applicability::MaybeIncorrect);
     /// ```
-    fn suggest_item_with_attr<D: Display + ?Sized>(&mut self, cx: &T, item: Span, msg: &str, attr: &D);
+    fn suggest_item_with_attr<D: Display + ?Sized>(
+        &mut self,
+        cx: &T,
+        item: Span,
+        msg: &str,
+        attr: &D,
+        applicability: Applicability
+    );
 
     /// Suggest to add an item before another.
     ///
@@ -415,21 +526,28 @@ pub trait DiagnosticBuilderExt<'a, T: LintContext<'a>> {
     /// db.suggest_prepend_item(cx, item,
     /// "fn foo() {
     ///     bar();
-    /// }");
+    /// }", Applicability::MaybeIncorrect);
     /// ```
-    fn suggest_prepend_item(&mut self, cx: &T, item: Span, msg: &str, new_item: &str);
+    fn suggest_prepend_item(&mut self, cx: &T, item: Span, msg: &str, new_item: &str, applicability: Applicability);
 }
 
 impl<'a, 'b, 'c, T: LintContext<'c>> DiagnosticBuilderExt<'c, T> for rustc_errors::DiagnosticBuilder<'b> {
-    fn suggest_item_with_attr<D: Display + ?Sized>(&mut self, cx: &T, item: Span, msg: &str, attr: &D) {
+    fn suggest_item_with_attr<D: Display + ?Sized>(
+        &mut self,
+        cx: &T,
+        item: Span,
+        msg: &str,
+        attr: &D,
+        applicability: Applicability
+    ) {
         if let Some(indent) = indentation(cx, item) {
             let span = Span { hi: item.lo, ..item };
 
-            self.span_suggestion(span, msg, format!("{}\n{}", attr, indent));
+            self.span_suggestion_with_applicability(span, msg, format!("{}\n{}", attr, indent), applicability);
         }
     }
 
-    fn suggest_prepend_item(&mut self, cx: &T, item: Span, msg: &str, new_item: &str) {
+    fn suggest_prepend_item(&mut self, cx: &T, item: Span, msg: &str, new_item: &str, applicability: Applicability) {
         if let Some(indent) = indentation(cx, item) {
             let span = Span { hi: item.lo, ..item };
 
@@ -443,7 +561,7 @@ impl<'a, 'b, 'c, T: LintContext<'c>> DiagnosticBuilderExt<'c, T> for rustc_error
                 })
                 .collect::<String>();
 
-            self.span_suggestion(span, msg, format!("{}\n{}", new_item, indent));
+            self.span_suggestion_with_applicability(span, msg, format!("{}\n{}", new_item, indent), applicability);
         }
     }
 }
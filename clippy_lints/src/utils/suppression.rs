@@ -0,0 +1,173 @@
+//! Keep track of the suppression comments of the crate, `// clippy: allow(lint_name) reason="..."`,
+//! which allow Clippy lints on the statement or item that follows them.
+//!
+//! Attributes cannot be put on every statement or expression, so the `suppression` early lint
+//! pass collects these comments before any lint runs and installs them here. The `span_lint*`
+//! helpers look them up before emitting a lint, and the `cargo-clippy` driver takes them at the
+//! end of the compilation to report them, together with the `#[allow(..)]` attributes of Clippy
+//! lints that `attrs` records.
+
+#![deny(missing_docs_in_private_items)]
+
+use std::cell::RefCell;
+use syntax::codemap::Span;
+use utils::catalogue;
+
+/// How Clippy lints were allowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// A `// clippy: allow(..)` comment.
+    Comment,
+    /// An `#[allow(..)]` or `#![allow(..)]` attribute.
+    Attribute,
+}
+
+impl Kind {
+    /// The name of the kind, as in the output of the driver.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Kind::Comment => "comment",
+            Kind::Attribute => "attribute",
+        }
+    }
+}
+
+/// A suppression comment and the statement or item it applies to, or a lint attribute.
+#[derive(Clone, Debug)]
+pub struct Suppression {
+    /// Whether this is a comment or an attribute.
+    pub kind: Kind,
+    /// The lints and lint groups allowed, as written. For attributes, only Clippy's are kept.
+    pub lints: Vec<String>,
+    /// The justification given with `reason="..."` or, for attributes, in a comment next to them.
+    pub reason: Option<String>,
+    /// The span of the comment or attribute.
+    pub span: Span,
+    /// The span of the statement or item a comment applies to, `None` if nothing follows it and
+    /// for attributes.
+    pub target: Option<Span>,
+    /// The name of the file containing the comment or attribute, as known by the codemap.
+    pub file_name: String,
+    /// The line of the comment or attribute, starting at 1.
+    pub line: usize,
+    /// Whether each of `lints` suppressed a lint. This is not tracked for attributes, for which it
+    /// is empty.
+    pub used: Vec<bool>,
+}
+
+thread_local! {
+    /// The suppression comments and lint attributes of the crate being linted.
+    static SUPPRESSIONS: RefCell<Vec<Suppression>> = RefCell::new(Vec::new());
+}
+
+/// Replace the suppressions known on the current thread with the comments of a new crate.
+pub fn install(suppressions: Vec<Suppression>) {
+    SUPPRESSIONS.with(|known| *known.borrow_mut() = suppressions);
+}
+
+/// Record a lint attribute, unless it already was.
+pub fn record(suppression: Suppression) {
+    SUPPRESSIONS.with(|known| {
+        let mut known = known.borrow_mut();
+        if !known.iter().any(|other| other.span == suppression.span) {
+            known.push(suppression);
+        }
+    });
+}
+
+/// Call `f` with the suppressions known on the current thread.
+pub fn with<F: FnOnce(&[Suppression]) -> T, T>(f: F) -> T {
+    SUPPRESSIONS.with(|known| f(&known.borrow()))
+}
+
+/// Remove and return the suppressions known on the current thread.
+pub fn take() -> Vec<Suppression> {
+    SUPPRESSIONS.with(|known| known.borrow_mut().drain(..).collect())
+}
+
+/// Whether a suppression comment allows the lint `lint` (in lowercase) at `span`, in which case
+/// the suppression is marked as used.
+pub fn suppresses(lint: &str, span: Span) -> bool {
+    SUPPRESSIONS.with(|known| {
+        let mut suppressed = false;
+        for suppression in known.borrow_mut().iter_mut() {
+            if !suppression.target.map_or(false, |target| contains(target, span)) {
+                continue;
+            }
+            for (name, used) in suppression.lints.iter().zip(&mut suppression.used) {
+                if allows(name, lint) {
+                    *used = true;
+                    suppressed = true;
+                }
+            }
+        }
+        suppressed
+    })
+}
+
+/// Whether `name`, as written in a suppression comment, is the lint `lint` or one of its groups.
+fn allows(name: &str, lint: &str) -> bool {
+    let name = name.replace('-', "_").to_lowercase();
+    name == lint || catalogue::find_group(&name).map_or(false, |group| group.lints.contains(&lint))
+}
+
+/// Whether `span`, or the macro call it was expanded from, is in `target`.
+fn contains(target: Span, span: Span) -> bool {
+    let mut span = span;
+    loop {
+        if target.lo <= span.lo && span.hi <= target.hi {
+            return true;
+        }
+        if let Some(info) = span.ctxt.outer().expn_info() {
+            span = info.call_site;
+        } else {
+            return false;
+        }
+    }
+}
+
+/// Parse the text of a `//` comment, without the slashes.
+///
+/// Returns `None` if it is not a suppression comment, that is if it does not start with
+/// `clippy:`, `Some(Err(()))` if it is a malformed one, and the lints and the reason otherwise.
+pub fn parse(comment: &str) -> Option<Result<(Vec<String>, Option<String>), ()>> {
+    let comment = comment.trim();
+    if !comment.starts_with("clippy:") {
+        return None;
+    }
+    Some(parse_allow(comment["clippy:".len()..].trim_left()))
+}
+
+/// Parse `allow(lint_name, ...) reason="..."`, the reason being optional.
+fn parse_allow(text: &str) -> Result<(Vec<String>, Option<String>), ()> {
+    if !text.starts_with("allow(") {
+        return Err(());
+    }
+    let text = &text["allow(".len()..];
+    let close = text.find(')').ok_or(())?;
+    let lints: Vec<_> = text[..close].split(',').map(str::trim).map(str::to_owned).collect();
+    if lints.iter().any(|lint| lint.is_empty() || !lint.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-')) {
+        return Err(());
+    }
+
+    let rest = text[close + 1..].trim();
+    if rest.is_empty() {
+        return Ok((lints, None));
+    }
+    if !rest.starts_with("reason") {
+        return Err(());
+    }
+    let rest = rest["reason".len()..].trim_left();
+    if !rest.starts_with('=') {
+        return Err(());
+    }
+    let rest = rest[1..].trim_left();
+    if rest.len() < 2 || !rest.starts_with('"') || !rest.ends_with('"') {
+        return Err(());
+    }
+    let reason = &rest[1..rest.len() - 1];
+    if reason.contains('"') {
+        return Err(());
+    }
+    Ok((lints, Some(reason.to_owned())))
+}
@@ -3,7 +3,8 @@ use rustc::lint::*;
 use rustc::ty;
 use rustc_const_eval::ConstContext;
 use syntax::codemap::Span;
-use utils::{higher, is_copy, snippet, span_lint_and_then};
+use utils::{higher, is_copy, snippet_with_applicability, span_lint_and_then};
+use utils::sugg::{Applicability, SuggestionExt};
 
 /// **What it does:** Checks for usage of `&vec![..]` when using `&[..]` would
 /// be possible.
@@ -57,10 +58,14 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
 }
 
 fn check_vec_macro(cx: &LateContext, vec_args: &higher::VecArgs, span: Span) {
+    let mut applicability = Applicability::MachineApplicable;
     let snippet = match *vec_args {
         higher::VecArgs::Repeat(elem, len) => {
             if ConstContext::with_tables(cx.tcx, cx.tables).eval(len).is_ok() {
-                format!("&[{}; {}]", snippet(cx, elem.span, "elem"), snippet(cx, len.span, "len")).into()
+                format!("&[{}; {}]",
+                        snippet_with_applicability(cx, elem.span, "elem", &mut applicability),
+                        snippet_with_applicability(cx, len.span, "len", &mut applicability))
+                    .into()
             } else {
                 return;
             }
@@ -73,7 +78,7 @@ fn check_vec_macro(cx: &LateContext, vec_args: &higher::VecArgs, span: Span) {
                     ctxt: args[0].span.ctxt,
                 };
 
-                format!("&[{}]", snippet(cx, span, "..")).into()
+                format!("&[{}]", snippet_with_applicability(cx, span, "..", &mut applicability)).into()
             } else {
                 "&[]".into()
             }
@@ -84,7 +89,12 @@ fn check_vec_macro(cx: &LateContext, vec_args: &higher::VecArgs, span: Span) {
                        USELESS_VEC,
                        span,
                        "useless use of `vec!`",
-                       |db| { db.span_suggestion(span, "you can use a slice directly", snippet); });
+                       |db| {
+                           db.span_suggestion_with_applicability(span,
+                                                                 "you can use a slice directly",
+                                                                 snippet,
+                                                                 applicability);
+                       });
 }
 
 /// Return the item type of the vector (ie. the `T` in `Vec<T>`).
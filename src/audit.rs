@@ -0,0 +1,95 @@
+//! `cargo clippy --suppressions`, which lists where Clippy lints are allowed, by `#[allow(..)]`
+//! attributes and `// clippy: allow(..)` comments, with the reason given for each, for audits.
+//!
+//! Like for the summary, each `cargo rustc` child process saves the suppressions of its crate in a
+//! directory shared by the whole run, and `cargo clippy` lists them once every package was linted.
+
+use diagnostics::Suppression;
+use serde_json;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// Save the suppressions of a crate in `dir`, next to those of the other crates.
+pub fn save(dir: &Path, suppressions: &[Suppression]) -> io::Result<()> {
+    let index = fs::read_dir(dir)?.count();
+    let json = serde_json::to_string(suppressions).expect("suppressions can always be serialized");
+    File::create(dir.join(format!("suppressions-{}.json", index)))?.write_all(json.as_bytes())
+}
+
+/// Read the suppressions saved in `dir`.
+pub fn load_all(dir: &Path) -> Result<Vec<Suppression>, String> {
+    let mut all = Vec::new();
+    let entries = fs::read_dir(dir).map_err(|err| format!("could not read `{}`: {}", dir.display(), err))?;
+    for entry in entries {
+        let path = entry.map_err(|err| format!("could not read `{}`: {}", dir.display(), err))?.path();
+        let mut content = String::new();
+        File::open(&path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .map_err(|err| format!("could not read `{}`: {}", path.display(), err))?;
+        let suppressions: Vec<Suppression> = serde_json::from_str(&content)
+            .map_err(|err| format!("could not parse `{}`: {}", path.display(), err))?;
+        all.extend(suppressions);
+    }
+    Ok(all)
+}
+
+/// A line of the report: one lint allowed somewhere.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Row<'a> {
+    file_name: &'a str,
+    line: usize,
+    lint: &'a str,
+    kind: &'a str,
+    reason: Option<&'a str>,
+    unused: bool,
+}
+
+/// Print every allowed lint with its location, the kind of suppression and its reason on the
+/// standard error, sorted by location. A file linted as part of several targets is listed once.
+pub fn print(suppressions: &[Suppression]) {
+    let mut rows: Vec<_> = suppressions.iter()
+        .flat_map(|suppression| {
+            suppression.lints.iter().map(move |lint| {
+                Row {
+                    file_name: &suppression.file_name,
+                    line: suppression.line,
+                    lint: lint,
+                    kind: &suppression.kind,
+                    reason: suppression.reason.as_ref().map(AsRef::as_ref),
+                    unused: suppression.unused.contains(lint),
+                }
+            })
+        })
+        .collect();
+    rows.sort();
+    rows.dedup_by(|a, b| (a.file_name, a.line, a.lint) == (b.file_name, b.line, b.lint));
+
+    let stderr = io::stderr();
+    let mut handle = stderr.lock();
+    let without_reason = rows.iter().filter(|row| row.reason.is_none()).count();
+    let _ = writeln!(handle,
+                     "Clippy found {} allowed lints, {} of them without a reason",
+                     rows.len(),
+                     without_reason);
+    if rows.is_empty() {
+        return;
+    }
+
+    let locations: Vec<_> = rows.iter().map(|row| format!("{}:{}", row.file_name, row.line)).collect();
+    let location_width = locations.iter().map(String::len).max().unwrap_or(0);
+    let lint_width = rows.iter().map(|row| row.lint.len()).max().unwrap_or(0);
+    let _ = writeln!(handle);
+    for (row, location) in rows.iter().zip(&locations) {
+        let reason = row.reason.map_or_else(|| "(no reason)".to_owned(), |reason| format!("\"{}\"", reason));
+        let _ = writeln!(handle,
+                         "{:<location_width$}  {:<lint_width$}  {:<9}  {}{}",
+                         location,
+                         row.lint,
+                         row.kind,
+                         reason,
+                         if row.unused { " (unused)" } else { "" },
+                         location_width = location_width,
+                         lint_width = lint_width);
+    }
+}
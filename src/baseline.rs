@@ -0,0 +1,374 @@
+//! Baselines of known warnings, for `cargo clippy --write-baseline` and `cargo clippy --baseline`.
+//!
+//! A baseline lists the Clippy warnings of a code base at some point, so that only new warnings
+//! are reported afterwards. Warnings are identified by their lint, their package and file and a
+//! fingerprint of the flagged code that does not depend on its position, so that unrelated changes
+//! to the file do not invalidate the baseline. File names are relative to their package, so the
+//! package tells apart the `src/lib.rs` of the members of a workspace.
+//!
+//! Each `cargo rustc` child process lints one crate, so the driver writes a `Report` of the
+//! warnings it found in a directory shared by the whole run, and `cargo clippy` combines them once
+//! every package was linted.
+
+use diagnostics::{Diagnostic, DiagnosticSpan};
+use rustc::session::Session;
+use serde_json;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The version of the baseline file format.
+const VERSION: u32 = 2;
+
+/// What `cargo clippy` does with a baseline file.
+pub enum Mode {
+    /// Only report the warnings that are not in the baseline (`--baseline`).
+    Check(PathBuf),
+    /// Record the current warnings in the baseline (`--write-baseline`).
+    Write(PathBuf),
+}
+
+/// A warning, as recorded in a baseline file.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Entry {
+    pub lint: String,
+    pub package: String,
+    pub file: String,
+    pub fingerprint: String,
+}
+
+/// The content of a baseline file.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub warnings: Vec<Entry>,
+}
+
+/// The warnings found while linting one crate, before any was suppressed.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Report {
+    /// The source files of the crate, so that entries of other crates are not reported as stale.
+    pub files: Vec<SourceFile>,
+    pub warnings: Vec<Entry>,
+}
+
+/// A source file of a package.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SourceFile {
+    pub package: String,
+    pub file: String,
+}
+
+impl Entry {
+    /// The baseline entry of a diagnostic of `package`, if it was produced by Clippy.
+    pub fn from_diagnostic(package: &str, diagnostic: &Diagnostic) -> Option<Entry> {
+        match (diagnostic.lint.as_ref(), diagnostic.primary_span()) {
+            (Some(lint), Some(span)) => {
+                let code = flagged_code(span);
+                let hash = fnv1a(&[lint.as_bytes(), diagnostic.message.as_bytes(), code.as_bytes()]);
+                Some(Entry {
+                    lint: lint.clone(),
+                    package: package.to_owned(),
+                    file: span.file_name.clone(),
+                    fingerprint: format!("{:016x}", hash),
+                })
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The code highlighted by `span`, with whitespace normalized.
+fn flagged_code(span: &DiagnosticSpan) -> String {
+    let code = span.text
+        .iter()
+        .map(|line| {
+            // columns start at 1
+            let start = line.highlight_start.saturating_sub(1);
+            let len = line.highlight_end.saturating_sub(line.highlight_start);
+            line.text.chars().skip(start).take(len).collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    code.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The 64-bit FNV-1a hash of `parts`. Unlike `DefaultHasher`, it is guaranteed to stay the same
+/// across Rust versions, which matters for a hash stored in a file.
+pub fn fnv1a(parts: &[&[u8]]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    for part in parts {
+        for &byte in part.iter().chain(&[0]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+/// Read a baseline file.
+pub fn read(path: &Path) -> Result<Baseline, String> {
+    let mut content = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .map_err(|err| format!("could not read baseline `{}`: {}", path.display(), err))?;
+    let baseline: Baseline = serde_json::from_str(&content)
+        .map_err(|err| format!("could not parse baseline `{}`: {}", path.display(), err))?;
+    if baseline.version != VERSION {
+        return Err(format!("unsupported baseline version {} in `{}`, expected {}",
+                           baseline.version,
+                           path.display(),
+                           VERSION));
+    }
+    Ok(baseline)
+}
+
+/// Write a baseline file with the given warnings.
+pub fn write(path: &Path, mut warnings: Vec<Entry>) -> io::Result<()> {
+    warnings.sort();
+    let baseline = Baseline {
+        version: VERSION,
+        warnings: warnings,
+    };
+    let json = serde_json::to_string_pretty(&baseline).expect("baselines can always be serialized");
+    File::create(path)?.write_all(json.as_bytes())
+}
+
+/// Count how many times each entry appears.
+fn count(entries: &[Entry]) -> BTreeMap<&Entry, usize> {
+    let mut counts = BTreeMap::new();
+    for entry in entries {
+        *counts.entry(entry).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Remove the diagnostics of `package` that are in the baseline. A baseline entry suppresses only
+/// one diagnostic, so that new occurrences of a known warning are still reported.
+pub fn suppress(diagnostics: &mut Vec<Diagnostic>, package: &str, baseline: &Baseline) {
+    let mut known = count(&baseline.warnings);
+    diagnostics.retain(|diagnostic| {
+        let entry = if let Some(entry) = Entry::from_diagnostic(package, diagnostic) {
+            entry
+        } else {
+            return true;
+        };
+        match known.get_mut(&entry) {
+            Some(remaining) if *remaining > 0 => {
+                *remaining -= 1;
+                false
+            },
+            _ => true,
+        }
+    });
+}
+
+/// The entries of the baseline that no warning of `report` matches, among the files that were
+/// linted.
+pub fn stale(baseline: &Baseline, report: &Report) -> Vec<Entry> {
+    let files: BTreeSet<_> = report.files.iter().map(|file| (&file.package, &file.file)).collect();
+    let mut found = count(&report.warnings);
+    baseline.warnings
+        .iter()
+        .filter(|entry| files.contains(&(&entry.package, &entry.file)))
+        .filter(|entry| match found.get_mut(entry) {
+            Some(remaining) if *remaining > 0 => {
+                *remaining -= 1;
+                false
+            },
+            _ => true,
+        })
+        .cloned()
+        .collect()
+}
+
+/// The source files of the crate compiled by `sess`.
+pub fn source_files(sess: &Session) -> Vec<String> {
+    sess.codemap()
+        .files()
+        .iter()
+        .filter(|file| file.is_real_file() && !file.is_imported())
+        .map(|file| file.name.clone())
+        .collect()
+}
+
+/// The name of the package being linted, which cargo passes down to the compiler. It is empty when
+/// the driver is not run by cargo.
+pub fn package() -> String {
+    ::std::env::var("CARGO_PKG_NAME").unwrap_or_default()
+}
+
+impl Report {
+    /// The report of the crate of `package` made of the source files `files`.
+    pub fn new(package: &str, files: Vec<String>, diagnostics: &[Diagnostic]) -> Report {
+        Report {
+            files: files.into_iter()
+                .map(|file| {
+                    SourceFile {
+                        package: package.to_owned(),
+                        file: file,
+                    }
+                })
+                .collect(),
+            warnings: diagnostics.iter().filter_map(|diagnostic| Entry::from_diagnostic(package, diagnostic)).collect(),
+        }
+    }
+
+    /// Save the report in `dir`, next to the reports of the other crates.
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        let index = fs::read_dir(dir)?.count();
+        let json = serde_json::to_string(self).expect("reports can always be serialized");
+        File::create(dir.join(format!("report-{}.json", index)))?.write_all(json.as_bytes())
+    }
+
+    /// Combine the reports saved in `dir`.
+    pub fn load_all(dir: &Path) -> Result<Report, String> {
+        let mut all = Report::default();
+        let entries = fs::read_dir(dir).map_err(|err| format!("could not read `{}`: {}", dir.display(), err))?;
+        for entry in entries {
+            let path = entry.map_err(|err| format!("could not read `{}`: {}", dir.display(), err))?.path();
+            let mut content = String::new();
+            File::open(&path)
+                .and_then(|mut file| file.read_to_string(&mut content))
+                .map_err(|err| format!("could not read `{}`: {}", path.display(), err))?;
+            let report: Report = serde_json::from_str(&content)
+                .map_err(|err| format!("could not parse `{}`: {}", path.display(), err))?;
+            all.files.extend(report.files);
+            all.warnings.extend(report.warnings);
+        }
+        Ok(all)
+    }
+}
+
+/// A new directory for the reports of the `cargo rustc` child processes.
+pub fn report_dir() -> io::Result<PathBuf> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let dir = ::std::env::temp_dir().join(format!("cargo-clippy-{}-{}", now.as_secs(), now.subsec_nanos()));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod test {
+    use diagnostics::{Diagnostic, DiagnosticSpan, DiagnosticSpanLine};
+    use super::{fnv1a, flagged_code, stale, suppress, Baseline, Entry, Report, VERSION};
+
+    fn span(file_name: &str, lines: &[(&str, usize, usize)]) -> DiagnosticSpan {
+        DiagnosticSpan {
+            file_name: file_name.to_owned(),
+            byte_start: 0,
+            byte_end: 0,
+            line_start: 1,
+            line_end: lines.len(),
+            column_start: 1,
+            column_end: 1,
+            is_primary: true,
+            text: lines.iter()
+                .map(|&(text, start, end)| {
+                    DiagnosticSpanLine {
+                        text: text.to_owned(),
+                        highlight_start: start,
+                        highlight_end: end,
+                    }
+                })
+                .collect(),
+            label: None,
+            suggested_replacement: None,
+        }
+    }
+
+    fn diagnostic(lint: Option<&str>, file_name: &str, code: &str) -> Diagnostic {
+        Diagnostic {
+            message: "this is a warning".to_owned(),
+            code: None,
+            level: "warning".to_owned(),
+            spans: vec![span(file_name, &[(code, 1, code.len() + 1)])],
+            children: Vec::new(),
+            rendered: None,
+            lint: lint.map(str::to_owned),
+            group: None,
+            applicability: None,
+        }
+    }
+
+    fn entry(package: &str, file_name: &str, code: &str) -> Entry {
+        Entry::from_diagnostic(package, &diagnostic(Some("needless_return"), file_name, code)).unwrap()
+    }
+
+    fn baseline(warnings: Vec<Entry>) -> Baseline {
+        Baseline {
+            version: VERSION,
+            warnings: warnings,
+        }
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(0xcbf2_9ce4_8422_2325, fnv1a(&[]));
+        assert_eq!(0x089b_e207_b544_f1e4, fnv1a(&["a".as_bytes()]));
+        // the parts are separated, so moving bytes from one part to the next changes the hash
+        assert_eq!(0xad22_872f_536e_4705, fnv1a(&["ab".as_bytes(), "c".as_bytes()]));
+        assert_eq!(0x4018_01fc_84f3_ca79, fnv1a(&["a".as_bytes(), "bc".as_bytes()]));
+    }
+
+    #[test]
+    fn test_flagged_code() {
+        // only the highlighted part of each line is kept
+        assert_eq!("x + 1", flagged_code(&span("src/lib.rs", &[("let a = x + 1;", 9, 14)])));
+        // indentation and line breaks do not change the flagged code
+        let split = span("src/lib.rs", &[("    return foo(a,", 5, 18), ("               b);", 16, 18)]);
+        let joined = span("src/lib.rs", &[("return foo(a, b);", 1, 17)]);
+        assert_eq!("return foo(a, b)", flagged_code(&split));
+        assert_eq!(flagged_code(&joined), flagged_code(&split));
+        // the column of the highlight does not matter either
+        assert_eq!(flagged_code(&span("src/lib.rs", &[("  x", 3, 4)])),
+                   flagged_code(&span("src/lib.rs", &[("x", 1, 2)])));
+    }
+
+    #[test]
+    fn test_entry() {
+        assert_eq!(None, Entry::from_diagnostic("foo", &diagnostic(None, "src/lib.rs", "x")));
+        // the members of a workspace have their own `src/lib.rs`
+        assert!(entry("foo", "src/lib.rs", "x") != entry("bar", "src/lib.rs", "x"));
+        assert!(entry("foo", "src/lib.rs", "x") != entry("foo", "src/main.rs", "x"));
+        assert!(entry("foo", "src/lib.rs", "x") != entry("foo", "src/lib.rs", "y"));
+    }
+
+    #[test]
+    fn test_suppress() {
+        let known = baseline(vec![entry("foo", "src/lib.rs", "x"), entry("foo", "src/lib.rs", "y")]);
+        let mut diagnostics = vec![diagnostic(Some("needless_return"), "src/lib.rs", "x"),
+                                   // a second occurrence of a known warning
+                                   diagnostic(Some("needless_return"), "src/lib.rs", "x"),
+                                   diagnostic(Some("needless_return"), "src/lib.rs", "z"),
+                                   // not produced by Clippy
+                                   diagnostic(None, "src/lib.rs", "y")];
+        suppress(&mut diagnostics, "foo", &known);
+        let codes: Vec<_> = diagnostics.iter().map(|diagnostic| flagged_code(&diagnostic.spans[0])).collect();
+        assert_eq!(vec!["x", "z", "y"], codes);
+
+        // the same warning in another package is new
+        let mut diagnostics = vec![diagnostic(Some("needless_return"), "src/lib.rs", "x")];
+        suppress(&mut diagnostics, "bar", &known);
+        assert_eq!(1, diagnostics.len());
+    }
+
+    #[test]
+    fn test_stale() {
+        let known = baseline(vec![entry("foo", "src/lib.rs", "x"),
+                                  entry("foo", "src/lib.rs", "x"),
+                                  entry("foo", "src/lib.rs", "y"),
+                                  // in a file that was not linted
+                                  entry("foo", "src/main.rs", "z"),
+                                  // in the same file of another package, which was not linted
+                                  entry("bar", "src/lib.rs", "z")]);
+        let report = Report::new("foo",
+                                 vec!["src/lib.rs".to_owned()],
+                                 &[diagnostic(Some("needless_return"), "src/lib.rs", "x"),
+                                   diagnostic(Some("needless_return"), "src/lib.rs", "y")]);
+        // one of the two `x` entries is not matched anymore
+        assert_eq!(vec![entry("foo", "src/lib.rs", "x")], stale(&known, &report));
+    }
+}
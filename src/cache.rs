@@ -0,0 +1,240 @@
+//! `cargo clippy --cache`, which replays the diagnostics of the crates that did not change since
+//! they were last linted instead of linting them again.
+//!
+//! The driver keeps an entry per target in the output directory cargo gives it, named after a
+//! hash of the rustc arguments. An entry is used if it was written by the same version of Clippy
+//! with the same configuration, and if the source files of the crate and the dependencies it was
+//! compiled against have the same content.
+
+use baseline;
+use clippy_lints::utils::conf;
+use clippy_lints::utils::sugg::Applicability;
+use diagnostics::{Diagnostic, Suppression};
+use serde_json;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// The diagnostics of a crate, with what they depend on.
+#[derive(Debug, Deserialize, Serialize)]
+struct Entry {
+    /// The hash of Clippy's version and configuration.
+    key: String,
+    /// The source files of the crate.
+    files: Vec<String>,
+    /// The hash of the content of the source files and dependencies, by path.
+    inputs: BTreeMap<String, String>,
+    /// The number of errors of the compilation.
+    errors: usize,
+    diagnostics: Vec<Diagnostic>,
+    /// What the driver found out about each diagnostic, which is not part of rustc's JSON output.
+    annotations: Vec<Annotation>,
+    /// The suppression comments of the crate.
+    #[serde(default)]
+    suppressions: Vec<Suppression>,
+}
+
+/// The Clippy lint of a diagnostic and the applicability of its sub-diagnostics.
+#[derive(Debug, Deserialize, Serialize)]
+struct Annotation {
+    lint: Option<String>,
+    group: Option<String>,
+    applicability: Vec<Option<String>>,
+}
+
+/// The cache entry of the crate being compiled.
+pub struct Cache {
+    path: PathBuf,
+    key: String,
+    /// The files the crate is compiled against, given with `--extern`.
+    externs: Vec<String>,
+}
+
+/// The hexadecimal hash of `parts`, which is stable as it is kept in the cache entries.
+fn hash(parts: &[&[u8]]) -> String {
+    format!("{:016x}", baseline::fnv1a(parts))
+}
+
+/// The hash of the content of a file, `None` if it cannot be read.
+fn hash_file(path: &str) -> Option<String> {
+    let mut content = Vec::new();
+    File::open(path).and_then(|mut file| file.read_to_end(&mut content)).ok().map(|_| hash(&[content.as_slice()]))
+}
+
+/// The configuration the lints will use, as the Clippy plugin reads it, `None` if it cannot be
+/// read.
+fn conf_fingerprint() -> Option<String> {
+    let (manifest, file) = if let (Ok(manifest), Ok(file)) = (conf::lookup_manifest(), conf::lookup_conf_file()) {
+        (manifest, file)
+    } else {
+        return None;
+    };
+    let (conf, errors) = conf::read(manifest.as_ref().map(AsRef::as_ref), file.as_ref().map(AsRef::as_ref));
+    if !errors.is_empty() {
+        return None;
+    }
+    let mut fingerprint: Vec<_> = conf.fields()
+        .into_iter()
+        .map(|field| format!("{}={}", field.name, field.value))
+        .collect();
+    fingerprint.push(format!("{:?}", conf.lints));
+    Some(fingerprint.join("\n"))
+}
+
+impl Cache {
+    /// The cache entry of the crate compiled with the rustc arguments `args`, `None` if there is no
+    /// output directory to keep it in or if the configuration cannot be read.
+    pub fn new(args: &[String]) -> Option<Cache> {
+        let out_dir = args.iter().position(|arg| arg == "--out-dir").and_then(|i| args.get(i + 1));
+        let (out_dir, conf) = if let (Some(out_dir), Some(conf)) = (out_dir, conf_fingerprint()) {
+            (out_dir, conf)
+        } else {
+            return None;
+        };
+        let key = hash(&[env!("CARGO_PKG_VERSION").as_bytes(), conf.as_bytes()]);
+        let externs = args.windows(2)
+            .filter(|pair| pair[0] == "--extern")
+            .filter_map(|pair| pair[1].splitn(2, '=').nth(1))
+            .map(str::to_owned)
+            .collect();
+        let args: Vec<_> = args.iter().map(|arg| arg.as_bytes()).collect();
+        Some(Cache {
+            path: Path::new(out_dir).join("clippy-cache").join(format!("{}.json", hash(&args))),
+            key: key,
+            externs: externs,
+        })
+    }
+
+    /// The diagnostics, number of errors, source files and suppression comments of the last
+    /// compilation, if nothing changed since.
+    pub fn load(&self) -> Option<(Vec<Diagnostic>, usize, Vec<String>, Vec<Suppression>)> {
+        let mut content = String::new();
+        if File::open(&self.path).and_then(|mut file| file.read_to_string(&mut content)).is_err() {
+            return None;
+        }
+        let entry: Entry = if let Ok(entry) = serde_json::from_str(&content) {
+            entry
+        } else {
+            return None;
+        };
+        if entry.key != self.key || entry.annotations.len() != entry.diagnostics.len() ||
+           entry.inputs.iter().any(|(path, hash)| hash_file(path).as_ref() != Some(hash)) {
+            return None;
+        }
+
+        let mut diagnostics = entry.diagnostics;
+        for (diagnostic, annotation) in diagnostics.iter_mut().zip(entry.annotations) {
+            diagnostic.lint = annotation.lint;
+            diagnostic.group = annotation.group;
+            for (child, applicability) in diagnostic.children.iter_mut().zip(annotation.applicability) {
+                child.applicability = applicability.and_then(|name| Applicability::from_name(&name));
+            }
+        }
+        Some((diagnostics, entry.errors, entry.files, entry.suppressions))
+    }
+
+    /// Save the diagnostics and suppression comments of a compilation of the crate made of `files`.
+    pub fn store(&self, files: &[String], diagnostics: &[Diagnostic], suppressions: &[Suppression], errors: usize)
+                 -> io::Result<()> {
+        let mut inputs = BTreeMap::new();
+        for path in files.iter().chain(&self.externs) {
+            // an input that cannot be read cannot be checked, so the crate would always be linted
+            if let Some(hash) = hash_file(path) {
+                inputs.insert(path.clone(), hash);
+            } else {
+                return Ok(());
+            }
+        }
+        let entry = Entry {
+            key: self.key.clone(),
+            files: files.to_vec(),
+            inputs: inputs,
+            errors: errors,
+            diagnostics: diagnostics.to_vec(),
+            annotations: diagnostics.iter()
+                .map(|diagnostic| {
+                    Annotation {
+                        lint: diagnostic.lint.clone(),
+                        group: diagnostic.group.clone(),
+                        applicability: diagnostic.children
+                            .iter()
+                            .map(|child| child.applicability.map(|applicability| applicability.as_str().to_owned()))
+                            .collect(),
+                    }
+                })
+                .collect(),
+            suppressions: suppressions.to_vec(),
+        };
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string(&entry).expect("cache entries can always be serialized");
+        File::create(&self.path)?.write_all(json.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use baseline;
+    use diagnostics::Diagnostic;
+    use serde_json;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::Path;
+    use super::Cache;
+
+    fn write(path: &Path, content: &str) -> String {
+        File::create(path).unwrap().write_all(content.as_bytes()).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    fn cache(dir: &Path, key: &str, externs: &[String]) -> Cache {
+        Cache {
+            path: dir.join("clippy-cache").join("entry.json"),
+            key: key.to_owned(),
+            externs: externs.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_invalidation() {
+        let dir = baseline::report_dir().unwrap();
+        let file = write(&dir.join("lib.rs"), "fn main() { return; }");
+        let dependency = write(&dir.join("libdep.rlib"), "dep v1");
+        let mut diagnostic: Diagnostic = serde_json::from_str(r#"{
+            "message": "unneeded return statement",
+            "code": null,
+            "level": "warning",
+            "spans": [],
+            "children": []
+        }"#)
+            .unwrap();
+        diagnostic.lint = Some("needless_return".to_owned());
+
+        let files = vec![file.clone()];
+        let externs = vec![dependency.clone()];
+        cache(&dir, "key", &externs).store(&files, &[diagnostic], &[], 0).unwrap();
+        let (diagnostics, errors, cached_files, _) = cache(&dir, "key", &externs).load().unwrap();
+        assert_eq!(Some("needless_return"), diagnostics[0].lint.as_ref().map(String::as_str));
+        assert_eq!(0, errors);
+        assert_eq!(files, cached_files);
+
+        // another version of Clippy or another configuration
+        assert!(cache(&dir, "other key", &externs).load().is_none());
+
+        // a changed source file
+        write(&dir.join("lib.rs"), "fn main() {}");
+        assert!(cache(&dir, "key", &externs).load().is_none());
+        // the entry is used again once the file has its old content back
+        write(&dir.join("lib.rs"), "fn main() { return; }");
+        assert!(cache(&dir, "key", &externs).load().is_some());
+
+        // a changed dependency
+        write(&dir.join("libdep.rlib"), "dep v2");
+        assert!(cache(&dir, "key", &externs).load().is_none());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
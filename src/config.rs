@@ -0,0 +1,141 @@
+//! `cargo clippy --print-config`, which shows the configuration Clippy would use.
+
+use clippy_lints::utils::conf::{self, Conf};
+use serde_json;
+use std::io::{self, Write};
+use toml;
+
+/// The format of the printed configuration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Toml,
+    Json,
+}
+
+impl Format {
+    pub fn from_name(format: &str) -> Option<Format> {
+        match format {
+            "toml" => Some(Format::Toml),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Read the configuration like the Clippy plugin does, from the current directory, and print it.
+/// Returns whether the configuration could be read without error.
+pub fn print(format: Format) -> bool {
+    let file = match conf::lookup_conf_file() {
+        Ok(file) => file,
+        Err(err) => {
+            let _ = writeln!(io::stderr(), "error: could not look for Clippy's configuration file: {}", err);
+            return false;
+        },
+    };
+    let manifest = match conf::lookup_manifest() {
+        Ok(manifest) => manifest,
+        Err(err) => {
+            let _ = writeln!(io::stderr(), "error: could not look for `Cargo.toml`: {}", err);
+            return false;
+        },
+    };
+    let (conf, errors) = conf::read(manifest.as_ref().map(AsRef::as_ref), file.as_ref().map(AsRef::as_ref));
+    for error in &errors {
+        let _ = writeln!(io::stderr(),
+                         "error: error reading Clippy's configuration file: {}\n  --> {}",
+                         error.error,
+                         error.location());
+    }
+
+    match format {
+        Format::Toml => print_toml(&conf),
+        Format::Json => print_json(&conf),
+    }
+    errors.is_empty()
+}
+
+/// Print the configuration as a `clippy.toml` file, with the files it comes from, the defaults and
+/// the lints in comments.
+#[allow(print_stdout)]
+fn print_toml(conf: &Conf) {
+    if conf.files.is_empty() {
+        println!("# no configuration file found, this is the default configuration");
+    } else {
+        for file in &conf.files {
+            println!("# read from {}", file.display());
+        }
+    }
+
+    for field in conf.fields() {
+        println!();
+        println!("# used by: {}", field.lints.join(", "));
+        println!("# default: {}", field.default);
+        println!("{} = {}", field.name, field.value);
+    }
+
+    if !conf.lints.is_empty() {
+        println!();
+        println!("[lints]");
+        for &(ref lint, level) in &conf.lints {
+            println!("{} = \"{}\"", lint, level.as_str());
+        }
+    }
+}
+
+/// The configuration file as printed by `cargo clippy --print-config=json`.
+#[derive(Serialize)]
+struct Config {
+    /// The configuration files, the inherited ones first.
+    files: Vec<String>,
+    fields: Vec<Field>,
+    /// The `[lints]` table, in the order the levels are applied.
+    lints: Vec<(String, &'static str)>,
+}
+
+#[derive(Serialize)]
+struct Field {
+    name: &'static str,
+    value: serde_json::Value,
+    default: serde_json::Value,
+    lints: Vec<String>,
+}
+
+/// Print the configuration as a JSON object.
+#[allow(print_stdout)]
+fn print_json(conf: &Conf) {
+    let config = Config {
+        files: conf.files.iter().map(|file| file.display().to_string()).collect(),
+        fields: conf.fields()
+            .into_iter()
+            .map(|field| {
+                Field {
+                    name: field.name,
+                    value: to_json(field.value),
+                    default: to_json(field.default),
+                    lints: field.lints,
+                }
+            })
+            .collect(),
+        lints: conf.lints.iter().map(|&(ref lint, level)| (lint.clone(), level.as_str())).collect(),
+    };
+    println!("{}", serde_json::to_string_pretty(&config).expect("configurations can always be serialized"));
+}
+
+/// Convert a TOML value to JSON.
+fn to_json(value: toml::Value) -> serde_json::Value {
+    match value {
+        toml::Value::String(s) |
+        toml::Value::Datetime(s) => s.into(),
+        toml::Value::Integer(i) => i.into(),
+        toml::Value::Float(f) => f.into(),
+        toml::Value::Boolean(b) => b.into(),
+        toml::Value::Array(values) => values.into_iter().map(to_json).collect::<Vec<_>>().into(),
+        toml::Value::Table(table) => {
+            let mut map = serde_json::Map::new();
+            for (key, value) in table {
+                map.insert(key, to_json(value));
+            }
+            map.into()
+        },
+    }
+}
@@ -0,0 +1,409 @@
+//! Post-processing of the diagnostics rustc emits while Clippy runs.
+//!
+//! When some machine-readable output is requested, the driver runs the compiler with
+//! `--error-format=json`, captures the diagnostics and annotates them with the Clippy lint that
+//! produced them and the applicability of their suggestions. The diagnostics that are kept are
+//! then printed with rustc's own emitter.
+
+use clippy_lints::utils::emitted::Emitted;
+use clippy_lints::utils::sugg::Applicability;
+use clippy_lints::utils::suppression;
+use rustc::session::Session;
+use rustc_errors::{CodeSuggestion, ColorConfig, DiagnosticBuilder, Handler, Level, RenderSpan, SubDiagnostic};
+use rustc_errors::emitter::EmitterWriter;
+use rustc_errors::snippet::Style;
+use serde_json;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use syntax::codemap::{BytePos, CodeMap, FileMap, MultiSpan, Span, NO_EXPANSION};
+
+/// The lint groups a Clippy lint can belong to, in order of preference when a lint is in several
+/// groups.
+const GROUPS: [&str; 4] = ["clippy", "clippy_pedantic", "clippy_restrictions", "clippy_internal"];
+
+/// A diagnostic in the format of rustc's `--error-format=json`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Diagnostic {
+    pub message: String,
+    pub code: Option<DiagnosticCode>,
+    pub level: String,
+    pub spans: Vec<DiagnosticSpan>,
+    pub children: Vec<Diagnostic>,
+    #[serde(default)]
+    pub rendered: Option<String>,
+    /// The Clippy lint that produced this diagnostic, if any.
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub lint: Option<String>,
+    /// The lint group of `lint`.
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// How confident the lint is in this sub-diagnostic, if it is a suggestion.
+    #[serde(skip_deserializing, skip_serializing)]
+    pub applicability: Option<Applicability>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiagnosticCode {
+    pub code: String,
+    pub explanation: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiagnosticSpan {
+    pub file_name: String,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+    #[serde(default)]
+    pub text: Vec<DiagnosticSpanLine>,
+    pub label: Option<String>,
+    pub suggested_replacement: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiagnosticSpanLine {
+    pub text: String,
+    pub highlight_start: usize,
+    pub highlight_end: usize,
+}
+
+impl Diagnostic {
+    /// The primary span of the diagnostic, if it has one.
+    pub fn primary_span(&self) -> Option<&DiagnosticSpan> {
+        self.spans.iter().find(|span| span.is_primary)
+    }
+
+    /// Whether this sub-diagnostic is a suggestion.
+    pub fn is_suggestion(&self) -> bool {
+        self.spans.iter().any(|span| span.suggested_replacement.is_some())
+    }
+
+    /// Whether this diagnostic was produced by `emitted`.
+    fn is_emitted_by(&self, emitted: &Emitted) -> bool {
+        self.message == emitted.message &&
+        self.primary_span().map_or(false, |span| {
+            span.file_name == emitted.file_name && span.byte_start == emitted.byte_start &&
+            span.byte_end == emitted.byte_end
+        })
+    }
+}
+
+/// A `// clippy: allow(..)` comment or an `#[allow(..)]` attribute of Clippy lints, for audits.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Suppression {
+    /// `comment` or `attribute`.
+    pub kind: String,
+    /// The Clippy lints and lint groups it allows, as written.
+    pub lints: Vec<String>,
+    /// The justification given with `reason="..."` or, for attributes, in a comment next to them.
+    pub reason: Option<String>,
+    /// The file of the comment or attribute, as given to the compiler like the `file_name` of spans.
+    pub file_name: String,
+    /// The line of the comment or attribute, starting at 1.
+    pub line: usize,
+    /// The lints of `lints` that did not suppress anything, always empty for attributes.
+    pub unused: Vec<String>,
+}
+
+impl<'a> From<&'a suppression::Suppression> for Suppression {
+    fn from(suppression: &'a suppression::Suppression) -> Self {
+        Suppression {
+            kind: suppression.kind.as_str().to_owned(),
+            lints: suppression.lints.clone(),
+            reason: suppression.reason.clone(),
+            file_name: suppression.file_name.clone(),
+            line: suppression.line,
+            unused: suppression.lints
+                .iter()
+                .zip(&suppression.used)
+                .filter(|&(_, &used)| !used)
+                .map(|(lint, _)| lint.clone())
+                .collect(),
+        }
+    }
+}
+
+/// A `Write` implementation that keeps what was written, so it can be given to the compiler as
+/// its diagnostic destination.
+#[derive(Clone, Default)]
+pub struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().expect("diagnostic buffer poisoned").write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Buffer {
+    /// Parse the diagnostics written so far, one JSON object per line. Lines that are not
+    /// diagnostics are returned separately so they are not lost.
+    pub fn diagnostics(&self) -> (Vec<Diagnostic>, Vec<String>) {
+        let buffer = self.0.lock().expect("diagnostic buffer poisoned");
+        let mut diagnostics = Vec::new();
+        let mut other = Vec::new();
+        for line in String::from_utf8_lossy(&buffer).lines() {
+            match serde_json::from_str(line) {
+                Ok(diagnostic) => diagnostics.push(diagnostic),
+                Err(_) => other.push(line.to_owned()),
+            }
+        }
+        (diagnostics, other)
+    }
+}
+
+/// Map the name of every Clippy lint to its lint group.
+pub fn lint_groups(sess: &Session) -> HashMap<String, &'static str> {
+    let lint_groups = sess.lint_store.borrow().get_lint_groups();
+    let mut groups = HashMap::new();
+    // later groups overwrite earlier ones, so go from the least to the most preferred group
+    for group in GROUPS.iter().rev() {
+        for &(name, ref lints, _) in &lint_groups {
+            if name == *group {
+                groups.extend(lints.iter().map(|lint| (lint.to_string(), name)));
+            }
+        }
+    }
+    groups
+}
+
+/// Fill in the `lint` and `group` of the diagnostics that were produced by Clippy, and the
+/// `applicability` of their suggestions.
+pub fn annotate(diagnostics: &mut [Diagnostic], mut emitted: Vec<Emitted>, groups: &HashMap<String, &'static str>) {
+    for diagnostic in diagnostics {
+        if let Some(index) = emitted.iter().position(|emitted| diagnostic.is_emitted_by(emitted)) {
+            let emitted = emitted.remove(index);
+            // suggestions are recorded in the order they were added to the diagnostic
+            let suggestions = diagnostic.children.iter_mut().filter(|child| child.is_suggestion());
+            for (child, &applicability) in suggestions.zip(&emitted.suggestions) {
+                child.applicability = Some(applicability);
+            }
+            diagnostic.group = groups.get(&emitted.lint).map(|&group| group.to_owned());
+            diagnostic.lint = Some(emitted.lint);
+        }
+    }
+}
+
+/// Print the diagnostics on the standard error with rustc's own human-readable emitter.
+///
+/// The spans of the JSON diagnostics are mapped back to the source files, so the output is the one
+/// rustc would have printed without `--error-format=json`.
+pub fn emit_human(diagnostics: &[Diagnostic]) {
+    let codemap = Rc::new(CodeMap::new());
+    let emitter = EmitterWriter::stderr(ColorConfig::Auto, Some(codemap.clone()));
+    let handler = Handler::with_emitter(true, false, box emitter);
+    let mut files = HashMap::new();
+    for diagnostic in diagnostics {
+        let mut db = DiagnosticBuilder::new(&handler, level(&diagnostic.level), &diagnostic.message);
+        db.code = diagnostic.code.as_ref().map(|code| code.code.clone());
+        db.set_span(multi_span(&codemap, &mut files, &diagnostic.spans));
+        for child in &diagnostic.children {
+            let suggestion: Vec<_> = child.spans.iter().filter(|span| span.suggested_replacement.is_some()).collect();
+            let sub = if suggestion.is_empty() {
+                SubDiagnostic {
+                    level: level(&child.level),
+                    message: vec![(child.message.clone(), Style::NoStyle)],
+                    span: multi_span(&codemap, &mut files, &child.spans),
+                    render_span: None,
+                }
+            } else {
+                let spans = suggestion.iter().filter_map(|span| to_span(&codemap, &mut files, span)).collect();
+                SubDiagnostic {
+                    level: level(&child.level),
+                    message: vec![(child.message.clone(), Style::LabelPrimary)],
+                    span: MultiSpan::new(),
+                    render_span: Some(RenderSpan::Suggestion(CodeSuggestion {
+                        msp: MultiSpan::from_spans(spans),
+                        substitutes: suggestion.iter().filter_map(|span| span.suggested_replacement.clone()).collect(),
+                    })),
+                }
+            };
+            db.children.push(sub);
+        }
+        db.emit();
+    }
+}
+
+/// The source files loaded to print the diagnostics, by name. `None` if the file could not be read.
+type Files = HashMap<String, Option<Rc<FileMap>>>;
+
+/// The level of a diagnostic, from its name in the JSON output.
+fn level(name: &str) -> Level {
+    match name {
+        "error: internal compiler error" => Level::Bug,
+        "error" => Level::Error,
+        "warning" => Level::Warning,
+        "help" => Level::Help,
+        _ => Level::Note,
+    }
+}
+
+/// The primary spans of a diagnostic, with the labels of all its spans.
+fn multi_span(codemap: &CodeMap, files: &mut Files, spans: &[DiagnosticSpan]) -> MultiSpan {
+    let mut multi_span = MultiSpan::from_spans(spans.iter()
+        .filter(|span| span.is_primary)
+        .filter_map(|span| to_span(codemap, files, span))
+        .collect());
+    for span in spans {
+        if let (Some(label), Some(sp)) = (span.label.as_ref(), to_span(codemap, files, span)) {
+            multi_span.push_span_label(sp, label.clone());
+        }
+    }
+    multi_span
+}
+
+/// Map a span of the JSON output to a span of `codemap`, loading its file the first time it is
+/// seen. Spans in files that cannot be read, like those of the standard library's macros, are
+/// left out.
+fn to_span(codemap: &CodeMap, files: &mut Files, span: &DiagnosticSpan) -> Option<Span> {
+    let file = files.entry(span.file_name.clone())
+        .or_insert_with(|| codemap.load_file(Path::new(&span.file_name)).ok());
+    file.as_ref().map(|file| {
+        Span {
+            lo: file.start_pos + BytePos(span.byte_start as u32),
+            hi: file.start_pos + BytePos(span.byte_end as u32),
+            ctxt: NO_EXPANSION,
+        }
+    })
+}
+
+/// A diagnostic as printed by `cargo clippy --message-format=json`.
+#[derive(Serialize)]
+struct Message<'a> {
+    /// Always `diagnostic`, to tell diagnostics and suppressions apart in the output.
+    #[serde(rename = "type")]
+    kind: &'static str,
+    lint: Option<&'a str>,
+    level: &'a str,
+    group: Option<&'a str>,
+    message: &'a str,
+    span: Option<&'a DiagnosticSpan>,
+    notes: Vec<Note<'a>>,
+    suggestions: Vec<Suggestion<'a>>,
+    rendered: Option<&'a str>,
+}
+
+/// A note, help or other sub-diagnostic without suggestion.
+#[derive(Serialize)]
+struct Note<'a> {
+    level: &'a str,
+    message: &'a str,
+    spans: &'a [DiagnosticSpan],
+}
+
+/// A suggestion, made of one or more spans with their replacement.
+#[derive(Serialize)]
+struct Suggestion<'a> {
+    message: &'a str,
+    /// One of `MachineApplicable`, `MaybeIncorrect`, `HasPlaceholders` or `Unspecified`.
+    applicability: &'static str,
+    spans: Vec<&'a DiagnosticSpan>,
+}
+
+impl<'a> From<&'a Diagnostic> for Message<'a> {
+    fn from(diagnostic: &'a Diagnostic) -> Self {
+        let (suggestions, notes): (Vec<_>, Vec<_>) = diagnostic.children
+            .iter()
+            .partition(|child| child.is_suggestion());
+
+        Message {
+            kind: "diagnostic",
+            lint: diagnostic.lint.as_ref().map(AsRef::as_ref),
+            level: &diagnostic.level,
+            group: diagnostic.group.as_ref().map(AsRef::as_ref),
+            message: &diagnostic.message,
+            span: diagnostic.primary_span(),
+            notes: notes.into_iter()
+                .map(|note| {
+                    Note {
+                        level: &note.level,
+                        message: &note.message,
+                        spans: &note.spans,
+                    }
+                })
+                .collect(),
+            suggestions: suggestions.into_iter()
+                .map(|suggestion| {
+                    Suggestion {
+                        message: &suggestion.message,
+                        applicability: suggestion.applicability.unwrap_or(Applicability::Unspecified).as_str(),
+                        spans: suggestion.spans.iter().filter(|span| span.suggested_replacement.is_some()).collect(),
+                    }
+                })
+                .collect(),
+            rendered: diagnostic.rendered.as_ref().map(AsRef::as_ref),
+        }
+    }
+}
+
+/// A suppression as printed by `cargo clippy --message-format=json`.
+#[derive(Serialize)]
+struct SuppressionMessage<'a> {
+    /// Always `suppression`, to tell diagnostics and suppressions apart in the output.
+    #[serde(rename = "type")]
+    kind: &'static str,
+    suppression: &'a Suppression,
+}
+
+/// Print the diagnostics, then the suppressions, on the standard output, one JSON object
+/// per line.
+#[allow(print_stdout)]
+pub fn emit_json(diagnostics: &[Diagnostic], suppressions: &[Suppression]) {
+    for diagnostic in diagnostics {
+        let message = Message::from(diagnostic);
+        println!("{}", serde_json::to_string(&message).expect("diagnostics can always be serialized"));
+    }
+    for suppression in suppressions {
+        let message = SuppressionMessage {
+            kind: "suppression",
+            suppression: suppression,
+        };
+        println!("{}", serde_json::to_string(&message).expect("suppressions can always be serialized"));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{self, Value};
+    use super::{Diagnostic, Message, Suppression, SuppressionMessage};
+
+    #[test]
+    fn test_message_types() {
+        let diagnostic: Diagnostic = serde_json::from_str(r#"{
+            "message": "unused variable: `x`",
+            "code": null,
+            "level": "warning",
+            "spans": [],
+            "children": []
+        }"#)
+            .unwrap();
+        let message = serde_json::to_value(&Message::from(&diagnostic)).unwrap();
+        assert_eq!(Some(&Value::String("diagnostic".to_owned())), message.get("type"));
+
+        let suppression = Suppression {
+            kind: "comment".to_owned(),
+            lints: vec!["needless_range_loop".to_owned()],
+            reason: None,
+            file_name: "src/main.rs".to_owned(),
+            line: 3,
+            unused: Vec::new(),
+        };
+        let message = serde_json::to_value(&SuppressionMessage {
+                kind: "suppression",
+                suppression: &suppression,
+            })
+            .unwrap();
+        assert_eq!(Some(&Value::String("suppression".to_owned())), message.get("type"));
+        assert_eq!(Some(3), message.get("suppression").and_then(|s| s.get("line")).and_then(Value::as_u64));
+    }
+}
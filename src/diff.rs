@@ -0,0 +1,246 @@
+//! `cargo clippy --diff-base <rev>`, which only reports the warnings of the lines changed since a
+//! git revision.
+//!
+//! `cargo clippy` asks git for the changed lines once and saves them in a file for the `cargo rustc`
+//! child processes, which filter their diagnostics before emitting them.
+
+use diagnostics::Diagnostic;
+use serde_json;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::usize;
+
+/// The lines changed since a revision.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ChangedLines {
+    /// The revision given with `--diff-base`.
+    pub base: String,
+    /// The ranges of changed lines, inclusive and starting at 1, by absolute file path.
+    pub files: BTreeMap<String, Vec<(usize, usize)>>,
+}
+
+/// Run git in the current directory and return its output.
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|err| format!("could not run `git {}`: {}", args.join(" "), err))?;
+    if !output.status.success() {
+        return Err(format!("`git {}` failed: {}",
+                           args.join(" "),
+                           String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+impl ChangedLines {
+    /// Ask git for the lines changed since the common ancestor of `base` and `HEAD`, including the
+    /// uncommitted changes. Untracked files are entirely changed.
+    pub fn new(base: &str) -> Result<ChangedLines, String> {
+        let toplevel = PathBuf::from(git(&["rev-parse", "--show-toplevel"])?.trim());
+        let merge_base = git(&["merge-base", base, "HEAD"])?;
+        let diff = git(&["diff", "--no-color", "--no-ext-diff", "--unified=0", merge_base.trim(), "--"])?;
+
+        let mut changed = ChangedLines {
+            base: base.to_owned(),
+            files: BTreeMap::new(),
+        };
+        changed.parse_diff(&toplevel, &diff);
+        for file in git(&["ls-files", "--others", "--exclude-standard", "--full-name"])?.lines() {
+            changed.files.insert(absolute(&toplevel.join(file)), vec![(1, usize::MAX)]);
+        }
+        Ok(changed)
+    }
+
+    /// Read the added lines of a unified diff without context, whose paths are relative to
+    /// `toplevel`.
+    fn parse_diff(&mut self, toplevel: &Path, diff: &str) {
+        let mut file = None;
+        for line in diff.lines() {
+            if line.starts_with("+++ ") {
+                // `+++ /dev/null` for deleted files
+                file = if line.starts_with("+++ b/") {
+                    Some(absolute(&toplevel.join(&line["+++ b/".len()..])))
+                } else {
+                    None
+                };
+            } else if line.starts_with("@@ ") {
+                if let (Some(file), Some(range)) = (file.as_ref(), parse_hunk_header(line)) {
+                    self.files.entry(file.clone()).or_insert_with(Vec::new).push(range);
+                }
+            }
+        }
+    }
+
+    /// Whether the lines `start..=end` of `file` overlap a changed line.
+    fn overlaps(&self, file: &str, start: usize, end: usize) -> bool {
+        self.files
+            .get(file)
+            .map_or(false, |ranges| ranges.iter().any(|&(first, last)| first <= end && start <= last))
+    }
+
+    /// Save the changed lines in a new temporary file, for the child processes.
+    pub fn save(&self) -> Result<PathBuf, String> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let name = format!("cargo-clippy-diff-{}-{}.json", now.as_secs(), now.subsec_nanos());
+        let path = ::std::env::temp_dir().join(name);
+        let json = serde_json::to_string(self).expect("changed lines can always be serialized");
+        File::create(&path)
+            .and_then(|mut file| file.write_all(json.as_bytes()))
+            .map_err(|err| format!("could not write `{}`: {}", path.display(), err))?;
+        Ok(path)
+    }
+
+    /// Read the changed lines saved by `save`.
+    pub fn load(path: &Path) -> Result<ChangedLines, String> {
+        let mut content = String::new();
+        File::open(path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .map_err(|err| format!("could not read `{}`: {}", path.display(), err))?;
+        serde_json::from_str(&content).map_err(|err| format!("could not parse `{}`: {}", path.display(), err))
+    }
+}
+
+/// The lines added by a hunk, from its header `@@ -a,b +c,d @@`. Hunks that only remove lines add
+/// none.
+fn parse_hunk_header(line: &str) -> Option<(usize, usize)> {
+    let added = if let Some(added) = line.split(' ').find(|part| part.starts_with('+')) {
+        added
+    } else {
+        return None;
+    };
+    let mut parts = added[1..].splitn(2, ',');
+    let start: Option<usize> = parts.next().and_then(|start| start.parse().ok());
+    let count: Option<usize> = parts.next().map_or(Some(1), |count| count.parse().ok());
+    match (start, count) {
+        (Some(start), Some(count)) if count > 0 => Some((start, start + count - 1)),
+        _ => None,
+    }
+}
+
+/// The absolute path of `path`, with symbolic links resolved if it exists.
+fn absolute(path: &Path) -> String {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()).display().to_string()
+}
+
+/// Remove the warnings and Clippy lints whose primary span is not on a changed line, and return
+/// how many were removed. Other errors are kept, as are the diagnostics without a location.
+pub fn filter(diagnostics: &mut Vec<Diagnostic>, changed: &ChangedLines) -> usize {
+    let mut paths = HashMap::new();
+    let len = diagnostics.len();
+    diagnostics.retain(|diagnostic| {
+        if diagnostic.lint.is_none() && diagnostic.level != "warning" {
+            return true;
+        }
+        let span = if let Some(span) = diagnostic.primary_span() {
+            span
+        } else {
+            return true;
+        };
+        // file names are relative to the directory of the child process
+        let path = paths.entry(span.file_name.clone()).or_insert_with(|| absolute(Path::new(&span.file_name)));
+        changed.overlaps(path, span.line_start, span.line_end)
+    });
+    len - diagnostics.len()
+}
+
+#[cfg(test)]
+mod test {
+    use diagnostics::{Diagnostic, DiagnosticSpan};
+    use std::collections::BTreeMap;
+    use std::path::Path;
+    use super::{filter, parse_hunk_header, ChangedLines};
+
+    fn diagnostic(lint: Option<&str>, level: &str, line_start: usize, line_end: usize) -> Diagnostic {
+        Diagnostic {
+            message: "this is a warning".to_owned(),
+            code: None,
+            level: level.to_owned(),
+            spans: vec![DiagnosticSpan {
+                            file_name: "/nonexistent/src/lib.rs".to_owned(),
+                            byte_start: 0,
+                            byte_end: 0,
+                            line_start: line_start,
+                            line_end: line_end,
+                            column_start: 1,
+                            column_end: 1,
+                            is_primary: true,
+                            text: Vec::new(),
+                            label: None,
+                            suggested_replacement: None,
+                        }],
+            children: Vec::new(),
+            rendered: None,
+            lint: lint.map(str::to_owned),
+            group: None,
+            applicability: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_hunk_header() {
+        assert_eq!(Some((4, 6)), parse_hunk_header("@@ -3,0 +4,3 @@ fn main() {"));
+        // the count is omitted for hunks of one line
+        assert_eq!(Some((5, 5)), parse_hunk_header("@@ -5 +5 @@"));
+        assert_eq!(Some((7, 7)), parse_hunk_header("@@ -6,2 +7 @@"));
+        // hunks that only remove lines
+        assert_eq!(None, parse_hunk_header("@@ -10,2 +12,0 @@"));
+        assert_eq!(None, parse_hunk_header("@@ -1 +0,0 @@"));
+    }
+
+    #[test]
+    fn test_parse_diff() {
+        let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -2 +2 @@ fn foo() {
+-    bar()
++    baz()
+@@ -10,2 +9,0 @@ fn foo() {
+-    qux();
+-    quux();
+@@ -20,0 +19,2 @@ fn foo() {
++    qux();
++    quux();
+diff --git a/src/old.rs b/src/old.rs
+deleted file mode 100644
+--- a/src/old.rs
++++ /dev/null
+@@ -1 +0,0 @@
+-fn old() {}
+";
+        let mut changed = ChangedLines::default();
+        changed.parse_diff(Path::new("/nonexistent"), diff);
+        let mut expected = BTreeMap::new();
+        expected.insert("/nonexistent/src/lib.rs".to_owned(), vec![(2, 2), (19, 20)]);
+        assert_eq!(expected, changed.files);
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut changed = ChangedLines::default();
+        changed.files.insert("/nonexistent/src/lib.rs".to_owned(), vec![(10, 10), (20, 25)]);
+
+        let mut diagnostics = vec![diagnostic(Some("needless_return"), "warning", 10, 10),
+                                   diagnostic(Some("needless_return"), "warning", 11, 19),
+                                   // multi-line spans are kept if any of their lines changed
+                                   diagnostic(Some("needless_return"), "warning", 8, 12),
+                                   diagnostic(Some("needless_return"), "warning", 15, 20),
+                                   diagnostic(Some("needless_return"), "warning", 25, 30),
+                                   diagnostic(Some("needless_return"), "warning", 1, 9),
+                                   diagnostic(Some("needless_return"), "error", 1, 1),
+                                   // rustc errors are always kept, but not rustc warnings
+                                   diagnostic(None, "error", 1, 1),
+                                   diagnostic(None, "warning", 1, 1)];
+        assert_eq!(4, filter(&mut diagnostics, &changed));
+        let lines: Vec<_> = diagnostics.iter()
+            .map(|diagnostic| (diagnostic.spans[0].line_start, diagnostic.spans[0].line_end))
+            .collect();
+        assert_eq!(vec![(10, 10), (8, 12), (15, 20), (25, 30), (1, 1)], lines);
+    }
+}
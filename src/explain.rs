@@ -0,0 +1,172 @@
+//! `cargo clippy --list-lints`, `cargo clippy --list-groups` and `cargo clippy --explain <lint>`,
+//! which show the documentation of the lints and lint groups without network access.
+
+use clippy_lints::utils::catalogue::{self, GroupInfo, LintInfo};
+use clippy_lints::utils::conf::{self, Conf};
+use serde_json;
+
+/// The configuration keys that apply to `lint`, with their default values.
+fn config_keys(lint: &LintInfo) -> Vec<(&'static str, String)> {
+    Conf::default()
+        .fields()
+        .into_iter()
+        .filter(|field| field.lints.iter().any(|name| name == lint.name))
+        .map(|field| (field.name, field.default.to_string()))
+        .collect()
+}
+
+/// Print every lint with its level, group and description, as a table.
+#[allow(print_stdout)]
+pub fn list() {
+    let name_width = catalogue::LINTS.iter().map(|lint| lint.name.len()).max().unwrap_or(0);
+    println!("{:name_width$}  {:10}  {:19}  {}",
+             "name",
+             "level",
+             "group",
+             "description",
+             name_width = name_width);
+    for lint in catalogue::LINTS {
+        println!("{:name_width$}  {:10}  {:19}  {}",
+                 lint.name,
+                 lint.level,
+                 lint.group,
+                 lint.desc,
+                 name_width = name_width);
+    }
+}
+
+/// A lint as printed by `cargo clippy --list-lints=json`.
+#[derive(Serialize)]
+struct Lint {
+    name: &'static str,
+    level: &'static str,
+    group: &'static str,
+    desc: &'static str,
+    docs: &'static str,
+    config: Vec<Config>,
+}
+
+/// A configuration key that applies to a lint.
+#[derive(Serialize)]
+struct Config {
+    name: &'static str,
+    default: String,
+}
+
+/// Print every lint with all its documentation, as a JSON array.
+#[allow(print_stdout)]
+pub fn list_json() {
+    let lints: Vec<_> = catalogue::LINTS.iter()
+        .map(|lint| {
+            Lint {
+                name: lint.name,
+                level: lint.level,
+                group: lint.group,
+                desc: lint.desc,
+                docs: lint.docs,
+                config: config_keys(lint)
+                    .into_iter()
+                    .map(|(name, default)| {
+                        Config {
+                            name: name,
+                            default: default,
+                        }
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+    println!("{}", serde_json::to_string_pretty(&lints).expect("lints can always be serialized"));
+}
+
+/// Print every lint group with its description and number of lints, as a table.
+#[allow(print_stdout)]
+pub fn list_groups() {
+    let name_width = catalogue::GROUPS.iter().map(|group| group.name.len()).max().unwrap_or(0);
+    println!("{:name_width$}  {:>5}  {}", "name", "lints", "description", name_width = name_width);
+    for group in catalogue::GROUPS {
+        println!("{:name_width$}  {:>5}  {}",
+                 group.name,
+                 group.lints.len(),
+                 group.desc,
+                 name_width = name_width);
+    }
+}
+
+/// Print the documentation of a lint, or the lints of a lint group. Returns an error if there is no
+/// such lint or group.
+pub fn explain(name: &str) -> Result<(), String> {
+    if let Some(lint) = catalogue::find(name) {
+        explain_lint(lint);
+        return Ok(());
+    }
+    if let Some(group) = catalogue::find_group(name) {
+        explain_group(group);
+        return Ok(());
+    }
+
+    let mut err = format!("no lint or lint group named `{}`", name);
+    let names = catalogue::LINTS.iter().map(|lint| lint.name).chain(catalogue::GROUPS.iter().map(|group| group.name));
+    if let Some(similar) = conf::similar_name(name, names) {
+        err.push_str(&format!(", did you mean `{}`?", similar));
+    }
+    Err(err)
+}
+
+/// Print the lints of a lint group, with their default level and description.
+#[allow(print_stdout)]
+fn explain_group(group: &GroupInfo) {
+    println!("{} (lint group)", group.name);
+    println!();
+    println!("{}", group.desc);
+    println!();
+    let lints: Vec<_> = group.lints.iter().filter_map(|name| catalogue::find(name)).collect();
+    let name_width = lints.iter().map(|lint| lint.name.len()).max().unwrap_or(0);
+    for lint in lints {
+        println!("    {:name_width$}  {:5}  {}", lint.name, lint.level, lint.desc, name_width = name_width);
+    }
+}
+
+/// Print the documentation of a lint.
+#[allow(print_stdout)]
+fn explain_lint(lint: &LintInfo) {
+    if lint.group.is_empty() {
+        println!("{} ({})", lint.name, lint.level);
+    } else {
+        println!("{} ({} by default, in `{}`)", lint.name, lint.level, lint.group);
+    }
+    println!();
+    println!("{}", lint.desc);
+    println!();
+    print!("{}", render(lint.docs));
+
+    let keys = config_keys(lint);
+    if !keys.is_empty() {
+        println!();
+        println!("Configuration (in `clippy.toml`):");
+        println!();
+        for (key, default) in keys {
+            println!("    {} = {}", key, default);
+        }
+    }
+}
+
+/// Render the Markdown documentation of a lint for a terminal: emphasis is removed and code blocks
+/// are indented.
+pub fn render(docs: &str) -> String {
+    let mut out = String::new();
+    let mut in_code = false;
+    for line in docs.lines() {
+        if line.starts_with("```") {
+            in_code = !in_code;
+        } else if in_code {
+            out.push_str("    ");
+            out.push_str(line);
+            out.push('\n');
+        } else {
+            out.push_str(&line.replace("**", ""));
+            out.push('\n');
+        }
+    }
+    out
+}
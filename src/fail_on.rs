@@ -0,0 +1,212 @@
+//! `cargo clippy --fail-on <lint|group|level>`, which fails the run when some Clippy lints fire,
+//! whatever their level, without turning rustc's own warnings into failures like `-D warnings`
+//! does.
+//!
+//! The warnings are collected like for `--summary`, and checked once every package was linted.
+
+use clippy_lints::utils::catalogue::{self, GroupInfo};
+use clippy_lints::utils::conf;
+use std::io::{self, Write};
+use summary::{self, Warning};
+
+/// The levels that can be given to `--fail-on`, with the lowest level of the diagnostics they
+/// match: the lints denied with `deny` are Clippy warnings too.
+const LEVELS: &[(&str, &str)] = &[("warnings", "warning"),
+                                  ("warn", "warning"),
+                                  ("errors", "error"),
+                                  ("deny", "error")];
+
+/// Which Clippy warnings fail the run.
+#[derive(Clone, Debug)]
+pub enum Condition {
+    /// The warnings of a lint.
+    Lint(&'static str),
+    /// The warnings of the lints of a group, including the presets like `clippy_no_panic`.
+    Group(&'static GroupInfo),
+    /// The warnings emitted at a level or above, `warning` or `error`.
+    Level(&'static str),
+}
+
+impl Condition {
+    /// The condition given on the command line, as a lint name, lint group or level.
+    pub fn from_name(name: &str) -> Result<Condition, String> {
+        if let Some(group) = catalogue::find_group(name) {
+            return Ok(Condition::Group(group));
+        }
+        if let Some(&(_, level)) = LEVELS.iter().find(|&&(spelling, _)| spelling == name) {
+            return Ok(Condition::Level(level));
+        }
+        if let Some(lint) = catalogue::find(name) {
+            return Ok(Condition::Lint(lint.name));
+        }
+
+        let mut err = format!("`--fail-on {}` is not a Clippy lint, lint group or level", name);
+        let candidates = catalogue::LINTS
+            .iter()
+            .map(|lint| lint.name)
+            .chain(catalogue::GROUPS.iter().map(|group| group.name))
+            .chain(LEVELS.iter().map(|&(spelling, _)| spelling));
+        if let Some(similar) = conf::similar_name(name, candidates) {
+            err.push_str(&format!(", did you mean `{}`?", similar));
+        }
+        Err(err)
+    }
+
+    fn matches(&self, warning: &Warning) -> bool {
+        match *self {
+            Condition::Lint(lint) => warning.lint == lint,
+            Condition::Group(group) => group.lints.contains(&warning.lint.as_str()),
+            Condition::Level(level) => warning.level == level || warning.level == "error",
+        }
+    }
+}
+
+impl ::std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Condition::Lint(name) => write!(f, "{}", name),
+            Condition::Group(group) => write!(f, "{}", group.name),
+            Condition::Level(level) => write!(f, "{}s", level),
+        }
+    }
+}
+
+/// Parse the values of the `--fail-on` flags, which may each list several conditions separated by
+/// commas.
+pub fn parse(values: &[String]) -> Result<Vec<Condition>, String> {
+    values.iter()
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(Condition::from_name)
+        .collect()
+}
+
+/// Check the warnings against the conditions, and report the lints that fail the run on the
+/// standard error. Returns whether the run fails.
+pub fn check(conditions: &[Condition], warnings: &[Warning]) -> bool {
+    if let Some(report) = report(conditions, warnings) {
+        let _ = io::stderr().write_all(report.as_bytes());
+        true
+    } else {
+        false
+    }
+}
+
+/// The report of the warnings that fail the run, with the number of warnings of each lint and
+/// the conditions they match. `None` if no warning matches the conditions.
+fn report(conditions: &[Condition], warnings: &[Warning]) -> Option<String> {
+    let failing: Vec<_> = warnings.iter()
+        .filter(|warning| conditions.iter().any(|condition| condition.matches(warning)))
+        .collect();
+    if failing.is_empty() {
+        return None;
+    }
+
+    let mut report = format!("error: {} Clippy warnings fail the run because of `--fail-on`:\n", failing.len());
+    for (lint, count) in summary::count(failing.iter().map(|warning| warning.lint.as_str())) {
+        let reasons: Vec<_> = conditions.iter()
+            .filter(|condition| failing.iter().any(|warning| warning.lint == lint && condition.matches(warning)))
+            .map(|condition| format!("`--fail-on {}`", condition))
+            .collect();
+        report.push_str(&format!("{:>8}  {} ({})\n", count, lint, reasons.join(", ")));
+    }
+    Some(report)
+}
+
+#[cfg(test)]
+mod test {
+    use summary::Warning;
+    use super::{parse, report, Condition};
+
+    fn names(conditions: &[Condition]) -> Vec<String> {
+        conditions.iter().map(ToString::to_string).collect()
+    }
+
+    fn warning(lint: &str, level: &str) -> Warning {
+        Warning {
+            lint: lint.to_owned(),
+            group: None,
+            level: level.to_owned(),
+            file: None,
+        }
+    }
+
+    #[test]
+    fn test_from_name() {
+        match Condition::from_name("needless-range-loop") {
+            Ok(Condition::Lint("needless_range_loop")) => (),
+            other => panic!("unexpected condition: {:?}", other),
+        }
+        match Condition::from_name("clippy_pedantic") {
+            Ok(Condition::Group(group)) => assert_eq!("clippy_pedantic", group.name),
+            other => panic!("unexpected condition: {:?}", other),
+        }
+        match Condition::from_name("warn") {
+            Ok(Condition::Level("warning")) => (),
+            other => panic!("unexpected condition: {:?}", other),
+        }
+        match Condition::from_name("deny") {
+            Ok(Condition::Level("error")) => (),
+            other => panic!("unexpected condition: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_name_unknown() {
+        assert_eq!(Err("`--fail-on needless_range_lop` is not a Clippy lint, lint group or level, did you mean \
+                        `needless_range_loop`?"
+                           .to_owned()),
+                   Condition::from_name("needless_range_lop").map(|condition| condition.to_string()));
+        assert_eq!(Err("`--fail-on warningz` is not a Clippy lint, lint group or level, did you mean `warnings`?"
+                           .to_owned()),
+                   Condition::from_name("warningz").map(|condition| condition.to_string()));
+        assert_eq!(Err("`--fail-on xyzzy` is not a Clippy lint, lint group or level".to_owned()),
+                   Condition::from_name("xyzzy").map(|condition| condition.to_string()));
+    }
+
+    #[test]
+    fn test_parse() {
+        let values = vec!["needless_range_loop, clippy_pedantic".to_owned(), "errors,".to_owned(), "warn".to_owned()];
+        let conditions = parse(&values).unwrap();
+        assert_eq!(vec!["needless_range_loop", "clippy_pedantic", "errors", "warnings"], names(&conditions));
+
+        assert!(parse(&[]).unwrap().is_empty());
+        assert!(parse(&["clippy,xyzzy".to_owned()]).is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let warnings = vec![warning("needless_range_loop", "warning"),
+                            warning("cast_possible_truncation", "warning"),
+                            warning("needless_range_loop", "warning"),
+                            warning("cast_possible_wrap", "error")];
+
+        let conditions = parse(&["needless_range_loop,clippy_pedantic".to_owned()]).unwrap();
+        assert_eq!(Some("error: 4 Clippy warnings fail the run because of `--fail-on`:\n       2  needless_range_loop \
+                         (`--fail-on needless_range_loop`)\n       1  cast_possible_truncation (`--fail-on \
+                         clippy_pedantic`)\n       1  cast_possible_wrap (`--fail-on clippy_pedantic`)\n"
+                           .to_owned()),
+                   report(&conditions, &warnings));
+
+        assert_eq!(None, report(&parse(&["needless_return".to_owned()]).unwrap(), &warnings));
+    }
+
+    #[test]
+    fn test_report_levels() {
+        let warnings = vec![warning("needless_range_loop", "warning"), warning("cast_possible_wrap", "error")];
+
+        // denied lints are Clippy warnings too
+        let conditions = parse(&["warnings".to_owned()]).unwrap();
+        assert_eq!(Some("error: 2 Clippy warnings fail the run because of `--fail-on`:\n       1  cast_possible_wrap \
+                         (`--fail-on warnings`)\n       1  needless_range_loop (`--fail-on warnings`)\n"
+                           .to_owned()),
+                   report(&conditions, &warnings));
+
+        let conditions = parse(&["errors".to_owned(), "cast_possible_wrap".to_owned()]).unwrap();
+        assert_eq!(Some("error: 1 Clippy warnings fail the run because of `--fail-on`:\n       1  cast_possible_wrap \
+                         (`--fail-on errors`, `--fail-on cast_possible_wrap`)\n"
+                           .to_owned()),
+                   report(&conditions, &warnings));
+    }
+}
@@ -0,0 +1,113 @@
+//! `cargo-clippy --file foo.rs`, which lints a single file without a Cargo project, like a script
+//! or a snippet from a bug report.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// The editions this version of rustc supports.
+const EDITIONS: &[&str] = &["2015"];
+
+/// The arguments to give to the driver to lint the file given with `--file` in `args`, the
+/// arguments of `cargo-clippy`. The other arguments, like `-L` or `-W`, are passed to rustc as is.
+pub fn rustc_args(mut args: Vec<String>) -> Result<Vec<String>, String> {
+    let mut files = super::remove_values(&mut args, &["--file"]);
+    let file = match (files.pop(), files.is_empty()) {
+        (Some(file), true) => file,
+        (Some(_), false) => return Err("`--file` can only be given once".to_owned()),
+        (None, _) => return Err("`--file` requires a file to lint".to_owned()),
+    };
+
+    // rustc only knows the 2015 edition, which is what it compiles
+    for edition in super::remove_values(&mut args, &["--edition"]) {
+        if !EDITIONS.contains(&edition.as_str()) {
+            return Err(format!("unsupported edition `{}`, this version of rustc supports `{}`",
+                               edition,
+                               EDITIONS.join("`, `")));
+        }
+    }
+
+    let mut source = String::new();
+    File::open(&file)
+        .and_then(|mut f| f.read_to_string(&mut source))
+        .map_err(|err| format!("could not read `{}`: {}", file, err))?;
+
+    // the first argument is the name of the program
+    let mut rustc_args = vec![args.remove(0), file.clone(), "-Zno-trans".to_owned()];
+    if !args.iter().any(|arg| arg == "--crate-type" || arg.starts_with("--crate-type=")) {
+        rustc_args.extend_from_slice(&["--crate-type".to_owned(), crate_type(&source).to_owned()]);
+    }
+    if !args.iter().any(|arg| arg == "--crate-name" || arg.starts_with("--crate-name=")) {
+        rustc_args.extend_from_slice(&["--crate-name".to_owned(), crate_name(Path::new(&file))]);
+    }
+    rustc_args.extend(args);
+    Ok(rustc_args)
+}
+
+/// The crate type of a file without `--crate-type`: a binary if it has a `main` function, a library
+/// otherwise.
+fn crate_type(source: &str) -> &'static str {
+    if source.contains("fn main(") { "bin" } else { "lib" }
+}
+
+/// A valid crate name for `path`: its file stem, where rustc would reject names like `bug-1234`.
+fn crate_name(path: &Path) -> String {
+    let stem = path.file_stem().map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+    let name: String = stem.chars().map(|c| if c.is_alphanumeric() || c == '_' { c } else { '_' }).collect();
+    if name.chars().next().map_or(true, |c| c.is_numeric()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use baseline;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::Path;
+    use super::{crate_name, crate_type, rustc_args};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&arg| arg.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_crate_name() {
+        assert_eq!("snippet", crate_name(Path::new("/tmp/snippet.rs")));
+        assert_eq!("bug_1234", crate_name(Path::new("bug-1234.rs")));
+        assert_eq!("a_b_c", crate_name(Path::new("a.b c.rs")));
+        assert_eq!("_1234", crate_name(Path::new("1234.rs")));
+        assert_eq!("_", crate_name(Path::new("")));
+    }
+
+    #[test]
+    fn test_crate_type() {
+        assert_eq!("bin", crate_type("fn main() {\n    println!(\"hello\");\n}\n"));
+        assert_eq!("lib", crate_type("pub fn foo() {}\n"));
+        assert_eq!("lib", crate_type("fn mainly() {}\n"));
+    }
+
+    #[test]
+    fn test_rustc_args() {
+        let dir = baseline::report_dir().unwrap();
+        let file = dir.join("bug-1234.rs");
+        File::create(&file).unwrap().write_all(b"fn main() {}\n").unwrap();
+        let file = file.to_string_lossy().into_owned();
+
+        assert_eq!(args(&["clippy-driver", &file, "-Zno-trans", "--crate-type", "bin", "--crate-name", "bug_1234",
+                          "-Wclippy_pedantic"]),
+                   rustc_args(args(&["clippy-driver", "--file", &file, "-Wclippy_pedantic"])).unwrap());
+        // the crate type and name given on the command line are kept
+        assert_eq!(args(&["clippy-driver", &file, "-Zno-trans", "--crate-type=lib", "--crate-name", "foo"]),
+                   rustc_args(args(&["clippy-driver", "--file", &file, "--crate-type=lib", "--crate-name", "foo"]))
+                       .unwrap());
+
+        assert!(rustc_args(args(&["clippy-driver", "--file", &file, "--edition", "2018"])).is_err());
+        assert!(rustc_args(args(&["clippy-driver", "--file", &file, "--file", &file])).is_err());
+        assert!(rustc_args(args(&["clippy-driver"])).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,207 @@
+//! Automatic application of suggestions for `cargo clippy --fix`.
+
+use clippy_lints::utils::sugg::Applicability;
+use diagnostics::Diagnostic;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// A replacement of the text between two byte offsets of a file.
+#[derive(Clone, Debug)]
+pub struct Replacement {
+    pub file_name: String,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub text: String,
+}
+
+impl Replacement {
+    /// Whether both replacements touch the same text. Two insertions at the same position overlap
+    /// too as their order would be arbitrary.
+    fn overlaps(&self, other: &Replacement) -> bool {
+        self.file_name == other.file_name &&
+        ((self.byte_start < other.byte_end && other.byte_start < self.byte_end) || self.byte_start == other.byte_start)
+    }
+}
+
+/// The replacements of one suggestion. They only make sense together.
+pub type Fix = Vec<Replacement>;
+
+/// Collect the suggestions of `diagnostics` that can be applied automatically.
+pub fn fixes(diagnostics: &[Diagnostic]) -> Vec<Fix> {
+    diagnostics.iter().filter_map(fix).collect()
+}
+
+/// The suggestion of `diagnostic` that can be applied automatically, that is its first
+/// `MachineApplicable` suggestion.
+pub fn fix(diagnostic: &Diagnostic) -> Option<Fix> {
+    diagnostic.children
+        .iter()
+        .find(|child| child.is_suggestion() && child.applicability == Some(Applicability::MachineApplicable))
+        .map(suggestion)
+}
+
+/// The replacements suggested by a sub-diagnostic, empty if it is not a suggestion.
+fn suggestion(child: &Diagnostic) -> Fix {
+    child.spans
+        .iter()
+        .filter_map(|span| {
+            span.suggested_replacement.as_ref().map(|text| {
+                Replacement {
+                    file_name: span.file_name.clone(),
+                    byte_start: span.byte_start,
+                    byte_end: span.byte_end,
+                    text: text.clone(),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Apply `fixes` to the source files.
+///
+/// A fix that overlaps a fix accepted before it is skipped, it will be found again by the next
+/// run. Fixes in files that do not exist (like `<std macros>`) are skipped too. Returns the number
+/// of fixes applied to each file.
+pub fn apply(fixes: Vec<Fix>) -> io::Result<BTreeMap<String, usize>> {
+    let mut accepted: BTreeMap<String, Vec<Replacement>> = BTreeMap::new();
+    let mut counts = BTreeMap::new();
+
+    for fix in fixes {
+        let applicable = fix.iter().all(|replacement| Path::new(&replacement.file_name).is_file()) &&
+                         !fix.iter().any(|replacement| {
+            accepted.get(&replacement.file_name)
+                    .map_or(false, |others| others.iter().any(|other| replacement.overlaps(other)))
+        });
+        if !applicable {
+            continue;
+        }
+
+        if let Some(replacement) = fix.first() {
+            *counts.entry(replacement.file_name.clone()).or_insert(0) += 1;
+        }
+        for replacement in fix {
+            accepted.entry(replacement.file_name.clone()).or_insert_with(Vec::new).push(replacement);
+        }
+    }
+
+    for (file_name, mut replacements) in accepted {
+        let mut source = String::new();
+        File::open(&file_name)?.read_to_string(&mut source)?;
+
+        replacements.sort_by_key(|replacement| replacement.byte_start);
+        let mut fixed = String::with_capacity(source.len());
+        let mut last = 0;
+        for replacement in &replacements {
+            if replacement.byte_end > source.len() || !source.is_char_boundary(replacement.byte_start) ||
+               !source.is_char_boundary(replacement.byte_end) {
+                return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                          format!("suggestion does not match the content of `{}`", file_name)));
+            }
+            fixed.push_str(&source[last..replacement.byte_start]);
+            fixed.push_str(&replacement.text);
+            last = replacement.byte_end;
+        }
+        fixed.push_str(&source[last..]);
+
+        File::create(&file_name)?.write_all(fixed.as_bytes())?;
+    }
+
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod test {
+    use baseline;
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
+    use std::path::Path;
+    use super::{apply, Replacement};
+
+    fn write(path: &Path, content: &str) -> String {
+        File::create(path).unwrap().write_all(content.as_bytes()).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    fn read(path: &str) -> String {
+        let mut content = String::new();
+        File::open(path).unwrap().read_to_string(&mut content).unwrap();
+        content
+    }
+
+    fn replacement(file_name: &str, byte_start: usize, byte_end: usize, text: &str) -> Replacement {
+        Replacement {
+            file_name: file_name.to_owned(),
+            byte_start: byte_start,
+            byte_end: byte_end,
+            text: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_apply_out_of_order() {
+        let dir = baseline::report_dir().unwrap();
+        let file = write(&dir.join("lib.rs"), "let a = x + 1;\nlet b = y + 2;\n");
+
+        // the replacements are sorted by position before being applied
+        let fixes = vec![vec![replacement(&file, 23, 28, "y.add(2)")], vec![replacement(&file, 8, 13, "x.add(1)")]];
+        let counts = apply(fixes).unwrap();
+
+        assert_eq!("let a = x.add(1);\nlet b = y.add(2);\n", read(&file));
+        assert_eq!(Some(&2), counts.get(&file));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_apply_overlapping() {
+        let dir = baseline::report_dir().unwrap();
+        let file = write(&dir.join("lib.rs"), "a + b + c");
+
+        let fixes = vec![vec![replacement(&file, 0, 5, "ab")],
+                         // overlaps the first fix
+                         vec![replacement(&file, 4, 9, "bc")],
+                         // insertion at the same position as the first fix
+                         vec![replacement(&file, 0, 0, "x")],
+                         // touches the end of the first fix without overlapping it
+                         vec![replacement(&file, 5, 6, "")]];
+        let counts = apply(fixes).unwrap();
+
+        assert_eq!("ab+ c", read(&file));
+        assert_eq!(Some(&2), counts.get(&file));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_apply_multiple_files() {
+        let dir = baseline::report_dir().unwrap();
+        let first = write(&dir.join("first.rs"), "fn foo() {}");
+        let second = write(&dir.join("second.rs"), "fn bar() {}");
+
+        let fixes = vec![vec![replacement(&first, 3, 6, "baz"), replacement(&second, 3, 6, "qux")],
+                         // a fix is skipped as a whole if one of its replacements overlaps
+                         vec![replacement(&first, 0, 2, "pub fn"), replacement(&second, 4, 5, "u")],
+                         vec![replacement(&second, 0, 0, "pub ")],
+                         // fixes in files that do not exist are skipped
+                         vec![replacement("<std macros>", 0, 1, "")]];
+        let counts = apply(fixes).unwrap();
+
+        assert_eq!("fn baz() {}", read(&first));
+        assert_eq!("pub fn qux() {}", read(&second));
+        assert_eq!(Some(&1), counts.get(&first));
+        assert_eq!(Some(&1), counts.get(&second));
+        assert_eq!(None, counts.get("<std macros>"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_apply_out_of_bounds() {
+        let dir = baseline::report_dir().unwrap();
+        let file = write(&dir.join("lib.rs"), "fn foo() {}");
+
+        assert!(apply(vec![vec![replacement(&file, 3, 42, "bar")]]).is_err());
+        // nothing is written if a suggestion does not match the file
+        assert_eq!("fn foo() {}", read(&file));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -6,14 +6,22 @@
 
 extern crate clippy_lints;
 extern crate getopts;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
 extern crate rustc;
 extern crate rustc_driver;
 extern crate rustc_errors;
 extern crate rustc_plugin;
 extern crate syntax;
+extern crate toml;
+#[cfg(test)]
+extern crate regex;
 
+use clippy_lints::utils::{emitted, suppression};
 use rustc_driver::{driver, CompilerCalls, RustcDefaultCalls, Compilation};
-use rustc::session::{config, Session};
+use rustc::session::{config, CompileResult, Session};
 use rustc::session::config::{Input, ErrorOutputType};
 use std::path::PathBuf;
 use std::process::{self, Command};
@@ -22,6 +30,20 @@ use std::io::{self, Write};
 
 extern crate cargo_metadata;
 
+mod audit;
+mod baseline;
+mod cache;
+mod config;
+mod diagnostics;
+mod diff;
+mod explain;
+mod fail_on;
+mod file;
+mod fix;
+mod profile;
+mod sarif;
+mod summary;
+
 struct ClippyCompilerCalls {
     default: RustcDefaultCalls,
     run_lints: bool,
@@ -82,27 +104,12 @@ impl<'a> CompilerCalls<'a> for ClippyCompilerCalls {
                                                                                           the krate must be parsed")
                                                                                  .span);
                     registry.args_hidden = Some(Vec::new());
-                    clippy_lints::register_plugins(&mut registry);
-
-                    let rustc_plugin::registry::Registry { early_lint_passes,
-                                                           late_lint_passes,
-                                                           lint_groups,
-                                                           llvm_passes,
-                                                           attributes,
-                                                           .. } = registry;
-                    let sess = &state.session;
-                    let mut ls = sess.lint_store.borrow_mut();
-                    for pass in early_lint_passes {
-                        ls.register_early_pass(Some(sess), true, pass);
-                    }
-                    for pass in late_lint_passes {
-                        ls.register_late_pass(Some(sess), true, pass);
-                    }
-
-                    for (name, to) in lint_groups {
-                        ls.register_group(Some(sess), true, name, to);
-                    }
+                    let conf = clippy_lints::read_conf(&registry);
+                    clippy_lints::register_plugins(&mut registry, &conf);
 
+                    // `register_plugins` already moved the lint passes and groups to the lint store
+                    let rustc_plugin::registry::Registry { llvm_passes, attributes, .. } = registry;
+                    let sess = &state.session;
                     sess.plugin_llvm_passes.borrow_mut().extend(llvm_passes);
                     sess.plugin_attributes.borrow_mut().extend(attributes);
                 }
@@ -124,10 +131,42 @@ Usage:
 Common options:
     -h, --help               Print this message
     --features               Features to compile for the package
+    --all                    Check all packages in the workspace
+    -p, --package <pkg>      Package to check, may be given several times
+    --exclude <pkg>          Exclude a package from `--all`, may be given several times
+    --lib                    Check only the library
+    --bin <name>             Check only the given binary, may be given several times
+    --bins                   Check all binaries
+    --example <name>         Check only the given example, may be given several times
+    --examples               Check all examples
+    --test <name>            Check only the given test, may be given several times
+    --tests                  Check all tests
+    --bench <name>           Check only the given benchmark, may be given several times
+    --benches                Check all benchmarks
+    --all-targets            Check all targets (default), cannot be combined with the flags above
+    --message-format <fmt>   Error format: human (default), json or sarif
+    --fix                    Apply the suggestions that are known to be correct
+    --cache                  Replay the warnings of the crates that did not change since the last run
+    --write-baseline <file>  Record the current warnings in a baseline file
+    --baseline <file>        Only report the warnings that are not in the baseline file
+    --diff-base <rev>        Only report the warnings of the lines changed since the git revision
+    --summary                Print the number of warnings per lint, lint group and file
+    --top <n>                Only list the <n> lints and files with the most warnings in the summary
+    --fail-on <cond>         Fail if a lint, lint group or level (warnings, errors) fires, may be repeated
+    --suppressions           List the Clippy lints allowed by attributes and comments, with their reasons
+    --profile-lints[=<fmt>]  Print the time spent in each lint pass: human (default) or json
+    --print-config[=<fmt>]   Print the configuration from `clippy.toml`: toml (default) or json
+    --list-lints[=<fmt>]     List the lints with their level and group: human (default) or json
+    --list-groups            List the lint groups, including presets like `clippy_no_panic`
+    --explain <lint>         Print the documentation of a lint, or the lints of a lint group
     -V, --version            Print version info and exit
 
 Other options are the same as `cargo rustc`.
 
+To lint a single file without a Cargo project:
+
+    cargo-clippy --file <file.rs> [--edition <year>] [-L <dir>...] [<rustc opts>...]
+
 To allow or deny a lint from the command line you can use `cargo clippy --`
 with:
 
@@ -172,100 +211,776 @@ pub fn main() {
     if let Some("clippy") = std::env::args().nth(1).as_ref().map(AsRef::as_ref) {
         // this arm is executed on the initial call to `cargo clippy`
 
-        let manifest_path_arg = std::env::args().skip(2).find(|val| val.starts_with("--manifest-path="));
+        cargo_clippy(std::env::args().skip(2).collect());
+    } else if std::env::args().any(|arg| arg == "--file" || arg.starts_with("--file=")) {
+        // this arm is executed on `cargo-clippy --file foo.rs`, without cargo
 
-        let mut metadata = if let Ok(metadata) = cargo_metadata::metadata(manifest_path_arg.as_ref()
-            .map(AsRef::as_ref)) {
-            metadata
-        } else {
-            let _ = io::stderr().write_fmt(format_args!("error: Could not obtain cargo metadata.\n"));
-            process::exit(101);
-        };
+        let args = file::rustc_args(std::env::args().collect()).unwrap_or_else(|err| fail(&err));
+        run_driver(args);
+    } else {
+        // this arm is executed when cargo-clippy runs `cargo rustc` with the `RUSTC` env var set to itself
 
-        let manifest_path = manifest_path_arg.map(|arg| PathBuf::from(Path::new(&arg["--manifest-path=".len()..])));
-
-        let current_dir = std::env::current_dir();
-
-        let package_index = metadata.packages
-            .iter()
-            .position(|package| {
-                let package_manifest_path = Path::new(&package.manifest_path);
-                if let Some(ref manifest_path) = manifest_path {
-                    package_manifest_path == manifest_path
-                } else {
-                    let current_dir = current_dir.as_ref().expect("could not read current directory");
-                    let package_manifest_directory = package_manifest_path.parent()
-                        .expect("could not find parent directory of package manifest");
-                    package_manifest_directory == current_dir
-                }
+        run_driver(std::env::args().collect());
+    }
+}
+
+/// The sysroot of the toolchain Clippy was built with.
+fn sys_root() -> String {
+    let home = option_env!("RUSTUP_HOME").or(option_env!("MULTIRUST_HOME"));
+    let toolchain = option_env!("RUSTUP_TOOLCHAIN").or(option_env!("MULTIRUST_TOOLCHAIN"));
+    if let (Some(home), Some(toolchain)) = (home, toolchain) {
+        format!("{}/toolchains/{}", home, toolchain)
+    } else {
+        option_env!("SYSROOT")
+            .map(|s| s.to_owned())
+            .or_else(|| {
+                Command::new("rustc")
+                    .arg("--print")
+                    .arg("sysroot")
+                    .output()
+                    .ok()
+                    .and_then(|out| String::from_utf8(out.stdout).ok())
+                    .map(|s| s.trim().to_owned())
             })
-            .expect("could not find matching package");
-        let package = metadata.packages.remove(package_index);
-        for target in package.targets {
-            let args = std::env::args().skip(2);
-            if let Some(first) = target.kind.get(0) {
-                if target.kind.len() > 1 || first.ends_with("lib") {
-                    if let Err(code) = process(std::iter::once("--lib".to_owned()).chain(args)) {
-                        std::process::exit(code);
-                    }
-                } else if ["bin", "example", "test", "bench"].contains(&&**first) {
-                    if let Err(code) = process(vec![format!("--{}", first), target.name].into_iter().chain(args)) {
-                        std::process::exit(code);
+            .expect("need to specify SYSROOT env var during clippy compilation, or use rustup or multirust")
+    }
+}
+
+/// Run the compiler with the rustc arguments `args`, the first one being the program name, and
+/// Clippy's lints if they contain `-Zno-trans`.
+fn run_driver(args: Vec<String>) {
+    let sys_root = sys_root();
+    rustc_driver::in_rustc_thread(move || {
+            // this conditional check for the --sysroot flag is there so users can call `cargo-clippy` directly
+            // without having to pass --sysroot or anything
+            let mut args = args;
+            if !args.iter().any(|s| s == "--sysroot") {
+                args.extend_from_slice(&["--sysroot".to_owned(), sys_root]);
+            }
+
+            // this check ensures that dependencies are built but not linted and the final crate is
+            // linted but not built
+            let clippy_enabled = args.iter().any(|s| s == "-Zno-trans");
+
+            if clippy_enabled {
+                args.extend_from_slice(&["--cfg".to_owned(), r#"feature="cargo-clippy""#.to_owned()]);
+            }
+
+            let mut ccc = ClippyCompilerCalls::new(clippy_enabled);
+            let options = Options::from_env();
+            let profile_format = if clippy_enabled { options.profile } else { None };
+            if profile_format.is_some() {
+                clippy_lints::utils::profile::start();
+            }
+            let result = if clippy_enabled && options.capture() {
+                run_compiler_captured(&args, &mut ccc, &options)
+            } else {
+                rustc_driver::run_compiler(&args, &mut ccc, None, None).0
+            };
+            if let Some(format) = profile_format {
+                profile::print(format, profile::crate_name(&args), &clippy_lints::utils::profile::take());
+            }
+            if let Err(err_count) = result {
+                if err_count > 0 {
+                    std::process::exit(1);
+                }
+            }
+        })
+        .expect("rustc_thread failed");
+}
+
+/// Print an error and exit.
+fn fail(msg: &str) -> ! {
+    let _ = writeln!(io::stderr(), "error: {}", msg);
+    process::exit(101);
+}
+
+/// Create a temporary directory for the `cargo rustc` child processes and pass its path down to
+/// them in the environment variable `var`.
+fn temp_dir_for_children(var: &str) -> PathBuf {
+    match baseline::report_dir() {
+        Ok(dir) => {
+            std::env::set_var(var, &dir);
+            dir
+        },
+        Err(err) => fail(&format!("could not create a temporary directory: {}", err)),
+    }
+}
+
+/// Run `cargo clippy` with the given arguments.
+fn cargo_clippy(mut args: Vec<String>) {
+    use std::env;
+
+    if run_info_command(&mut args) {
+        return;
+    }
+
+    let all = remove_flag(&mut args, "--all");
+    let fix = remove_flag(&mut args, "--fix");
+    if remove_flag(&mut args, "--cache") {
+        env::set_var("CLIPPY_CACHE", "1");
+    }
+    let package_names = remove_values(&mut args, &["-p", "--package"]);
+    let excluded = remove_values(&mut args, &["--exclude"]);
+    let targets = TargetFilter::from_args(&mut args).unwrap_or_else(|err| fail(&err));
+
+    let baseline_mode = baseline_mode(&mut args).unwrap_or_else(|err| fail(&err));
+
+    let changed_lines = remove_values(&mut args, &["--diff-base"]).pop().map(|base| {
+        let path = diff::ChangedLines::new(&base).and_then(|changed| changed.save()).unwrap_or_else(|err| fail(&err));
+        env::set_var("CLIPPY_CHANGED_LINES", &path);
+        path
+    });
+
+    let top = remove_values(&mut args, &["--top"]).pop().map(|top| {
+        top.parse::<usize>().unwrap_or_else(|err| fail(&format!("invalid value for `--top`: {}", err)))
+    });
+    // `--top` implies `--summary`
+    let summary = remove_flag(&mut args, "--summary") || top.is_some();
+    let fail_on = fail_on::parse(&remove_values(&mut args, &["--fail-on"])).unwrap_or_else(|err| fail(&err));
+    // `--fail-on` checks the warnings collected for the summary
+    let summary_dir = if summary || !fail_on.is_empty() {
+        Some(temp_dir_for_children("CLIPPY_SUMMARY_DIR"))
+    } else {
+        None
+    };
+
+    let suppressions_dir = if remove_flag(&mut args, "--suppressions") {
+        Some(temp_dir_for_children("CLIPPY_SUPPRESSIONS_DIR"))
+    } else {
+        None
+    };
+
+    let sarif_dir = pass_down_formats(&mut args);
+
+    // the manifest path is replaced by the one of each selected package
+    let manifest_path_index = args.iter().position(|val| val.starts_with("--manifest-path="));
+    let manifest_path_arg = manifest_path_index.map(|i| args.remove(i));
+
+    let metadata = if let Ok(metadata) = cargo_metadata::metadata(manifest_path_arg.as_ref()
+        .map(AsRef::as_ref)) {
+        metadata
+    } else {
+        fail("Could not obtain cargo metadata.");
+    };
+
+    let manifest_path = manifest_path_arg.map(|arg| PathBuf::from(Path::new(&arg["--manifest-path=".len()..])));
+
+    let packages = select_packages(metadata.packages,
+                                   manifest_path.as_ref().map(AsRef::as_ref),
+                                   all,
+                                   &package_names,
+                                   &excluded)
+        .unwrap_or_else(|err| fail(&err));
+    if let Err(err) = targets.check(&packages) {
+        fail(&err);
+    }
+
+    // the child processes report the warnings they find in this directory
+    let report_dir = baseline_mode.as_ref().map(|mode| {
+        if let baseline::Mode::Check(ref path) = *mode {
+            env::set_var("CLIPPY_BASELINE", path);
+        }
+        temp_dir_for_children("CLIPPY_REPORT_DIR")
+    });
+
+    if fix {
+        // apply the fixes first, then lint again to show what is left and check that the fixed
+        // lints are gone
+        env::set_var("CLIPPY_FIX", "apply");
+        let exit_code = lint_packages(&packages, &targets, &args);
+        if exit_code != 0 {
+            process::exit(exit_code);
+        }
+        env::set_var("CLIPPY_FIX", "verify");
+    }
+
+    let exit_code = lint_packages(&packages, &targets, &args);
+
+    if let Some(path) = changed_lines {
+        let _ = std::fs::remove_file(path);
+    }
+
+    if let Some(dir) = sarif_dir {
+        let results = sarif::load_all(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        sarif::print(results.unwrap_or_else(|err| fail(&err)), &sarif::root(), exit_code == 0);
+    }
+
+    if let Some(dir) = suppressions_dir {
+        let suppressions = audit::load_all(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        audit::print(&suppressions.unwrap_or_else(|err| fail(&err)));
+    }
+
+    let mut failed_on = false;
+    if let Some(dir) = summary_dir {
+        let warnings = summary::load_all(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        let warnings = warnings.unwrap_or_else(|err| fail(&err));
+        if summary {
+            summary::print(&warnings, top);
+        }
+        failed_on = fail_on::check(&fail_on, &warnings);
+    }
+
+    if let (Some(mode), Some(dir)) = (baseline_mode, report_dir) {
+        let result = finish_baseline(&mode, &dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        if let Err(err) = result {
+            fail(&err);
+        }
+    }
+
+    if exit_code != 0 {
+        process::exit(exit_code);
+    }
+    if failed_on {
+        process::exit(1);
+    }
+}
+
+/// Run the commands that print some information instead of linting, like `--explain`. Returns
+/// whether one was run.
+fn run_info_command(args: &mut Vec<String>) -> bool {
+    match flag_with_format(args, "--list-lints", "human").as_ref().map(AsRef::as_ref) {
+        None => (),
+        Some("human") => {
+            explain::list();
+            return true;
+        },
+        Some("json") => {
+            explain::list_json();
+            return true;
+        },
+        Some(format) => fail(&format!("unknown format `{}`, expected `human` or `json`", format)),
+    }
+
+    if remove_flag(args, "--list-groups") {
+        explain::list_groups();
+        return true;
+    }
+
+    if let Some(lint) = remove_values(args, &["--explain"]).pop() {
+        explain::explain(&lint).unwrap_or_else(|err| fail(&err));
+        return true;
+    }
+
+    if let Some(format) = flag_with_format(args, "--print-config", "toml") {
+        let format = config::Format::from_name(&format).unwrap_or_else(|| {
+            fail(&format!("unknown configuration format `{}`, expected `toml` or `json`", format))
+        });
+        if !config::print(format) {
+            process::exit(101);
+        }
+        return true;
+    }
+
+    false
+}
+
+/// Check the output formats and pass them down to the child processes, where the driver runs.
+/// Returns the directory where they save their results if a SARIF log was requested.
+fn pass_down_formats(args: &mut Vec<String>) -> Option<PathBuf> {
+    if let Some(format) = flag_with_format(args, "--profile-lints", "human") {
+        if profile::Format::from_name(&format).is_none() {
+            fail(&format!("unknown profile format `{}`, expected `human` or `json`", format));
+        }
+        std::env::set_var("CLIPPY_PROFILE_LINTS", format);
+    }
+
+    match remove_values(args, &["--message-format"]).last().map(AsRef::as_ref) {
+        None | Some("human") => None,
+        Some("json") => {
+            std::env::set_var("CLIPPY_MESSAGE_FORMAT", "json");
+            None
+        },
+        // the diagnostics are still shown on the standard error, the log is printed at the end
+        Some("sarif") => Some(temp_dir_for_children("CLIPPY_SARIF_DIR")),
+        Some(format) => fail(&format!("unknown message format `{}`, expected `human`, `json` or `sarif`", format)),
+    }
+}
+
+/// What `cargo clippy --fix` asks the driver to do.
+#[derive(Clone, Copy, PartialEq)]
+enum FixMode {
+    /// Apply the suggestions that can be applied automatically.
+    Apply,
+    /// Lint normally, and warn about the suggestions that should have been applied.
+    Verify,
+}
+
+/// How the driver handles the diagnostics of the crate it lints.
+///
+/// The driver runs in the `cargo rustc` child processes of `cargo clippy`, which passes its options
+/// down through environment variables.
+struct Options {
+    /// Print the diagnostics as JSON instead of the human-readable format.
+    json: bool,
+    /// Whether `--fix` was given, and in which run we are.
+    fix: Option<FixMode>,
+    /// The baseline file given with `--baseline`.
+    baseline: Option<PathBuf>,
+    /// Where to save the warnings found, for `--baseline` and `--write-baseline`.
+    report_dir: Option<PathBuf>,
+    /// The lines changed since the revision given with `--diff-base`.
+    changed_lines: Option<PathBuf>,
+    /// Where to save the warnings reported, for `--summary`.
+    summary_dir: Option<PathBuf>,
+    /// Where to save the results, for `--message-format sarif`.
+    sarif_dir: Option<PathBuf>,
+    /// Where to save the suppressions of the crate, for `--suppressions`.
+    suppressions_dir: Option<PathBuf>,
+    /// Whether to replay the diagnostics of the last run if the crate did not change, for `--cache`.
+    cache: bool,
+    /// How to print the time of the lint passes, if `--profile-lints` was given.
+    profile: Option<profile::Format>,
+}
+
+impl Options {
+    fn from_env() -> Self {
+        Options {
+            json: std::env::var("CLIPPY_MESSAGE_FORMAT").map(|format| format == "json").unwrap_or(false),
+            fix: match std::env::var("CLIPPY_FIX").as_ref().map(AsRef::as_ref) {
+                Ok("apply") => Some(FixMode::Apply),
+                Ok("verify") => Some(FixMode::Verify),
+                _ => None,
+            },
+            baseline: std::env::var_os("CLIPPY_BASELINE").map(PathBuf::from),
+            report_dir: std::env::var_os("CLIPPY_REPORT_DIR").map(PathBuf::from),
+            changed_lines: std::env::var_os("CLIPPY_CHANGED_LINES").map(PathBuf::from),
+            summary_dir: std::env::var_os("CLIPPY_SUMMARY_DIR").map(PathBuf::from),
+            sarif_dir: std::env::var_os("CLIPPY_SARIF_DIR").map(PathBuf::from),
+            suppressions_dir: std::env::var_os("CLIPPY_SUPPRESSIONS_DIR").map(PathBuf::from),
+            cache: std::env::var_os("CLIPPY_CACHE").is_some(),
+            profile: std::env::var("CLIPPY_PROFILE_LINTS").ok().and_then(|format| profile::Format::from_name(&format)),
+        }
+    }
+
+    /// Whether the diagnostics need to be captured and processed before being emitted.
+    fn capture(&self) -> bool {
+        self.json || self.fix.is_some() || self.report_dir.is_some() || self.changed_lines.is_some() ||
+        self.summary_dir.is_some() || self.sarif_dir.is_some() || self.suppressions_dir.is_some() || self.cache
+    }
+}
+
+/// Run the compiler with JSON diagnostics, annotate them with the Clippy lint that produced them,
+/// process them as requested by `options` and emit the result.
+fn run_compiler_captured(args: &[String], ccc: &mut ClippyCompilerCalls, options: &Options) -> CompileResult {
+    let cache = if options.cache { cache::Cache::new(args) } else { None };
+    let (mut found, mut result, files, suppressions) = match cache.as_ref().and_then(cache::Cache::load) {
+        Some((found, 0, files, suppressions)) => (found, Ok(()), files, suppressions),
+        Some((found, errors, files, suppressions)) => (found, Err(errors), files, suppressions),
+        None => compile_captured(args, ccc, cache.as_ref()),
+    };
+
+    match options.fix {
+        Some(FixMode::Apply) => {
+            // the remaining diagnostics are shown by the verification run
+            match fix::apply(fix::fixes(&found)) {
+                Ok(counts) => {
+                    for (file_name, count) in counts {
+                        let _ = writeln!(io::stderr(), "Fixed {} ({} fixes)", file_name, count);
                     }
+                },
+                Err(err) => {
+                    let _ = writeln!(io::stderr(), "error: could not apply the suggestions: {}", err);
+                    return Err(result.err().map_or(1, |errors| errors + 1));
+                },
+            }
+            return result;
+        },
+        Some(FixMode::Verify) => {
+            for diagnostic in found.iter().filter(|diagnostic| fix::fix(diagnostic).is_some()) {
+                if let (Some(lint), Some(span)) = (diagnostic.lint.as_ref(), diagnostic.primary_span()) {
+                    let _ = writeln!(io::stderr(),
+                                     "warning: the suggestion of `{}` at {}:{}:{} was not applied automatically",
+                                     lint,
+                                     span.file_name,
+                                     span.line_start,
+                                     span.column_start);
+                }
+            }
+        },
+        None => (),
+    }
+
+    if let Some(ref dir) = options.report_dir {
+        if let Err(err) = baseline::Report::new(&baseline::package(), files, &found).save(dir) {
+            let _ = writeln!(io::stderr(), "error: could not save the warnings for the baseline: {}", err);
+        }
+    }
+    if let Some(ref path) = options.baseline {
+        match baseline::read(path) {
+            Ok(known) => {
+                baseline::suppress(&mut found, &baseline::package(), &known);
+                result = recount_errors(&mut found, result);
+            },
+            Err(err) => {
+                let _ = writeln!(io::stderr(), "error: {}", err);
+            },
+        }
+    }
+
+    if let Some(ref path) = options.changed_lines {
+        match diff::ChangedLines::load(path) {
+            Ok(changed) => {
+                let hidden = diff::filter(&mut found, &changed);
+                if hidden > 0 {
+                    let _ = writeln!(io::stderr(),
+                                     "note: {} warnings outside of the lines changed since `{}` were not shown",
+                                     hidden,
+                                     changed.base);
+                }
+                result = recount_errors(&mut found, result);
+            },
+            Err(err) => {
+                let _ = writeln!(io::stderr(), "error: {}", err);
+            },
+        }
+    }
+
+    if let Some(ref dir) = options.summary_dir {
+        if let Err(err) = summary::save(dir, &found) {
+            let _ = writeln!(io::stderr(), "error: could not save the warnings for the summary: {}", err);
+        }
+    }
+
+    if let Some(ref dir) = options.sarif_dir {
+        if let Err(err) = sarif::save(dir, &found) {
+            let _ = writeln!(io::stderr(), "error: could not save the results for the SARIF log: {}", err);
+        }
+    }
+
+    if let Some(ref dir) = options.suppressions_dir {
+        if let Err(err) = audit::save(dir, &suppressions) {
+            let _ = writeln!(io::stderr(), "error: could not save the suppressions for the report: {}", err);
+        }
+    }
+
+    if options.json {
+        diagnostics::emit_json(&found, &suppressions);
+    } else {
+        diagnostics::emit_human(&found);
+    }
+
+    result
+}
+
+/// Run the compiler with JSON diagnostics and annotate them with the Clippy lint that produced
+/// them. Returns the diagnostics, the result of the compilation, the source files of the crate and
+/// its suppression comments, which are saved in `cache` for the next run.
+fn compile_captured(args: &[String], ccc: &mut ClippyCompilerCalls, cache: Option<&cache::Cache>)
+                    -> (Vec<diagnostics::Diagnostic>, CompileResult, Vec<String>, Vec<diagnostics::Suppression>) {
+    let mut args = args.to_owned();
+    args.push("--error-format=json".to_owned());
+
+    let buffer = diagnostics::Buffer::default();
+    emitted::start_recording();
+    let (result, sess) = rustc_driver::run_compiler(&args, ccc, None, Some(box buffer.clone()));
+    let emitted = emitted::take();
+    let suppressions: Vec<_> = suppression::take().iter().map(diagnostics::Suppression::from).collect();
+
+    let (mut found, other) = buffer.diagnostics();
+    for line in other {
+        let _ = writeln!(io::stderr(), "{}", line);
+    }
+    let sess = if let Some(sess) = sess {
+        sess
+    } else {
+        return (found, result, Vec::new(), suppressions);
+    };
+    diagnostics::annotate(&mut found, emitted, &diagnostics::lint_groups(&sess));
+
+    let files = baseline::source_files(&sess);
+    if let Some(cache) = cache {
+        if let Err(err) = cache.store(&files, &found, &suppressions, result.err().unwrap_or(0)) {
+            let _ = writeln!(io::stderr(), "warning: could not cache the warnings: {}", err);
+        }
+    }
+    (found, result, files, suppressions)
+}
+
+/// Fix up the result of the compilation once some diagnostics were suppressed: if the only errors
+/// left are rustc's "aborting due to previous error" summaries, nothing failed anymore.
+fn recount_errors(found: &mut Vec<diagnostics::Diagnostic>, result: CompileResult) -> CompileResult {
+    let is_summary = |diagnostic: &diagnostics::Diagnostic| {
+        diagnostic.level == "error" && diagnostic.message.starts_with("aborting due to")
+    };
+    if result.is_ok() || found.iter().any(|diagnostic| diagnostic.level == "error" && !is_summary(diagnostic)) {
+        return result;
+    }
+    found.retain(|diagnostic| !is_summary(diagnostic));
+    Ok(())
+}
+
+/// Parse a flag like `--print-config` that may be given a format, as in `--print-config=json`,
+/// returning the format if the flag is present.
+fn flag_with_format(args: &mut Vec<String>, flag: &str, default: &str) -> Option<String> {
+    if remove_flag(args, flag) {
+        Some(default.to_owned())
+    } else {
+        remove_values(args, &[flag]).pop()
+    }
+}
+
+/// Parse `--baseline <file>` and `--write-baseline <file>`. The path is made absolute, as the
+/// child processes do not run in the current directory.
+fn baseline_mode(args: &mut Vec<String>) -> Result<Option<baseline::Mode>, String> {
+    let check = remove_values(args, &["--baseline"]).pop();
+    let write = remove_values(args, &["--write-baseline"]).pop();
+    let current_dir = std::env::current_dir().map_err(|err| format!("could not read current directory: {}", err))?;
+    match (check, write) {
+        (Some(_), Some(_)) => Err("`--baseline` and `--write-baseline` cannot be used together".to_owned()),
+        (Some(path), None) => {
+            let path = current_dir.join(path);
+            // fail early instead of in every child process
+            baseline::read(&path)?;
+            Ok(Some(baseline::Mode::Check(path)))
+        },
+        (None, Some(path)) => Ok(Some(baseline::Mode::Write(current_dir.join(path)))),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Combine the reports of the child processes: write the baseline, or report its stale entries.
+fn finish_baseline(mode: &baseline::Mode, report_dir: &Path) -> Result<(), String> {
+    let report = baseline::Report::load_all(report_dir)?;
+    match *mode {
+        baseline::Mode::Write(ref path) => {
+            let count = report.warnings.len();
+            baseline::write(path, report.warnings)
+                .map_err(|err| format!("could not write baseline `{}`: {}", path.display(), err))?;
+            let _ = writeln!(io::stderr(), "Wrote {} warnings to {}", count, path.display());
+        },
+        baseline::Mode::Check(ref path) => {
+            let stale = baseline::stale(&baseline::read(path)?, &report);
+            if !stale.is_empty() {
+                let mut handle = io::stderr();
+                let _ = writeln!(handle,
+                                 "warning: {} entries of the baseline `{}` do not match any warning anymore:",
+                                 stale.len(),
+                                 path.display());
+                for entry in &stale {
+                    let _ = writeln!(handle, "    `{}` in {} ({})", entry.lint, entry.file, entry.fingerprint);
                 }
+                let _ = writeln!(handle, "    = note: run `cargo clippy --write-baseline` to update the baseline");
+            }
+        },
+    }
+    Ok(())
+}
+
+/// Lint every target of every package, returning the exit code of the first failure, or `0`.
+///
+/// All packages are linted even if an earlier one failed.
+fn lint_packages(packages: &[cargo_metadata::Package], targets: &TargetFilter, args: &[String]) -> i32 {
+    let mut exit_code = 0;
+    for package in packages {
+        if let Err(code) = lint_package(package, targets, args) {
+            if exit_code == 0 {
+                exit_code = code;
+            }
+        }
+    }
+    exit_code
+}
+
+/// Removes every occurrence of `flag` that appears before `--` from `args`, returning whether it
+/// was present.
+fn remove_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let end = args.iter().position(|arg| arg == "--").unwrap_or_else(|| args.len());
+    let len = args.len();
+    let mut i = 0;
+    args.retain(|arg| {
+        i += 1;
+        i > end || arg != flag
+    });
+    args.len() != len
+}
+
+/// Removes every `flag value` and `flag=value` pair that appears before `--` from `args`,
+/// returning the values in order. `flags` lists the spellings of the same option, e.g. `-p` and
+/// `--package`.
+fn remove_values(args: &mut Vec<String>, flags: &[&str]) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut i = 0;
+    while i < args.len() && args[i] != "--" {
+        if flags.contains(&&*args[i]) && i + 1 < args.len() {
+            args.remove(i);
+            values.push(args.remove(i));
+            continue;
+        }
+        let value = flags.iter()
+            .find(|flag| args[i].starts_with(&format!("{}=", flag)))
+            .map(|flag| args[i][flag.len() + 1..].to_owned());
+        if let Some(value) = value {
+            args.remove(i);
+            values.push(value);
+        } else {
+            i += 1;
+        }
+    }
+    values
+}
+
+/// Pick the packages to lint from the workspace members listed by `cargo metadata`.
+///
+/// Without `--all` or `-p`, this is the package whose manifest is at `manifest_path` (or in the
+/// current directory), which is what `cargo rustc` would build. Like with cargo, `-p` cannot be
+/// combined with `--all`, and `--exclude` requires it.
+fn select_packages(
+    packages: Vec<cargo_metadata::Package>,
+    manifest_path: Option<&Path>,
+    all: bool,
+    names: &[String],
+    excluded: &[String]
+) -> Result<Vec<cargo_metadata::Package>, String> {
+    if all && !names.is_empty() {
+        return Err("`-p` cannot be used together with `--all`".to_owned());
+    }
+    if !all && !excluded.is_empty() {
+        return Err("`--exclude` can only be used together with `--all`".to_owned());
+    }
+
+    if all {
+        if let Some(name) = excluded.iter().find(|name| !packages.iter().any(|package| &package.name == *name)) {
+            return Err(format!("excluded package `{}` is not a member of the workspace", name));
+        }
+        return Ok(packages.into_iter().filter(|package| !excluded.contains(&package.name)).collect());
+    }
+
+    if !names.is_empty() {
+        if let Some(name) = names.iter().find(|name| !packages.iter().any(|package| &package.name == *name)) {
+            return Err(format!("package `{}` is not a member of the workspace", name));
+        }
+        return Ok(packages.into_iter().filter(|package| names.contains(&package.name)).collect());
+    }
+
+    let current_dir = std::env::current_dir().map_err(|err| format!("could not read current directory: {}", err))?;
+    packages.into_iter()
+        .find(|package| {
+            let package_manifest_path = Path::new(&package.manifest_path);
+            if let Some(manifest_path) = manifest_path {
+                package_manifest_path == manifest_path
             } else {
-                panic!("badly formatted cargo metadata: target::kind is an empty array");
+                let package_manifest_directory = package_manifest_path.parent()
+                    .expect("could not find parent directory of package manifest");
+                package_manifest_directory == current_dir
             }
+        })
+        .map(|package| vec![package])
+        .ok_or_else(|| {
+            "could not find a package to lint in the current directory, use `--all` to lint the whole workspace or \
+             `-p <package>` to select packages"
+                .to_owned()
+        })
+}
+
+/// The kind of target `cargo clippy` lints `target` as: `lib`, `bin`, `example`, `test` or
+/// `bench`. Other targets, like build scripts, are not linted.
+fn target_kind(target: &cargo_metadata::Target) -> Option<&str> {
+    if let Some(first) = target.kind.get(0) {
+        if target.kind.len() > 1 || first.ends_with("lib") {
+            Some("lib")
+        } else if ["bin", "example", "test", "bench"].contains(&&**first) {
+            Some(first.as_str())
+        } else {
+            None
         }
     } else {
-        // this arm is executed when cargo-clippy runs `cargo rustc` with the `RUSTC` env var set to itself
+        panic!("badly formatted cargo metadata: target::kind is an empty array");
+    }
+}
+
+/// The targets selected with `--lib`, `--bin <name>`, `--tests`, etc.
+#[derive(Debug, Default)]
+struct TargetFilter {
+    /// The kinds of targets selected entirely, like `bin` for `--bins`.
+    kinds: Vec<&'static str>,
+    /// The targets selected by kind and name, like `("bin", "foo")` for `--bin foo`.
+    named: Vec<(&'static str, String)>,
+}
+
+impl TargetFilter {
+    /// Remove the target selection flags from `args`. `--all-targets` cannot be combined with the
+    /// flags that select some targets.
+    fn from_args(args: &mut Vec<String>) -> Result<TargetFilter, String> {
+        let mut filter = TargetFilter::default();
+        if remove_flag(args, "--lib") {
+            filter.kinds.push("lib");
+        }
+        let kinds = [("--bins", "bin"), ("--examples", "example"), ("--tests", "test"), ("--benches", "bench")];
+        for &(flag, kind) in &kinds {
+            if remove_flag(args, flag) {
+                filter.kinds.push(kind);
+            }
+        }
+        for &(flag, kind) in &[("--bin", "bin"), ("--example", "example"), ("--test", "test"), ("--bench", "bench")] {
+            filter.named.extend(remove_values(args, &[flag]).into_iter().map(|name| (kind, name)));
+        }
+        if remove_flag(args, "--all-targets") && (!filter.kinds.is_empty() || !filter.named.is_empty()) {
+            return Err("`--all-targets` cannot be used together with the flags that select some targets, like \
+                        `--lib` or `--bin <name>`"
+                .to_owned());
+        }
+        Ok(filter)
+    }
 
-        let home = option_env!("RUSTUP_HOME").or(option_env!("MULTIRUST_HOME"));
-        let toolchain = option_env!("RUSTUP_TOOLCHAIN").or(option_env!("MULTIRUST_TOOLCHAIN"));
-        let sys_root = if let (Some(home), Some(toolchain)) = (home, toolchain) {
-            format!("{}/toolchains/{}", home, toolchain)
+    /// Whether `target` is selected. Without any flag, all targets are.
+    fn matches(&self, target: &cargo_metadata::Target) -> bool {
+        let kind = if let Some(kind) = target_kind(target) {
+            kind
         } else {
-            option_env!("SYSROOT")
-                .map(|s| s.to_owned())
-                .or_else(|| {
-                    Command::new("rustc")
-                        .arg("--print")
-                        .arg("sysroot")
-                        .output()
-                        .ok()
-                        .and_then(|out| String::from_utf8(out.stdout).ok())
-                        .map(|s| s.trim().to_owned())
-                })
-                .expect("need to specify SYSROOT env var during clippy compilation, or use rustup or multirust")
+            return false;
         };
+        (self.kinds.is_empty() && self.named.is_empty()) || self.kinds.contains(&kind) ||
+        self.named.iter().any(|&(named_kind, ref name)| named_kind == kind && *name == target.name)
+    }
 
-        rustc_driver::in_rustc_thread(|| {
-                // this conditional check for the --sysroot flag is there so users can call `cargo-clippy` directly
-                // without having to pass --sysroot or anything
-                let mut args: Vec<String> = if env::args().any(|s| s == "--sysroot") {
-                    env::args().collect()
-                } else {
-                    env::args().chain(Some("--sysroot".to_owned())).chain(Some(sys_root)).collect()
-                };
-
-                // this check ensures that dependencies are built but not linted and the final crate is
-                // linted but not built
-                let clippy_enabled = env::args().any(|s| s == "-Zno-trans");
-
-                if clippy_enabled {
-                    args.extend_from_slice(&["--cfg".to_owned(), r#"feature="cargo-clippy""#.to_owned()]);
+    /// Check that every target named with a flag like `--bin <name>` exists in `packages`, and
+    /// that at least one target is selected.
+    fn check(&self, packages: &[cargo_metadata::Package]) -> Result<(), String> {
+        let targets: Vec<_> = packages.iter().flat_map(|package| package.targets.iter()).collect();
+        for &(kind, ref name) in &self.named {
+            if !targets.iter().any(|target| target_kind(target) == Some(kind) && target.name == *name) {
+                let mut err = format!("no {} target named `{}`", kind, name);
+                let names = targets.iter()
+                    .filter(|target| target_kind(target) == Some(kind))
+                    .map(|target| &*target.name);
+                if let Some(similar) = clippy_lints::utils::conf::similar_name(name, names) {
+                    err.push_str(&format!(", did you mean `{}`?", similar));
                 }
+                return Err(err);
+            }
+        }
+        if !targets.iter().any(|target| self.matches(target)) {
+            if self.kinds.is_empty() {
+                return Err("no target to check in the selected packages".to_owned());
+            }
+            let kinds: Vec<_> = self.kinds.iter().map(|kind| format!("`{}`", kind)).collect();
+            return Err(format!("no {} target to check in the selected packages", kinds.join(" or ")));
+        }
+        Ok(())
+    }
+}
 
-                let mut ccc = ClippyCompilerCalls::new(clippy_enabled);
-                let (result, _) = rustc_driver::run_compiler(&args, &mut ccc, None, None);
-                if let Err(err_count) = result {
-                    if err_count > 0 {
-                        std::process::exit(1);
-                    }
-                }
-            })
-            .expect("rustc_thread failed");
+/// Lint the selected targets of `package`, returning the exit code of the first target that failed.
+fn lint_package(package: &cargo_metadata::Package, targets: &TargetFilter, args: &[String]) -> Result<(), i32> {
+    let manifest_path_arg = format!("--manifest-path={}", package.manifest_path);
+    let mut result = Ok(());
+    for target in package.targets.iter().filter(|target| targets.matches(target)) {
+        let target_args = std::iter::once(manifest_path_arg.clone()).chain(args.iter().cloned());
+        let target_result = match target_kind(target) {
+            Some("lib") => process(std::iter::once("--lib".to_owned()).chain(target_args)),
+            Some(kind) => process(vec![format!("--{}", kind), target.name.clone()].into_iter().chain(target_args)),
+            None => Ok(()),
+        };
+        if result.is_ok() {
+            result = target_result;
+        }
     }
+    result
 }
 
 fn process<I>(old_args: I) -> Result<(), i32>
@@ -301,3 +1016,118 @@ fn process<I>(old_args: I) -> Result<(), i32>
         Err(exit_status.code().unwrap_or(-1))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use cargo_metadata::{Package, Target};
+    use serde_json;
+    use std::path::Path;
+    use super::{select_packages, TargetFilter};
+
+    fn package(name: &str) -> Package {
+        serde_json::from_str(&format!(r#"{{
+            "name": "{0}",
+            "version": "0.1.0",
+            "id": "{0} 0.1.0 (path+file:///ws/{0})",
+            "source": null,
+            "dependencies": [],
+            "targets": [],
+            "features": {{}},
+            "manifest_path": "/ws/{0}/Cargo.toml"
+        }}"#,
+                                      name))
+            .unwrap()
+    }
+
+    fn target(kind: &[&str], name: &str) -> Target {
+        Target {
+            name: name.to_owned(),
+            kind: kind.iter().map(|&kind| kind.to_owned()).collect(),
+            src_path: format!("src/{}.rs", name),
+        }
+    }
+
+    fn select(manifest_path: Option<&str>, all: bool, names: &[&str], excluded: &[&str])
+              -> Result<Vec<String>, String> {
+        let packages = vec![package("foo"), package("bar"), package("baz")];
+        let names: Vec<_> = names.iter().map(|&name| name.to_owned()).collect();
+        let excluded: Vec<_> = excluded.iter().map(|&name| name.to_owned()).collect();
+        select_packages(packages, manifest_path.map(Path::new), all, &names, &excluded)
+            .map(|packages| packages.into_iter().map(|package| package.name).collect())
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&arg| arg.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_select_packages() {
+        assert_eq!(Ok(vec!["foo".to_owned(), "bar".to_owned(), "baz".to_owned()]), select(None, true, &[], &[]));
+        assert_eq!(Ok(vec!["foo".to_owned(), "baz".to_owned()]), select(None, true, &[], &["bar"]));
+        assert_eq!(Ok(vec!["bar".to_owned()]), select(None, false, &["bar"], &[]));
+        assert_eq!(Ok(vec!["baz".to_owned()]), select(Some("/ws/baz/Cargo.toml"), false, &[], &[]));
+
+        assert_eq!(Err("package `qux` is not a member of the workspace".to_owned()),
+                   select(None, false, &["qux"], &[]));
+        assert_eq!(Err("excluded package `qux` is not a member of the workspace".to_owned()),
+                   select(None, true, &[], &["qux"]));
+        assert!(select(Some("/elsewhere/Cargo.toml"), false, &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_select_packages_conflicting_flags() {
+        assert_eq!(Err("`-p` cannot be used together with `--all`".to_owned()),
+                   select(None, true, &["bar"], &[]));
+        assert_eq!(Err("`--exclude` can only be used together with `--all`".to_owned()),
+                   select(None, false, &[], &["bar"]));
+        assert_eq!(Err("`--exclude` can only be used together with `--all`".to_owned()),
+                   select(None, false, &["foo"], &["bar"]));
+    }
+
+    #[test]
+    fn test_target_filter_from_args() {
+        let mut rest = args(&["--lib", "--bin", "foo", "--tests", "--example=bar", "--features", "baz"]);
+        let filter = TargetFilter::from_args(&mut rest).unwrap();
+        assert_eq!(vec!["lib", "test"], filter.kinds);
+        assert_eq!(vec![("bin", "foo".to_owned()), ("example", "bar".to_owned())], filter.named);
+        // the other flags are left for cargo
+        assert_eq!(args(&["--features", "baz"]), rest);
+
+        let mut rest = args(&["--all-targets"]);
+        let filter = TargetFilter::from_args(&mut rest).unwrap();
+        assert!(filter.kinds.is_empty() && filter.named.is_empty() && rest.is_empty());
+
+        assert!(TargetFilter::from_args(&mut args(&["--all-targets", "--bins"])).is_err());
+        assert!(TargetFilter::from_args(&mut args(&["--bench", "foo", "--all-targets"])).is_err());
+    }
+
+    #[test]
+    fn test_target_filter_matches() {
+        let lib = target(&["lib"], "foo");
+        let dylib = target(&["rlib", "dylib"], "foo");
+        let bin = target(&["bin"], "foo");
+        let other_bin = target(&["bin"], "bar");
+        let test = target(&["test"], "foo");
+        let build_script = target(&["custom-build"], "build-script-build");
+
+        // without any flag, every target but the build scripts is linted
+        let all = TargetFilter::from_args(&mut Vec::new()).unwrap();
+        for target in &[&lib, &dylib, &bin, &other_bin, &test] {
+            assert!(all.matches(target), "{:?}", target);
+        }
+        assert!(!all.matches(&build_script));
+
+        let filter = TargetFilter::from_args(&mut args(&["--lib", "--bin", "foo"])).unwrap();
+        assert!(filter.matches(&lib));
+        assert!(filter.matches(&dylib));
+        assert!(filter.matches(&bin));
+        assert!(!filter.matches(&other_bin));
+        assert!(!filter.matches(&test));
+        assert!(!filter.matches(&build_script));
+
+        // `--test foo` does not select the binary of the same name
+        let filter = TargetFilter::from_args(&mut args(&["--test", "foo"])).unwrap();
+        assert!(filter.matches(&test));
+        assert!(!filter.matches(&bin));
+    }
+}
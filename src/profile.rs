@@ -0,0 +1,94 @@
+//! Reports of `cargo clippy --profile-lints`, which times the lint passes of each crate.
+
+use clippy_lints::utils::profile::PassTime;
+use serde_json;
+use std::io::{self, Write};
+
+/// How to print the time of the lint passes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// A table on the standard error.
+    Human,
+    /// A JSON object per crate on the standard output.
+    Json,
+}
+
+impl Format {
+    pub fn from_name(format: &str) -> Option<Format> {
+        match format {
+            "human" => Some(Format::Human),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// The name of the crate being compiled, from the `--crate-name` argument `cargo` gives rustc.
+pub fn crate_name(args: &[String]) -> &str {
+    args.iter()
+        .position(|arg| arg == "--crate-name")
+        .and_then(|i| args.get(i + 1))
+        .map_or("<unknown>", AsRef::as_ref)
+}
+
+/// Print the time of each pass, slowest first.
+pub fn print(format: Format, krate: &str, passes: &[(&'static str, PassTime)]) {
+    match format {
+        Format::Human => print_human(krate, passes),
+        Format::Json => print_json(krate, passes),
+    }
+}
+
+/// Print the time of each pass as a table.
+#[allow(cast_precision_loss)]
+fn print_human(krate: &str, passes: &[(&'static str, PassTime)]) {
+    let stderr = io::stderr();
+    let mut handle = stderr.lock();
+    let total: u64 = passes.iter().map(|&(_, time)| time.nanos()).sum();
+    let _ = writeln!(handle, "time of the lint passes of `{}`: {:.3} ms", krate, total as f64 / 1e6);
+    let _ = writeln!(handle, "{:>12}  {:>6}  {:>15}  {:>10}  pass", "total (ms)", "%", "per call (µs)", "calls");
+    for &(name, time) in passes {
+        let _ = writeln!(handle,
+                         "{:>12.3}  {:>6.2}  {:>15.3}  {:>10}  {}",
+                         time.nanos() as f64 / 1e6,
+                         if total == 0 { 0. } else { time.nanos() as f64 * 100. / total as f64 },
+                         time.nanos_per_call() as f64 / 1e3,
+                         time.calls,
+                         name);
+    }
+}
+
+/// The time of the lint passes of a crate, as printed by `cargo clippy --profile-lints=json`.
+#[derive(Serialize)]
+struct Profile<'a> {
+    #[serde(rename = "crate")]
+    krate: &'a str,
+    passes: Vec<Pass>,
+}
+
+#[derive(Serialize)]
+struct Pass {
+    name: &'static str,
+    total_ns: u64,
+    per_call_ns: u64,
+    calls: u64,
+}
+
+/// Print the time of each pass as a JSON object on one line.
+#[allow(print_stdout)]
+fn print_json(krate: &str, passes: &[(&'static str, PassTime)]) {
+    let profile = Profile {
+        krate: krate,
+        passes: passes.iter()
+            .map(|&(name, time)| {
+                Pass {
+                    name: name,
+                    total_ns: time.nanos(),
+                    per_call_ns: time.nanos_per_call(),
+                    calls: time.calls,
+                }
+            })
+            .collect(),
+    };
+    println!("{}", serde_json::to_string(&profile).expect("profiles can always be serialized"));
+}
@@ -0,0 +1,841 @@
+//! `cargo clippy --message-format sarif`, which prints the warnings as a SARIF 2.1.0 log for code
+//! scanning tools.
+//!
+//! A SARIF log is a single document for the whole run, so like for `--summary`, each `cargo rustc`
+//! child process saves the results of its crate in a directory shared by the whole run, and
+//! `cargo clippy` prints the log once every package was linted. Every lint is described as a rule
+//! of the log, from the lint catalogue.
+
+use clippy_lints::utils::catalogue::{self, LintInfo};
+use clippy_lints::utils::sugg::Applicability;
+use diagnostics::{Diagnostic, DiagnosticSpan};
+use explain;
+use serde_json;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// The schema of the log, as registered on `schemastore.org`.
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The id of the base URI the paths of the log are relative to.
+const SRCROOT: &str = "%SRCROOT%";
+
+#[derive(Serialize)]
+struct Log {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool: Tool,
+    invocations: Vec<Invocation>,
+    original_uri_base_ids: BTreeMap<&'static str, ArtifactLocation>,
+    /// rustc counts columns in characters, not in UTF-16 code units as SARIF does by default.
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: ToolComponent,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent {
+    name: &'static str,
+    version: &'static str,
+    semantic_version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule>,
+}
+
+/// A lint, as a SARIF `reportingDescriptor`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: &'static str,
+    short_description: Message,
+    full_description: MultiformatMessage,
+    help_uri: String,
+    default_configuration: Configuration,
+    properties: RuleProperties,
+}
+
+#[derive(Serialize)]
+struct MultiformatMessage {
+    text: String,
+    markdown: &'static str,
+}
+
+#[derive(Serialize)]
+struct Configuration {
+    enabled: bool,
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct RuleProperties {
+    tags: Vec<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Invocation {
+    execution_successful: bool,
+}
+
+/// A warning of a Clippy lint.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: String,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<Location>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    /// The column following the region, like rustc's `column_end`.
+    end_column: usize,
+    byte_offset: usize,
+    byte_length: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+    properties: FixProperties,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct FixProperties {
+    /// One of `MachineApplicable`, `MaybeIncorrect`, `HasPlaceholders` or `Unspecified`.
+    applicability: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: ArtifactContent,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct ArtifactContent {
+    text: String,
+}
+
+impl Rule {
+    fn new(lint: &'static LintInfo) -> Self {
+        let (enabled, level) = match lint.level {
+            "deny" => (true, "error"),
+            "warn" => (true, "warning"),
+            "deprecated" => (false, "none"),
+            _ => (false, "warning"),
+        };
+        let mut tags = vec![lint.module];
+        if !lint.group.is_empty() {
+            tags.push(lint.group);
+        }
+        Rule {
+            id: lint.name,
+            short_description: Message { text: lint.desc.to_owned() },
+            full_description: MultiformatMessage {
+                text: explain::render(lint.docs),
+                markdown: lint.docs,
+            },
+            help_uri: format!("https://github.com/Manishearth/rust-clippy/wiki#{}", lint.name),
+            default_configuration: Configuration {
+                enabled: enabled,
+                level: level,
+            },
+            properties: RuleProperties { tags: tags },
+        }
+    }
+}
+
+impl SarifResult {
+    /// The result of a Clippy lint diagnostic, `None` for the other diagnostics.
+    fn new(diagnostic: &Diagnostic) -> Option<Self> {
+        let lint = if let Some(ref lint) = diagnostic.lint {
+            lint
+        } else {
+            return None;
+        };
+        let rule_index = if let Some(index) = catalogue::LINTS.iter().position(|info| info.name == *lint) {
+            index
+        } else {
+            return None;
+        };
+
+        let (suggestions, notes): (Vec<_>, Vec<_>) = diagnostic.children
+            .iter()
+            .partition(|child| child.is_suggestion());
+        let mut text = diagnostic.message.clone();
+        for note in notes.iter().filter(|note| note.spans.is_empty()) {
+            text.push_str(&format!("\n{}: {}", note.level, note.message));
+        }
+
+        Some(SarifResult {
+            rule_id: lint.clone(),
+            rule_index: rule_index,
+            level: match diagnostic.level.as_str() {
+                "error" => "error",
+                "warning" => "warning",
+                _ => "note",
+            }.to_owned(),
+            message: Message { text: text },
+            locations: diagnostic.primary_span().map(|span| Location::new(span, None)).into_iter().collect(),
+            related_locations: diagnostic.spans
+                .iter()
+                .filter(|span| !span.is_primary)
+                .chain(notes.iter().flat_map(|note| note.spans.iter()))
+                .map(|span| Location::new(span, span.label.clone()))
+                .collect(),
+            fixes: suggestions.into_iter().map(Fix::new).collect(),
+        })
+    }
+}
+
+impl Location {
+    fn new(span: &DiagnosticSpan, label: Option<String>) -> Self {
+        Location {
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation::new(&span.file_name),
+                region: Region::new(span),
+            },
+            message: label.map(|text| Message { text: text }),
+        }
+    }
+}
+
+impl ArtifactLocation {
+    /// The absolute location of `file_name`, which is relative to the directory of the child
+    /// process. It is made relative to `%SRCROOT%` when the log is printed.
+    fn new(file_name: &str) -> Self {
+        let path = Path::new(file_name);
+        let path = fs::canonicalize(path)
+            .or_else(|_| env::current_dir().map(|dir| dir.join(path)))
+            .unwrap_or_else(|_| path.to_path_buf());
+        ArtifactLocation {
+            uri: file_uri(&path),
+            uri_base_id: None,
+        }
+    }
+
+    /// Make the location relative to `root`, a directory URI ending with a slash, if it is in
+    /// that directory.
+    fn relativize(&mut self, root: &str) {
+        if self.uri.starts_with(root) {
+            self.uri = self.uri[root.len()..].to_owned();
+            self.uri_base_id = Some(SRCROOT.to_owned());
+        }
+    }
+}
+
+impl Region {
+    fn new(span: &DiagnosticSpan) -> Self {
+        Region {
+            start_line: span.line_start,
+            start_column: span.column_start,
+            end_line: span.line_end,
+            end_column: span.column_end,
+            byte_offset: span.byte_start,
+            byte_length: span.byte_end.saturating_sub(span.byte_start),
+        }
+    }
+}
+
+impl Fix {
+    fn new(suggestion: &Diagnostic) -> Self {
+        // the replacements of a file are grouped in a single change
+        let mut changes: Vec<ArtifactChange> = Vec::new();
+        for span in &suggestion.spans {
+            let replacement = if let Some(ref replacement) = span.suggested_replacement {
+                Replacement {
+                    deleted_region: Region::new(span),
+                    inserted_content: ArtifactContent { text: replacement.clone() },
+                }
+            } else {
+                continue;
+            };
+            let location = ArtifactLocation::new(&span.file_name);
+            if let Some(change) = changes.iter_mut().find(|change| change.artifact_location.uri == location.uri) {
+                change.replacements.push(replacement);
+                continue;
+            }
+            changes.push(ArtifactChange {
+                artifact_location: location,
+                replacements: vec![replacement],
+            });
+        }
+        Fix {
+            description: Message { text: suggestion.message.clone() },
+            artifact_changes: changes,
+            properties: FixProperties {
+                applicability: suggestion.applicability.unwrap_or(Applicability::Unspecified).as_str().to_owned(),
+            },
+        }
+    }
+}
+
+/// The `file://` URI of an absolute path.
+fn file_uri(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    // Windows paths start with the drive letter
+    let mut uri = String::from(if path.starts_with('/') { "file://" } else { "file:///" });
+    for byte in path.bytes() {
+        match byte {
+            b'A'...b'Z' | b'a'...b'z' | b'0'...b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => uri.push(byte as char),
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
+
+/// Save the results of the Clippy lints among `diagnostics` in `dir`, next to those of the other
+/// crates.
+pub fn save(dir: &Path, diagnostics: &[Diagnostic]) -> io::Result<()> {
+    let results: Vec<_> = diagnostics.iter().filter_map(SarifResult::new).collect();
+    let index = fs::read_dir(dir)?.count();
+    let json = serde_json::to_string(&results).expect("results can always be serialized");
+    File::create(dir.join(format!("sarif-{}.json", index)))?.write_all(json.as_bytes())
+}
+
+/// Read the results saved in `dir`.
+pub fn load_all(dir: &Path) -> Result<Vec<SarifResult>, String> {
+    let mut all = Vec::new();
+    let entries = fs::read_dir(dir).map_err(|err| format!("could not read `{}`: {}", dir.display(), err))?;
+    for entry in entries {
+        let path = entry.map_err(|err| format!("could not read `{}`: {}", dir.display(), err))?.path();
+        let mut content = String::new();
+        File::open(&path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .map_err(|err| format!("could not read `{}`: {}", path.display(), err))?;
+        let results: Vec<SarifResult> = serde_json::from_str(&content)
+            .map_err(|err| format!("could not parse `{}`: {}", path.display(), err))?;
+        all.extend(results);
+    }
+    Ok(all)
+}
+
+/// Print the SARIF log of the run on the standard output. The paths of the results are relative
+/// to `root` when they are in that directory.
+#[allow(print_stdout)]
+pub fn print(results: Vec<SarifResult>, root: &Path, successful: bool) {
+    let log = log(results, root, successful);
+    println!("{}", serde_json::to_string_pretty(&log).expect("logs can always be serialized"));
+}
+
+/// The SARIF log of a run, see `print`.
+fn log(mut results: Vec<SarifResult>, root: &Path, successful: bool) -> Log {
+    let mut root_uri = file_uri(root);
+    if !root_uri.ends_with('/') {
+        root_uri.push('/');
+    }
+    for result in &mut results {
+        for location in result.locations.iter_mut().chain(result.related_locations.iter_mut()) {
+            location.physical_location.artifact_location.relativize(&root_uri);
+        }
+        for change in result.fixes.iter_mut().flat_map(|fix| fix.artifact_changes.iter_mut()) {
+            change.artifact_location.relativize(&root_uri);
+        }
+    }
+
+    let mut base_ids = BTreeMap::new();
+    base_ids.insert(SRCROOT,
+                    ArtifactLocation {
+                        uri: root_uri,
+                        uri_base_id: None,
+                    });
+    Log {
+        schema: SCHEMA,
+        version: "2.1.0",
+        runs: vec![Run {
+                       tool: Tool {
+                           driver: ToolComponent {
+                               name: "clippy",
+                               version: env!("CARGO_PKG_VERSION"),
+                               semantic_version: env!("CARGO_PKG_VERSION"),
+                               information_uri: "https://github.com/Manishearth/rust-clippy",
+                               rules: catalogue::LINTS.iter().map(Rule::new).collect(),
+                           },
+                       },
+                       invocations: vec![Invocation { execution_successful: successful }],
+                       original_uri_base_ids: base_ids,
+                       column_kind: "unicodeCodePoints",
+                       results: results,
+                   }],
+    }
+}
+
+/// The directory `cargo clippy` was run from, which the paths of the log are relative to.
+pub fn root() -> PathBuf {
+    env::current_dir().ok().and_then(|dir| fs::canonicalize(dir).ok()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use clippy_lints::utils::sugg::Applicability;
+    use diagnostics::{Diagnostic, DiagnosticSpan};
+    use regex::Regex;
+    use serde_json::{self, Value};
+    use std::fs::File;
+    use std::io::Read;
+    use super::{log, SarifResult};
+
+    /// The official SARIF 2.1.0 schema, vendored unchanged by `util/fetch_sarif_schema.sh` to
+    /// validate the logs offline.
+    const SCHEMA_PATH: &str = "tests/auxiliary/sarif/sarif-schema-2.1.0.json";
+
+    fn span(file_name: &str, byte_start: usize, byte_end: usize, replacement: Option<&str>) -> DiagnosticSpan {
+        DiagnosticSpan {
+            file_name: file_name.to_owned(),
+            byte_start: byte_start,
+            byte_end: byte_end,
+            line_start: 3,
+            line_end: 3,
+            column_start: byte_start + 1,
+            column_end: byte_end + 1,
+            is_primary: true,
+            text: Vec::new(),
+            label: None,
+            suggested_replacement: replacement.map(str::to_owned),
+        }
+    }
+
+    fn diagnostic(message: &str, level: &str, spans: Vec<DiagnosticSpan>, children: Vec<Diagnostic>) -> Diagnostic {
+        Diagnostic {
+            message: message.to_owned(),
+            code: None,
+            level: level.to_owned(),
+            spans: spans,
+            children: children,
+            rendered: None,
+            lint: None,
+            group: None,
+            applicability: None,
+        }
+    }
+
+    /// Some diagnostics of Clippy lints: one with a machine-applicable fix in two files, one with a
+    /// note and a secondary span, and a rustc diagnostic that is not part of the log.
+    fn diagnostics() -> Vec<Diagnostic> {
+        let mut suggestion = diagnostic("replace it with",
+                                        "help",
+                                        vec![span("src/main.rs", 4, 13, Some("x += 1")),
+                                             span("src/lib.rs", 0, 0, Some("// fixed\n"))],
+                                        Vec::new());
+        suggestion.applicability = Some(Applicability::MachineApplicable);
+        let mut assign_op = diagnostic("manual implementation of an assign operation",
+                                       "warning",
+                                       vec![span("src/main.rs", 4, 13, None)],
+                                       vec![suggestion]);
+        assign_op.lint = Some("assign_op_pattern".to_owned());
+
+        let mut secondary = span("src/main.rs", 20, 24, None);
+        secondary.is_primary = false;
+        secondary.label = Some("the loop".to_owned());
+        let note = diagnostic("lint level defined here", "note", Vec::new(), Vec::new());
+        let mut needless_range_loop = diagnostic("the loop variable `i` is only used to index `v`.",
+                                                 "error",
+                                                 vec![span("src/main.rs", 20, 40, None), secondary],
+                                                 vec![note]);
+        needless_range_loop.lint = Some("needless_range_loop".to_owned());
+
+        let unused = diagnostic("unused variable: `x`", "warning", vec![span("src/main.rs", 8, 9, None)], Vec::new());
+
+        vec![assign_op, needless_range_loop, unused]
+    }
+
+    #[test]
+    fn test_log_matches_schema() {
+        let mut source = String::new();
+        if let Err(err) = File::open(SCHEMA_PATH).and_then(|mut file| file.read_to_string(&mut source)) {
+            panic!("could not read `{}`, run `util/fetch_sarif_schema.sh` to vendor it: {}", SCHEMA_PATH, err);
+        }
+        let schema: Value = serde_json::from_str(&source).unwrap();
+
+        let results: Vec<_> = diagnostics().iter().filter_map(SarifResult::new).collect();
+        assert_eq!(2, results.len());
+        assert_eq!(1, results[0].fixes.len());
+        assert_eq!(2, results[0].fixes[0].artifact_changes.len());
+
+        let root = super::root();
+        let log = serde_json::to_value(&log(results, &root, false)).unwrap();
+        let mut errors = Vec::new();
+        validate(&schema, &schema, &log, "", &mut errors);
+        assert!(errors.is_empty(), "the log does not match the SARIF schema:\n{}", errors.join("\n"));
+
+        // the paths are relative to the root of the run
+        let uri = &log["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(Some("src/main.rs"), uri["uri"].as_str());
+        assert_eq!(Some("%SRCROOT%"), uri["uriBaseId"].as_str());
+        let fix = &log["runs"][0]["results"][0]["fixes"][0];
+        assert_eq!(Some("MachineApplicable"), fix["properties"]["applicability"].as_str());
+    }
+
+    #[test]
+    fn test_validate() {
+        // make sure the validation can fail
+        let schema: Value = serde_json::from_str(r#"{
+            "type": "object",
+            "additionalProperties": false,
+            "required": ["a"],
+            "properties": {
+                "a": { "$ref": "#/definitions/positive" },
+                "b": { "type": "array", "minItems": 1, "uniqueItems": true, "items": { "enum": ["x", "y"] } },
+                "c": { "type": "string", "pattern": "^[a-z]{2}(-[A-Z]{2})?$", "format": "not checked" },
+                "d": { "oneOf": [{ "type": "integer" }, { "minimum": 0 }] },
+                "e": { "not": { "const": 0 } },
+                "f": { "additionalProperties": { "type": "boolean" }, "propertyNames": { "maxLength": 2 } }
+            },
+            "dependencies": { "c": ["b"] },
+            "definitions": { "positive": { "type": "integer", "exclusiveMinimum": 0 } }
+        }"#)
+            .unwrap();
+        let check = |json: &str| {
+            let mut errors = Vec::new();
+            validate(&schema, &schema, &serde_json::from_str(json).unwrap(), "", &mut errors);
+            errors.len()
+        };
+        assert_eq!(0, check(r#"{ "a": 1, "b": ["x", "y"], "c": "en-US", "d": 1.5, "e": 1, "f": { "x": true } }"#));
+        assert_eq!(0, check(r#"{ "a": 1.0 }"#));
+        assert_eq!(1, check(r#"{ "b": ["x"] }"#));
+        assert_eq!(1, check(r#"{ "a": 0 }"#));
+        assert_eq!(1, check(r#"{ "a": 1.5 }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "g": 1 }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "b": [] }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "b": ["x", "x"] }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "b": ["z"] }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "b": ["x"], "c": "english" }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "c": "en" }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "d": 1 }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "d": -1.5 }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "e": 0 }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "f": { "x": 1 } }"#));
+        assert_eq!(1, check(r#"{ "a": 1, "f": { "xyz": true } }"#));
+    }
+
+    /// Whether `value` is of the JSON schema `type`.
+    fn has_type(value: &Value, ty: &str) -> bool {
+        match ty {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            "integer" => value.as_f64().map_or(false, |number| number.fract() == 0.0),
+            "number" => value.is_number(),
+            _ => panic!("unknown type `{}`", ty),
+        }
+    }
+
+    /// Whether `value` matches `schema`, a part of the JSON schema `root`.
+    fn is_valid(root: &Value, schema: &Value, value: &Value) -> bool {
+        let mut errors = Vec::new();
+        validate(root, schema, value, "", &mut errors);
+        errors.is_empty()
+    }
+
+    /// Check `value`, at the JSON pointer `path`, against `schema`, a part of the draft-07 JSON
+    /// schema `root`, and collect the errors.
+    ///
+    /// Every validation keyword of draft-07 is supported, and an unknown keyword is an error rather
+    /// than ignored. `format` is only an annotation, as draft-07 allows, and `$ref` can only point
+    /// into `root`.
+    #[allow(cyclomatic_complexity)]
+    fn validate(root: &Value, schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+        let schema = match *schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                errors.push(format!("{}: no value is allowed", path));
+                return;
+            },
+            Value::Object(ref schema) => schema,
+            _ => panic!("a schema must be an object or a boolean"),
+        };
+
+        // the other keywords next to a `$ref` are ignored
+        if let Some(reference) = schema.get("$ref") {
+            let reference = reference.as_str().expect("`$ref` must be a string");
+            assert!(reference.starts_with('#'), "only local references are supported: `{}`", reference);
+            let target = root.pointer(&reference[1..]);
+            let target = target.unwrap_or_else(|| panic!("unknown reference `{}`", reference));
+            validate(root, target, value, path, errors);
+            return;
+        }
+
+        for (keyword, argument) in schema {
+            let error = match keyword.as_str() {
+                // annotations, and the keywords checked with `if`, `items` or the properties below
+                "$schema" | "$id" | "$comment" | "title" | "description" | "default" | "examples" |
+                "definitions" | "format" | "readOnly" | "writeOnly" | "contentMediaType" |
+                "contentEncoding" | "then" | "else" | "additionalItems" | "items" | "properties" |
+                "patternProperties" | "additionalProperties" => None,
+                "type" => {
+                    let matches = match *argument {
+                        Value::String(ref ty) => has_type(value, ty),
+                        Value::Array(ref types) => types.iter().any(|ty| has_type(value, ty.as_str().unwrap_or(""))),
+                        _ => panic!("`type` must be a string or an array"),
+                    };
+                    if matches { None } else { Some(format!("expected a value of type {}", argument)) }
+                },
+                "enum" => {
+                    let values = argument.as_array().expect("`enum` must be an array");
+                    if values.contains(value) { None } else { Some(format!("expected one of {}", argument)) }
+                },
+                "const" => if value == argument { None } else { Some(format!("expected {}", argument)) },
+                "multipleOf" | "maximum" | "exclusiveMaximum" | "minimum" | "exclusiveMinimum" => {
+                    let limit = argument.as_f64().expect("numeric keywords must have a number");
+                    value.as_f64().and_then(|number| {
+                        let matches = match keyword.as_str() {
+                            "multipleOf" => (number / limit).fract() == 0.0,
+                            "maximum" => number <= limit,
+                            "exclusiveMaximum" => number < limit,
+                            "minimum" => number >= limit,
+                            _ => number > limit,
+                        };
+                        if matches { None } else { Some(format!("expected a number with `{}` {}", keyword, limit)) }
+                    })
+                },
+                "maxLength" | "minLength" => {
+                    let limit = argument.as_u64().expect("length keywords must have an integer");
+                    value.as_str().and_then(|string| {
+                        let length = string.chars().count() as u64;
+                        if (keyword == "maxLength" && length > limit) || (keyword == "minLength" && length < limit) {
+                            Some(format!("expected a string with `{}` {}", keyword, limit))
+                        } else {
+                            None
+                        }
+                    })
+                },
+                "pattern" => {
+                    let pattern = Regex::new(argument.as_str().expect("`pattern` must be a string")).unwrap();
+                    value.as_str().and_then(|string| if pattern.is_match(string) {
+                        None
+                    } else {
+                        Some(format!("expected a string matching {}", argument))
+                    })
+                },
+                "maxItems" | "minItems" => {
+                    let limit = argument.as_u64().expect("item count keywords must have an integer");
+                    value.as_array().and_then(|items| {
+                        let length = items.len() as u64;
+                        if (keyword == "maxItems" && length > limit) || (keyword == "minItems" && length < limit) {
+                            Some(format!("expected an array with `{}` {}", keyword, limit))
+                        } else {
+                            None
+                        }
+                    })
+                },
+                "uniqueItems" => {
+                    value.as_array().and_then(|items| {
+                        let unique = items.iter().enumerate().all(|(i, item)| !items[..i].contains(item));
+                        if unique || argument != &Value::Bool(true) {
+                            None
+                        } else {
+                            Some("expected unique items".to_owned())
+                        }
+                    })
+                },
+                "contains" => {
+                    value.as_array().and_then(|items| if items.iter().any(|item| is_valid(root, argument, item)) {
+                        None
+                    } else {
+                        Some(format!("expected an item matching {}", argument))
+                    })
+                },
+                "maxProperties" | "minProperties" => {
+                    let limit = argument.as_u64().expect("property count keywords must have an integer");
+                    value.as_object().and_then(|object| {
+                        let length = object.len() as u64;
+                        if (keyword == "maxProperties" && length > limit) ||
+                           (keyword == "minProperties" && length < limit) {
+                            Some(format!("expected an object with `{}` {}", keyword, limit))
+                        } else {
+                            None
+                        }
+                    })
+                },
+                "required" => {
+                    let keys = argument.as_array().expect("`required` must be an array");
+                    value.as_object().and_then(|object| {
+                        let missing: Vec<_> = keys.iter()
+                            .filter_map(Value::as_str)
+                            .filter(|key| !object.contains_key(*key))
+                            .collect();
+                        if missing.is_empty() {
+                            None
+                        } else {
+                            Some(format!("missing required properties {:?}", missing))
+                        }
+                    })
+                },
+                "dependencies" => {
+                    let dependencies = argument.as_object().expect("`dependencies` must be an object");
+                    value.as_object().and_then(|object| {
+                        let broken: Vec<_> = dependencies.iter()
+                            .filter(|&(key, dependency)| {
+                                object.contains_key(key) &&
+                                match dependency.as_array() {
+                                    Some(keys) => {
+                                        !keys.iter().filter_map(Value::as_str).all(|key| object.contains_key(key))
+                                    },
+                                    None => !is_valid(root, dependency, value),
+                                }
+                            })
+                            .map(|(key, _)| key)
+                            .collect();
+                        if broken.is_empty() {
+                            None
+                        } else {
+                            Some(format!("unmet dependencies of {:?}", broken))
+                        }
+                    })
+                },
+                "propertyNames" => {
+                    value.as_object().and_then(|object| {
+                        let invalid: Vec<_> = object.keys()
+                            .filter(|key| !is_valid(root, argument, &Value::String((*key).clone())))
+                            .collect();
+                        if invalid.is_empty() {
+                            None
+                        } else {
+                            Some(format!("invalid property names {:?}", invalid))
+                        }
+                    })
+                },
+                "if" => {
+                    let branch = if is_valid(root, argument, value) { "then" } else { "else" };
+                    if let Some(branch_schema) = schema.get(branch) {
+                        validate(root, branch_schema, value, path, errors);
+                    }
+                    None
+                },
+                "allOf" | "anyOf" | "oneOf" => {
+                    let schemas = argument.as_array().expect("`allOf`, `anyOf` and `oneOf` must be arrays");
+                    let valid = schemas.iter().filter(|schema| is_valid(root, schema, value)).count();
+                    let matches = match keyword.as_str() {
+                        "allOf" => valid == schemas.len(),
+                        "anyOf" => valid > 0,
+                        _ => valid == 1,
+                    };
+                    if matches { None } else { Some(format!("expected a value matching `{}` {}", keyword, argument)) }
+                },
+                "not" => {
+                    if is_valid(root, argument, value) {
+                        Some(format!("expected a value not matching {}", argument))
+                    } else {
+                        None
+                    }
+                },
+                _ => panic!("unsupported keyword `{}`", keyword),
+            };
+            if let Some(error) = error {
+                errors.push(format!("{}: {}", path, error));
+            }
+        }
+
+        if let Some(items) = value.as_array() {
+            match schema.get("items") {
+                Some(&Value::Array(ref item_schemas)) => {
+                    for (i, item) in items.iter().enumerate() {
+                        let item_schema = item_schemas.get(i).or_else(|| schema.get("additionalItems"));
+                        if let Some(item_schema) = item_schema {
+                            validate(root, item_schema, item, &format!("{}/{}", path, i), errors);
+                        }
+                    }
+                },
+                Some(item_schema) => {
+                    for (i, item) in items.iter().enumerate() {
+                        validate(root, item_schema, item, &format!("{}/{}", path, i), errors);
+                    }
+                },
+                None => (),
+            }
+        }
+
+        if let Some(object) = value.as_object() {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            let patterns: Vec<_> = schema.get("patternProperties")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flat_map(|patterns| patterns)
+                .map(|(pattern, property_schema)| (Regex::new(pattern).unwrap(), property_schema))
+                .collect();
+            for (key, property) in object {
+                let property_path = format!("{}/{}", path, key);
+                let mut matched = false;
+                if let Some(property_schema) = properties.and_then(|properties| properties.get(key)) {
+                    validate(root, property_schema, property, &property_path, errors);
+                    matched = true;
+                }
+                for &(ref pattern, property_schema) in &patterns {
+                    if pattern.is_match(key) {
+                        validate(root, property_schema, property, &property_path, errors);
+                        matched = true;
+                    }
+                }
+                if let (false, Some(additional)) = (matched, schema.get("additionalProperties")) {
+                    validate(root, additional, property, &property_path, errors);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! `cargo clippy --summary`, which counts the warnings per lint, lint group and file. The warnings
+//! are also collected to check them against `--fail-on`.
+//!
+//! Like for baselines, each `cargo rustc` child process saves the warnings it reported in a
+//! directory shared by the whole run, and `cargo clippy` counts them once every package was
+//! linted.
+
+use diagnostics::Diagnostic;
+use serde_json;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// A warning reported by Clippy.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Warning {
+    pub lint: String,
+    pub group: Option<String>,
+    /// The level the warning was emitted at, like `warning` or `error`.
+    pub level: String,
+    pub file: Option<String>,
+}
+
+/// Save the Clippy warnings among `diagnostics` in `dir`, next to those of the other crates.
+pub fn save(dir: &Path, diagnostics: &[Diagnostic]) -> io::Result<()> {
+    let warnings: Vec<_> = diagnostics.iter()
+        .filter_map(|diagnostic| {
+            diagnostic.lint.as_ref().map(|lint| {
+                Warning {
+                    lint: lint.clone(),
+                    group: diagnostic.group.clone(),
+                    level: diagnostic.level.clone(),
+                    file: diagnostic.primary_span().map(|span| span.file_name.clone()),
+                }
+            })
+        })
+        .collect();
+    let index = fs::read_dir(dir)?.count();
+    let json = serde_json::to_string(&warnings).expect("warnings can always be serialized");
+    File::create(dir.join(format!("summary-{}.json", index)))?.write_all(json.as_bytes())
+}
+
+/// Read the warnings saved in `dir`.
+pub fn load_all(dir: &Path) -> Result<Vec<Warning>, String> {
+    let mut all = Vec::new();
+    let entries = fs::read_dir(dir).map_err(|err| format!("could not read `{}`: {}", dir.display(), err))?;
+    for entry in entries {
+        let path = entry.map_err(|err| format!("could not read `{}`: {}", dir.display(), err))?.path();
+        let mut content = String::new();
+        File::open(&path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .map_err(|err| format!("could not read `{}`: {}", path.display(), err))?;
+        let warnings: Vec<Warning> = serde_json::from_str(&content)
+            .map_err(|err| format!("could not parse `{}`: {}", path.display(), err))?;
+        all.extend(warnings);
+    }
+    Ok(all)
+}
+
+/// Count the occurrences of each key, most frequent first.
+pub fn count<'a, I: IntoIterator<Item = &'a str>>(keys: I) -> Vec<(&'a str, usize)> {
+    let mut counts = BTreeMap::new();
+    for key in keys {
+        *counts.entry(key).or_insert(0) += 1;
+    }
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    // `sort_by` is stable, so equal counts stay sorted by key
+    counts.sort_by(|&(_, a), &(_, b)| b.cmp(&a));
+    counts
+}
+
+/// Print the number of warnings per lint group, lint and file on the standard error. With `top`,
+/// only the lints and files with the most warnings are listed.
+pub fn print(warnings: &[Warning], top: Option<usize>) {
+    let stderr = io::stderr();
+    summarize(&mut stderr.lock(), warnings, top);
+}
+
+/// Write the summary printed by `print` to `out`.
+fn summarize<W: Write>(out: &mut W, warnings: &[Warning], top: Option<usize>) {
+    let _ = writeln!(out, "Clippy found {} warnings", warnings.len());
+    if warnings.is_empty() {
+        return;
+    }
+
+    let groups = count(warnings.iter().map(|warning| warning.group.as_ref().map_or("-", AsRef::as_ref)));
+    let lints = count(warnings.iter().map(|warning| warning.lint.as_str()));
+    let files = count(warnings.iter().map(|warning| warning.file.as_ref().map_or("-", AsRef::as_ref)));
+    let limit = top.unwrap_or_else(|| warnings.len());
+    print_counts(out, "lint group", &groups, groups.len());
+    print_counts(out, "lint", &lints, limit);
+    print_counts(out, "file", &files, limit);
+}
+
+/// Print the first `limit` counts of a section of the summary.
+fn print_counts<W: Write>(out: &mut W, title: &str, counts: &[(&str, usize)], limit: usize) {
+    let _ = writeln!(out, "\nby {}:", title);
+    for &(key, count) in counts.iter().take(limit) {
+        let _ = writeln!(out, "{:>8}  {}", count, key);
+    }
+    if counts.len() > limit {
+        let _ = writeln!(out, "{:>8}  ({} more)", "...", counts.len() - limit);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{count, summarize, Warning};
+
+    fn warning(lint: &str, group: Option<&str>, file: Option<&str>) -> Warning {
+        Warning {
+            lint: lint.to_owned(),
+            group: group.map(str::to_owned),
+            level: "warning".to_owned(),
+            file: file.map(str::to_owned),
+        }
+    }
+
+    fn warnings() -> Vec<Warning> {
+        vec![warning("needless_return", Some("clippy"), Some("src/lib.rs")),
+             warning("shadow_unrelated", Some("clippy_pedantic"), Some("src/main.rs")),
+             warning("needless_return", Some("clippy"), Some("src/main.rs")),
+             warning("box_vec", Some("clippy"), Some("src/main.rs")),
+             warning("needless_return", Some("clippy"), None),
+             warning("shadow_unrelated", None, Some("src/lib.rs"))]
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(Vec::<(&str, usize)>::new(), count(Vec::new()));
+        // most frequent first, then by key
+        assert_eq!(vec![("b", 3), ("a", 2), ("c", 2), ("d", 1)],
+                   count(vec!["c", "b", "a", "d", "b", "c", "a", "b"]));
+    }
+
+    #[test]
+    fn test_summarize() {
+        let mut out = Vec::new();
+        summarize(&mut out, &warnings(), None);
+        assert_eq!("\
+Clippy found 6 warnings
+
+by lint group:
+       4  clippy
+       1  -
+       1  clippy_pedantic
+
+by lint:
+       3  needless_return
+       2  shadow_unrelated
+       1  box_vec
+
+by file:
+       3  src/main.rs
+       2  src/lib.rs
+       1  -
+",
+                   String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn test_summarize_top() {
+        // the lint groups are never truncated
+        let mut out = Vec::new();
+        summarize(&mut out, &warnings(), Some(1));
+        assert_eq!("\
+Clippy found 6 warnings
+
+by lint group:
+       4  clippy
+       1  -
+       1  clippy_pedantic
+
+by lint:
+       3  needless_return
+     ...  (2 more)
+
+by file:
+       3  src/main.rs
+     ...  (2 more)
+",
+                   String::from_utf8(out).unwrap());
+
+        let mut out = Vec::new();
+        summarize(&mut out, &[], Some(1));
+        assert_eq!("Clippy found 0 warnings\n", String::from_utf8(out).unwrap());
+    }
+}
@@ -0,0 +1,28 @@
+extern crate clippy_lints;
+
+use clippy_lints::utils::sugg::Applicability;
+
+#[test]
+fn test_downgrade() {
+    let mut applicability = Applicability::MachineApplicable;
+    applicability.downgrade(Applicability::MaybeIncorrect);
+    assert_eq!(Applicability::MaybeIncorrect, applicability);
+    applicability.downgrade(Applicability::MachineApplicable);
+    assert_eq!(Applicability::MaybeIncorrect, applicability);
+    applicability.downgrade(Applicability::HasPlaceholders);
+    assert_eq!(Applicability::HasPlaceholders, applicability);
+    applicability.downgrade(Applicability::MaybeIncorrect);
+    assert_eq!(Applicability::HasPlaceholders, applicability);
+
+    let mut applicability = Applicability::Unspecified;
+    applicability.downgrade(Applicability::HasPlaceholders);
+    assert_eq!(Applicability::Unspecified, applicability);
+}
+
+#[test]
+fn test_as_str() {
+    assert_eq!("MachineApplicable", Applicability::MachineApplicable.as_str());
+    assert_eq!("MaybeIncorrect", Applicability::MaybeIncorrect.as_str());
+    assert_eq!("HasPlaceholders", Applicability::HasPlaceholders.as_str());
+    assert_eq!("Unspecified", Applicability::Unspecified.as_str());
+}
@@ -0,0 +1,12 @@
+fn needless_return() -> bool {
+    return true;
+}
+
+fn truncation(x: u64) -> u8 {
+    x as u8
+}
+
+fn main() {
+    needless_return();
+    truncation(1);
+}
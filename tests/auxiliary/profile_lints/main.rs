@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+pub struct Foo;
+
+impl Foo {
+    pub fn new() -> Foo {
+        Foo
+    }
+}
+
+fn needless_return(x: u8) -> u8 {
+    return x;
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    for i in 0..v.len() {
+        println!("{}", v[i]);
+    }
+
+    match v.first() {
+        Some(x) => println!("{}", x),
+        _ => (),
+    }
+
+    let mut map = HashMap::new();
+    if !map.contains_key(&1) {
+        map.insert(1, 2);
+    }
+
+    let x = 1;
+    let _ = x == x;
+    let _ = (x as u64) as u8;
+}
@@ -0,0 +1,48 @@
+extern crate clippy_lints;
+
+use clippy_lints::utils::catalogue::{self, LINTS};
+
+#[test]
+fn test_sorted() {
+    assert!(LINTS.windows(2).all(|pair| pair[0].name < pair[1].name));
+}
+
+#[test]
+fn test_find() {
+    let lint = catalogue::find("needless_range_loop").expect("`needless_range_loop` is documented");
+    assert_eq!("loops", lint.module);
+    assert_eq!("warn", lint.level);
+    assert_eq!("clippy", lint.group);
+    assert!(lint.docs.starts_with("**What it does:**"));
+
+    assert_eq!(Some("needless_range_loop"),
+               catalogue::find("Needless-Range-Loop").map(|lint| lint.name));
+    assert!(catalogue::find("needless_range_loops").is_none());
+}
+
+#[test]
+fn test_groups() {
+    assert_eq!("clippy_pedantic", catalogue::find("shadow_unrelated").unwrap().group);
+    assert_eq!("clippy_restrictions", catalogue::find("integer_arithmetic").unwrap().group);
+
+    let deprecated = catalogue::find("unstable_as_slice").unwrap();
+    assert_eq!("deprecated", deprecated.level);
+    assert_eq!("", deprecated.group);
+}
+
+#[test]
+fn test_lint_groups() {
+    for group in catalogue::GROUPS {
+        assert!(!group.lints.is_empty(), "`{}` is empty", group.name);
+        for name in group.lints {
+            assert!(catalogue::find(name).is_some(), "`{}` of `{}` is not a lint", name, group.name);
+        }
+    }
+
+    let clippy = catalogue::find_group("clippy").unwrap();
+    assert_eq!(LINTS.iter().filter(|lint| lint.group == "clippy").count(), clippy.lints.len());
+
+    let no_panic = catalogue::find_group("clippy-no-panic").expect("`clippy_no_panic` is a preset");
+    assert!(no_panic.lints.contains(&"option_unwrap_used"));
+    assert!(catalogue::find_group("clippy_no_panics").is_none());
+}
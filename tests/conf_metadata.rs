@@ -0,0 +1,42 @@
+extern crate clippy_lints;
+
+use clippy_lints::utils::conf::{self, Error};
+use std::path::Path;
+
+#[test]
+fn test_metadata() {
+    let dir = Path::new("tests/auxiliary/conf_metadata");
+    let manifest = dir.join("member/Cargo.toml");
+    let file = dir.join("member/clippy.toml");
+    let (conf, errors) = conf::read(Some(&manifest), Some(&file));
+
+    // the package extends the workspace's list
+    assert_eq!(vec!["toto", "tata", "titi"], conf.blacklisted_names);
+    // only set by the workspace
+    assert_eq!(10, conf.cyclomatic_complexity_threshold);
+    // the configuration file overrides the package, which overrides the workspace
+    assert_eq!(4, conf.too_many_arguments_threshold);
+    assert_eq!(vec![dir.join("Cargo.toml"), manifest.clone(), file], conf.files);
+    assert_eq!(1, conf.lints.len());
+    assert_eq!("needless_range_loop", conf.lints[0].0);
+
+    assert_eq!(1, errors.len());
+    match errors[0].error {
+        Error::Type("single-char-binding-names-threshold", "u64", "string") => (),
+        ref error => panic!("unexpected error: {}", error),
+    }
+    assert_eq!(format!("{}:9:1", manifest.display()), errors[0].location());
+
+    // the level is set in the manifest, not in the configuration file read after it
+    let error = conf::unknown_lint(&conf.files, "needless_range_loop");
+    assert_eq!(format!("{}:12:1", manifest.display()), error.location());
+}
+
+#[test]
+fn test_no_metadata() {
+    let manifest = Path::new("Cargo.toml");
+    let (conf, errors) = conf::read(Some(manifest), None);
+    assert!(errors.is_empty());
+    assert!(conf.files.is_empty());
+    assert_eq!(7, conf.too_many_arguments_threshold);
+}
@@ -0,0 +1,22 @@
+use std::env::consts::EXE_SUFFIX;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_lints_table() {
+    // the driver finds `clippy.toml` from the current directory
+    let driver = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("target/debug/cargo-clippy{}", EXE_SUFFIX));
+    let output = Command::new(driver)
+        .args(&["--file", "main.rs"])
+        .current_dir("tests/auxiliary/lint_levels")
+        .env_remove("CARGO_MANIFEST_DIR")
+        .env_remove("CLIPPY_MESSAGE_FORMAT")
+        .output()
+        .expect("could not run cargo-clippy");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("panicked"), "unexpected output: {}", stderr);
+    assert!(stderr.contains("error: unneeded return statement"), "unexpected output: {}", stderr);
+    assert!(stderr.contains("warning: casting u64 to u8 may truncate the value"), "unexpected output: {}", stderr);
+    assert!(!output.status.success());
+}
@@ -0,0 +1,30 @@
+use std::env::consts::EXE_SUFFIX;
+use std::process::{Command, Output};
+
+/// Lint `tests/auxiliary/profile_lints/main.rs` with the driver, profiling the lint passes if
+/// `profile` is given.
+fn lint(profile: Option<&str>) -> Output {
+    let mut command = Command::new(format!("target/debug/cargo-clippy{}", EXE_SUFFIX));
+    command.args(&["--file", "tests/auxiliary/profile_lints/main.rs", "-W", "clippy_pedantic"]);
+    if let Some(format) = profile {
+        command.env("CLIPPY_PROFILE_LINTS", format);
+    }
+    command.env_remove("CLIPPY_MESSAGE_FORMAT").output().expect("could not run cargo-clippy")
+}
+
+#[test]
+fn test_same_diagnostics() {
+    // `Timed` forwards every method of the passes it wraps by hand, a missing one would lose lints
+    let normal = lint(None);
+    let profiled = lint(Some("json"));
+
+    let stderr = String::from_utf8_lossy(&normal.stderr);
+    assert!(stderr.contains("warning: unneeded return statement"), "unexpected output: {}", stderr);
+    assert_eq!(stderr, String::from_utf8_lossy(&profiled.stderr));
+    assert_eq!(normal.status.code(), profiled.status.code());
+
+    assert!(normal.stdout.is_empty());
+    let profile = String::from_utf8_lossy(&profiled.stdout);
+    assert!(profile.contains(r#""name":"returns::ReturnPass""#), "unexpected profile: {}", profile);
+    assert!(profile.contains(r#""name":"loops::Pass""#), "unexpected profile: {}", profile);
+}
@@ -0,0 +1,40 @@
+extern crate clippy_lints;
+
+use clippy_lints::utils::suppression::parse;
+
+fn lints(names: &[&str]) -> Vec<String> {
+    names.iter().map(|&name| name.to_owned()).collect()
+}
+
+#[test]
+fn not_a_suppression() {
+    assert_eq!(None, parse(" TODO: allow(needless_range_loop)"));
+    assert_eq!(None, parse(" the clippy: prefix must come first"));
+}
+
+#[test]
+fn without_reason() {
+    assert_eq!(Some(Ok((lints(&["needless_range_loop"]), None))),
+               parse(" clippy: allow(needless_range_loop)"));
+    assert_eq!(Some(Ok((lints(&["cast_possible_truncation", "clippy_pedantic"]), None))),
+               parse("clippy:allow( cast_possible_truncation,clippy_pedantic )  "));
+}
+
+#[test]
+fn with_reason() {
+    assert_eq!(Some(Ok((lints(&["indexing_slicing"]), Some("`i` is checked above".to_owned())))),
+               parse(" clippy: allow(indexing_slicing) reason=\"`i` is checked above\""));
+    assert_eq!(Some(Ok((lints(&["indexing_slicing"]), Some(String::new())))),
+               parse(" clippy: allow(indexing_slicing) reason = \"\""));
+}
+
+#[test]
+fn malformed() {
+    assert_eq!(Some(Err(())), parse(" clippy: deny(needless_range_loop)"));
+    assert_eq!(Some(Err(())), parse(" clippy: allow(needless_range_loop"));
+    assert_eq!(Some(Err(())), parse(" clippy: allow()"));
+    assert_eq!(Some(Err(())), parse(" clippy: allow(a,,b)"));
+    assert_eq!(Some(Err(())), parse(" clippy: allow(needless_range_loop) because"));
+    assert_eq!(Some(Err(())), parse(" clippy: allow(needless_range_loop) reason=unquoted"));
+    assert_eq!(Some(Err(())), parse(" clippy: allow(needless_range_loop) reason=\"a\" \"b\""));
+}
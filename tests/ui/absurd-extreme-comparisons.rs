@@ -91,3 +91,10 @@ impl PartialOrd<u32> for U {
 pub fn foo(val: U) -> bool {
     val > std::u32::MAX
 }
+
+#[allow(bad_bit_mask)]
+pub fn masked(x: u32) {
+    (x & 0xff) <= 255;
+    (x & 0xff) > 255;
+    (x & 0xff) < 255; // ok
+}
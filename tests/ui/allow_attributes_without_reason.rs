@@ -0,0 +1,30 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(allow_attributes_without_reason)]
+#![allow(dead_code)]
+
+#[allow(needless_range_loop)]
+fn no_reason() {}
+
+// the tests have no reason to give
+#[allow(needless_range_loop)]
+fn comment_above() {}
+
+#[allow(clippy_pedantic)] // generated code
+fn trailing_comment() {}
+
+// several lines
+// of justification
+#[inline]
+#[allow(needless_range_loop, dead_code)]
+fn several_lines() {}
+
+/// Doc comments do not count.
+#[allow(needless_range_loop)]
+fn doc_comment() {}
+
+#[allow(unused_variables)]
+fn not_clippy() {}
+
+fn main() {}
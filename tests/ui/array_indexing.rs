@@ -42,4 +42,20 @@ fn main() {
     &empty[..0];
     &empty[1..];
     &empty[..4];
+
+    let i = 4;
+    x[i];
+    x[[1, 2, 3].len() + 1];
+    ranges(0);
+}
+
+fn ranges(i: usize) {
+    let x = [1, 2, 3, 4];
+    x[i % 4];
+    x[i & 3];
+    if i < 4 {
+        x[i];
+    }
+    x[i % 4 + 4];
+    x[i % 5];
 }
@@ -59,3 +59,12 @@ fn ineffective() {
     x | 3 > 4; // not an error (yet), better written as x >= 4
     x | 4 <= 19;
 }
+
+#[deny(bad_bit_mask)]
+#[allow(dead_code, no_effect, unnecessary_operation, absurd_extreme_comparisons)]
+fn locals(x: u32) {
+    // the values of immutable locals are known too
+    let mask = 0xff;
+    x & mask == 0x100;
+    x & mask <= 0xff;
+}
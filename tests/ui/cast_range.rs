@@ -0,0 +1,30 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(cast_precision_loss, cast_possible_truncation, cast_sign_loss, cast_possible_wrap)]
+#![allow(no_effect, unnecessary_operation)]
+
+fn casts(x: i32, y: u64, z: u32) {
+    // the values fit in the type they are cast to
+    (x & 0xff) as u8;
+    (y % 100) as u8;
+    (y >> 56) as u8;
+    std::cmp::min(y, 1000) as u16;
+    (x & 0x7fff) as f32;
+    if x >= 0 {
+        x as u32;
+    }
+
+    // the values may not fit
+    (x & 0x1ff) as u8;
+    (y % 1000) as u8;
+    x as u8;
+
+    // shifting by the width of the type or more overflows
+    let shift = if x > 0 { 24 } else { 32 };
+    (z >> shift) as u8;
+}
+
+fn main() {
+    casts(1, 2, 3);
+}
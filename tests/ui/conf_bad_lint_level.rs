@@ -0,0 +1,6 @@
+// error-pattern: error reading Clippy's configuration file: invalid level `denny` for `needless_return`, expected `allow`, `warn`, `deny` or `forbid`
+
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/ui/conf_bad_lint_level.toml"))]
+
+fn main() {}
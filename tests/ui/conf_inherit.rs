@@ -0,0 +1,17 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_inherit/child/clippy.toml"))]
+
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![deny(blacklisted_name, too_many_arguments)]
+
+fn two(a: u8, b: u8) {}
+
+fn three(a: u8, b: u8, c: u8) {}
+
+fn main() {
+    let toto = 42;
+    let tata = 42;
+    let titi = 42;
+    let foo = 42;
+}
@@ -0,0 +1,15 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/ui/conf_lint_levels.toml"))]
+
+fn needless_return() -> bool {
+    return true;
+}
+
+fn truncation(x: u64) -> u8 {
+    x as u8
+}
+
+fn main() {
+    needless_return();
+    truncation(1);
+}
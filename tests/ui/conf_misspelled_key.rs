@@ -0,0 +1,6 @@
+// error-pattern: error reading Clippy's configuration file: unknown key `cyclomatic-complexity-treshold`
+
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/ui/conf_misspelled_key.toml"))]
+
+fn main() {}
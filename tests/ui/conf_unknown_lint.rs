@@ -0,0 +1,6 @@
+// error-pattern: error reading Clippy's configuration file: unknown lint `needless_retrun` in `[lints]`
+
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/ui/conf_unknown_lint.toml"))]
+
+fn main() {}
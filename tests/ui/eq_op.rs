@@ -34,8 +34,8 @@ fn main() {
     // const folding
     1 + 1 == 2;
     1 - 1 == 0;
-
-
+    1.0 + 1.0 <= 2.0;
+    [1, 2].len() == 2;
     1 - 1;
     1 / 1;
     true && true;
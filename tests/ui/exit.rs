@@ -0,0 +1,16 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(exit)]
+
+use std::process;
+
+fn not_main() {
+    process::exit(1);
+}
+
+fn main() {
+    if false {
+        not_main();
+    }
+    std::process::exit(0);
+}
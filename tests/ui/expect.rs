@@ -0,0 +1,22 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(expect_used)]
+
+struct NotAnOption;
+
+impl NotAnOption {
+    fn expect(&self, _: &str) {}
+}
+
+fn main() {
+    let opt = Some(0);
+    let _ = opt.expect("");
+
+    let res: Result<u8, ()> = Ok(0);
+    let _ = res.expect("");
+
+    let opt_ref = &opt;
+    let _ = opt_ref.expect("");
+
+    NotAnOption.expect("");
+}
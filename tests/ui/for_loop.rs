@@ -489,6 +489,7 @@ fn main() {
     }
 
     test_for_kv_map();
+    test_explicit_counter_loop();
 }
 
 #[allow(used_underscore_binding)]
@@ -501,3 +502,14 @@ fn test_for_kv_map() {
         let _k = k;
     }
 }
+
+#[deny(explicit_counter_loop)]
+fn test_explicit_counter_loop() {
+    let vec = vec![1, 2, 3];
+
+    // the counter is reset before it is read after the loop
+    let mut index = 0;
+    for _v in &vec { index += 1 }
+    index = 0;
+    println!("{}", index);
+}
@@ -0,0 +1,22 @@
+#![feature(plugin, box_syntax)]
+#![plugin(clippy)]
+#![deny(heap_allocation)]
+#![allow(unused_variables)]
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+fn main() {
+    let a = box 1;
+    let b = vec![1, 2];
+    let c = vec![0u8; 16];
+    let d = Box::new(1);
+    let e = "e".to_string();
+    let f = Rc::new(1);
+    let g: HashMap<u8, u8> = HashMap::new();
+
+    // not linted
+    let h = [0u8; 16];
+    let i = "i".len();
+    let j = Some(1).map(|x| x + 1);
+}
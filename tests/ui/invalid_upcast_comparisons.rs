@@ -2,7 +2,7 @@
 #![plugin(clippy)]
 
 #![deny(invalid_upcast_comparisons)]
-#![allow(unused, eq_op, no_effect, unnecessary_operation)]
+#![allow(unused, eq_op, no_effect, unnecessary_operation, absurd_extreme_comparisons)]
 
 fn mk_value<T>() -> T { unimplemented!() }
 
@@ -78,4 +78,6 @@ fn main() {
     -5 >= (u8 as i32);
 
     -5 == (u32 as i32);
+
+    ((u32 & 0xff) as u64) > 300;
 }
@@ -0,0 +1,33 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(panic, unreachable)]
+
+fn panic(a: u32) -> u32 {
+    match a {
+        0 => panic!(),
+        1 => panic!("message"),
+        2 => panic!("{} {}", "panic with", "multiple arguments"),
+        _ => a,
+    }
+}
+
+fn unreachable(a: u32) -> u32 {
+    match a {
+        0 => unreachable!(),
+        1 => unreachable!("message"),
+        2 => unreachable!("{} {}", "unreachable with", "multiple arguments"),
+        _ => a,
+    }
+}
+
+fn asserts() {
+    // the panics in other macros are not linted
+    assert!(true);
+    assert_eq!(1, 1);
+}
+
+fn main() {
+    panic(3);
+    unreachable(3);
+    asserts();
+}
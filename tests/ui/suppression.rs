@@ -0,0 +1,48 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(double_parens, unused_suppression)]
+#![allow(dead_code)]
+
+fn dummy_fn<T>(_: T) {}
+
+fn statement() {
+    // clippy: allow(double_parens) reason="the parentheses are part of the test"
+    dummy_fn((0));
+    dummy_fn((1));
+}
+
+fn group(x: u32) -> bool {
+    // clippy: allow(clippy)
+    x == x
+}
+
+// clippy: allow(double_parens, eq_op)
+#[inline]
+fn item(x: u32) -> bool {
+    dummy_fn((0));
+    x == x
+}
+
+fn unused() {
+    // clippy: allow(double_parens)
+    let x = 0;
+    dummy_fn((x));
+}
+
+fn unknown() {
+    // clippy: allow(double_paren)
+    dummy_fn((0));
+}
+
+fn malformed() {
+    // clippy: allow double_parens
+    dummy_fn((0));
+}
+
+fn nothing_follows() {
+    dummy_fn(0);
+    // clippy: allow(double_parens)
+}
+
+fn main() {}
@@ -193,3 +193,14 @@ fn refutable() {
     for &Option::None in b.next() {}
     // */
 }
+
+fn used_in_outer_loop() {
+    let mut iter = 1u32..20;
+    loop {
+        // the next iteration uses the iterator again, so it cannot be moved into a `for` loop
+        println!("first: {:?}", iter.next());
+        while let Some(x) = iter.next() {
+            println!("{}", x);
+        }
+    }
+}
@@ -17,4 +17,5 @@ fn main() {
     let other_nan = zero / other_zero; // fine - this lint doesn't propegate constants.
     let not_nan = 2.0/0.0; // not an error: 2/0 = inf
     let also_not_nan = 0.0/2.0; // not an error: 0/2 = 0
+    let folded_nan = 0.0 / (2.0 - 2.0);
 }
@@ -0,0 +1,7 @@
+#!/bin/sh
+# Vendor the official SARIF 2.1.0 schema, unchanged, which the tests of `--message-format sarif`
+# validate the logs against.
+set -e
+mkdir -p tests/auxiliary/sarif
+curl -sSfL -o tests/auxiliary/sarif/sarif-schema-2.1.0.json \
+    https://docs.oasis-open.org/sarif/sarif/v2.1.0/errata01/os/schemas/sarif-schema-2.1.0.json
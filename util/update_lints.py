@@ -8,6 +8,8 @@ import os
 import re
 import sys
 
+import lintlib
+
 declare_lint_re = re.compile(r'''
     declare_lint! \s* [{(] \s*
     pub \s+ (?P<name>[A-Z_][A-Z_0-9]*) \s*,\s*
@@ -104,6 +106,93 @@ def gen_deprecated(lints):
         yield '    );\n'
 
 
+def gen_catalogue(lints, deprecated_lints, restriction_lints, docs):
+    """Write the documentation of every lint, as `LintInfo` items."""
+
+    def group(level):
+        return 'clippy' if level in ('warn', 'deny') else 'clippy_pedantic'
+
+    entries = [(name, module, level, group(level), desc)
+               for (module, name, level, desc) in lints]
+    entries += [(name, module, level, 'clippy_restrictions', desc)
+                for (module, name, level, desc) in restriction_lints]
+    entries += [(name, module, 'deprecated', '', desc)
+                for (module, name, desc) in deprecated_lints]
+
+    for (name, module, level, group, desc) in sorted(entries):
+        doc = ''.join(docs.get(name, [])).rstrip()
+        hashes = '#'
+        while '"' + hashes in doc:
+            hashes += '#'
+        entry = '    LintInfo {\n'
+        entry += '        name: "%s",\n' % name
+        entry += '        module: "%s",\n' % module
+        entry += '        level: "%s",\n' % level
+        entry += '        group: "%s",\n' % group
+        # `collect` only unescaped the quotes of the descriptions
+        entry += '        desc: "%s",\n' % desc.replace('"', '\\"')
+        entry += '        docs: r%s"%s"%s,\n' % (hashes, doc, hashes)
+        entry += '    },\n'
+        # `replace_region` compares the regions line by line
+        for line in entry.splitlines(True):
+            yield line
+
+
+# the descriptions of the lint groups made from the lint levels
+group_descs = {
+    'clippy': 'the lints that warn or deny by default',
+    'clippy_pedantic': 'stricter lints that are allowed by default, as they have some false positives',
+    'clippy_restrictions': 'lints that forbid some language features, allowed by default',
+}
+
+# the presets registered by hand in `register_plugins`, with their description
+# in the comment above them
+preset_re = re.compile(r'''
+    (?P<comment>(?:[ ]*//[^\n]*\n)+)
+    [ ]*reg\.register_lint_group\("(?P<name>clippy_\w+)",\s*vec!\[
+    (?P<lints>[^\]]*)
+    \]\);
+''', re.VERBOSE)
+
+
+def collect_presets(fn):
+    """Collect the presets registered in `fn`, in order."""
+    with open(fn) as fp:
+        code = fp.read()
+    presets = []
+    for match in preset_re.finditer(code):
+        comment = ' '.join(line.strip().lstrip('/').strip()
+                           for line in match.group('comment').splitlines())
+        lints = [lint.strip().split('::')[-1].lower()
+                 for lint in match.group('lints').split(',') if lint.strip()]
+        presets.append((match.group('name'), comment, lints))
+    return presets
+
+
+def gen_groups(lints, restriction_lints, presets):
+    """Write the lint groups with their lints, as `GroupInfo` items."""
+    groups = [
+        ('clippy', group_descs['clippy'],
+         [name for (_, name, level, _) in lints if level in ('warn', 'deny')]),
+        ('clippy_pedantic', group_descs['clippy_pedantic'],
+         [name for (_, name, level, _) in lints if level == 'allow']),
+        ('clippy_restrictions', group_descs['clippy_restrictions'],
+         [name for (_, name, _, _) in restriction_lints]),
+    ] + presets
+
+    for (name, desc, members) in groups:
+        entry = '    GroupInfo {\n'
+        entry += '        name: "%s",\n' % name
+        entry += '        desc: "%s",\n' % desc.replace('"', '\\"')
+        entry += '        lints: &[\n'
+        for lint in sorted(members):
+            entry += '            "%s",\n' % lint
+        entry += '        ],\n'
+        entry += '    },\n'
+        for line in entry.splitlines(True):
+            yield line
+
+
 def replace_region(fn, region_start, region_end, callback,
                    replace_start=True, write_back=True):
     """Replace a region in a file delimited by two lines matching regexes.
@@ -158,6 +247,15 @@ def main(print_only=False, check=False):
             collect(lints, deprecated_lints, restriction_lints,
                     os.path.join('clippy_lints', 'src', fn))
 
+    # collect the doc comments of the lints
+    lintlib.log.getLogger().setLevel(lintlib.log.WARNING)
+    documented = []
+    for fn in os.listdir('clippy_lints/src'):
+        if fn.endswith('.rs'):
+            lintlib.parse_lints(documented,
+                                os.path.join('clippy_lints', 'src', fn))
+    docs = dict((lint.name, lint.doc) for lint in documented)
+
     # determine version
     with open('Cargo.toml') as fp:
         for line in fp:
@@ -222,7 +320,7 @@ def main(print_only=False, check=False):
 
     # same for "deprecated" lint collection
     changed |= replace_region(
-        'clippy_lints/src/lib.rs', r'let mut store', r'end deprecated lints',
+        'clippy_lints/src/lib.rs', r'let mut store = reg.sess', r'end deprecated lints',
         lambda: gen_deprecated(deprecated_lints),
         replace_start=False,
         write_back=not check)
@@ -239,6 +337,19 @@ def main(print_only=False, check=False):
         r'\]\);', lambda: gen_group(restriction_lints),
         replace_start=False, write_back=not check)
 
+    # same for the documentation of the lints
+    changed |= replace_region(
+        'clippy_lints/src/utils/catalogue.rs', r'begin lint list', r'end lint list',
+        lambda: gen_catalogue(lints, deprecated_lints, restriction_lints, docs),
+        replace_start=False, write_back=not check)
+
+    # same for the lint groups
+    presets = collect_presets('clippy_lints/src/lib.rs')
+    changed |= replace_region(
+        'clippy_lints/src/utils/catalogue.rs', r'begin group list', r'end group list',
+        lambda: gen_groups(lints, restriction_lints, presets),
+        replace_start=False, write_back=not check)
+
     if check and changed:
         print('Please run util/update_lints.py to regenerate lints lists.')
         return 1